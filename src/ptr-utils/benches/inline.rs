@@ -0,0 +1,93 @@
+//! Inlining strategy benchmarks.
+//!
+//! The crate marks its single-access methods `#[inline(always)]`. These benchmarks
+//! check that choice against a plain `#[inline]` hint and against `#[inline(never)]`
+//! (the cost of a call per access) by calling [`UnalignedRead::read_u32_at`] and
+//! friends across the crate boundary, as a dependent crate would. The attribute is
+//! the crate's own, switched with a `cfg` when building it:
+//!
+//! ```bash
+//! cargo bench --bench inline -- --save-baseline always
+//! RUSTFLAGS='--cfg ptr_utils_inline="hint"' cargo bench --bench inline -- --baseline always
+//! RUSTFLAGS='--cfg ptr_utils_inline="never"' cargo bench --bench inline -- --baseline always
+//! ```
+//!
+//! - `hot`: a tight loop summing every [`u32`] in a buffer, where an out-of-line call
+//!   blocks unrolling and vectorization.
+//! - `cold`: a header parse (7 mixed-width field reads) run once per record, the kind
+//!   of call site a large parser has on rarely-taken paths.
+//!
+//! Measured on x86_64 with the workspace `bench` profile (median of each run; `cold`
+//! varies by ~1.5µs between runs of the same build):
+//!
+//! | attribute        | `hot` (64 KiB) | `cold` (2047 records) |
+//! |------------------|----------------|-----------------------|
+//! | `inline(always)` | 1.96-2.11µs    | 3.7-5.6µs             |
+//! | `inline`         | 1.99-2.12µs    | 4.2-5.6µs             |
+//! | `inline(never)`  | 23.7µs         | 29.1µs                |
+//!
+//! Under both `always` and `hint`, every read is inlined (no calls into the crate remain
+//! in the bench binary), while `never` is ~12x slower in `hot` and ~6x slower in `cold`.
+//! Each method body is a single unaligned load, smaller inline than the call it replaces,
+//! so `#[inline(always)]` costs no code size. It is kept because it also holds in debug
+//! builds and under `-C opt-level=s`, where the hint may be declined. Methods with loops
+//! (e.g. [`IterRead`](ptr_utils::IterRead) collection, [`FoldRead`](ptr_utils::FoldRead))
+//! already use plain `#[inline]`, so no `_inline`-suffixed split of the API is warranted.
+
+use core::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use ptr_utils::UnalignedRead;
+
+const BUFFER_SIZE: usize = 64 * 1024;
+const RECORD_SIZE: usize = 32;
+
+/// Sums every [`u32`] in the buffer, starting misaligned at offset 1.
+fn sum_u32(buffer: &[u8]) -> u32 {
+    let ptr = buffer.as_ptr();
+    let mut sum = 0u32;
+    let mut offset = 1;
+    while offset + 4 <= buffer.len() {
+        sum = sum.wrapping_add(unsafe { ptr.read_u32_at(offset) });
+        offset += 4;
+    }
+    sum
+}
+
+/// Parses a 32-byte record header of mixed-width fields, as a parser would once per record.
+#[inline(never)]
+fn parse_header(record: *const u8) -> u64 {
+    unsafe {
+        let magic = record.read_u32_at(0);
+        let version = record.read_u16_at(4);
+        let flags = record.read_u16_at(6);
+        let size = record.read_u64_at(8);
+        let offset = record.read_u32_at(16);
+        let count = record.read_u32_at(20);
+        let checksum = record.read_u64_at(24);
+        (magic as u64 ^ size ^ checksum)
+            .wrapping_add(offset as u64 * count as u64)
+            .wrapping_add(((version as u64) << 16) | flags as u64)
+    }
+}
+
+/// Parses the header of every record in the buffer, each starting misaligned.
+fn parse_all(buffer: &[u8]) -> u64 {
+    let ptr = buffer.as_ptr();
+    (0..buffer.len() / RECORD_SIZE - 1)
+        .map(|x| parse_header(ptr.wrapping_add(x * RECORD_SIZE + 1)))
+        .fold(0u64, u64::wrapping_add)
+}
+
+fn bench_inline(c: &mut Criterion) {
+    let buffer: Vec<u8> = (0..BUFFER_SIZE).map(|x| (x * 7) as u8).collect();
+
+    let mut group = c.benchmark_group("inline");
+    group.throughput(Throughput::Bytes(BUFFER_SIZE as u64));
+    group.bench_function("hot", |b| b.iter(|| sum_u32(black_box(&buffer))));
+    group.throughput(Throughput::Elements((BUFFER_SIZE / RECORD_SIZE - 1) as u64));
+    group.bench_function("cold", |b| b.iter(|| parse_all(black_box(&buffer))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_inline);
+criterion_main!(benches);
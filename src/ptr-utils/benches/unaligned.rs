@@ -0,0 +1,121 @@
+//! Unaligned read benchmarks.
+//!
+//! Compares [`UnalignedRead`] (which lowers to `read_unaligned`) against assembling
+//! values from individual byte loads, the only option on targets that trap on
+//! unaligned access. The cost model differs sharply between x86 and ARM, so these
+//! should be run on the target in question, e.g. via `cross`:
+//!
+//! ```text
+//! cross bench --target armv7-unknown-linux-gnueabihf --bench unaligned
+//! cross bench --target aarch64-unknown-linux-gnu --bench unaligned
+//! ```
+//!
+//! The `read_width` group reads every width misaligned, to check the
+//! [`load::SUPPORTED_SINGLE_LOAD_WIDTHS`](ptr_utils::load::SUPPORTED_SINGLE_LOAD_WIDTHS)
+//! table for the target: widths listed there should run at a similar rate per byte.
+//!
+//! For targets without an OS (e.g. `thumbv7em-none-eabihf`), inspect the generated
+//! code instead, e.g. with `cargo asm --target thumbv7em-none-eabihf`.
+
+use core::hint::black_box;
+use core::mem::size_of;
+use core::ptr::read_volatile;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ptr_utils::UnalignedRead;
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Assembles a [`u32`] from individual bytes, letting the compiler merge the loads
+/// where the target allows it.
+#[inline(always)]
+unsafe fn read_u32_bytes(ptr: *const u8, byte_offset: usize) -> u32 {
+    let p = ptr.add(byte_offset);
+    u32::from_ne_bytes([p.read(), p.add(1).read(), p.add(2).read(), p.add(3).read()])
+}
+
+/// Assembles a [`u32`] from individual volatile byte loads, which the compiler may
+/// not merge; this is the code a strict-alignment fallback must produce.
+#[inline(always)]
+unsafe fn read_u32_bytes_volatile(ptr: *const u8, byte_offset: usize) -> u32 {
+    let p = ptr.add(byte_offset);
+    u32::from_ne_bytes([
+        read_volatile(p),
+        read_volatile(p.add(1)),
+        read_volatile(p.add(2)),
+        read_volatile(p.add(3)),
+    ])
+}
+
+/// Sums every [`u32`] in the buffer, starting at `start` (use 1 for misaligned reads).
+#[inline(always)]
+fn sum_u32(buffer: &[u8], start: usize, read: unsafe fn(*const u8, usize) -> u32) -> u32 {
+    let ptr = buffer.as_ptr();
+    let mut sum = 0u32;
+    let mut offset = start;
+    while offset + 4 <= buffer.len() {
+        sum = sum.wrapping_add(unsafe { read(ptr, offset) });
+        offset += 4;
+    }
+    sum
+}
+
+fn bench_read_u32(c: &mut Criterion) {
+    let buffer: Vec<u8> = (0..BUFFER_SIZE).map(|x| x as u8).collect();
+    let mut group = c.benchmark_group("read_u32");
+    group.throughput(Throughput::Bytes(BUFFER_SIZE as u64));
+
+    for start in [0usize, 1] {
+        let label = if start == 0 { "aligned" } else { "misaligned" };
+
+        group.bench_with_input(BenchmarkId::new("read_u32_at", label), &start, |b, &s| {
+            b.iter(|| sum_u32(black_box(&buffer), s, |p, o| unsafe { p.read_u32_at(o) }))
+        });
+        group.bench_with_input(BenchmarkId::new("byte_assembly", label), &start, |b, &s| {
+            b.iter(|| sum_u32(black_box(&buffer), s, read_u32_bytes))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("byte_assembly_volatile", label),
+            &start,
+            |b, &s| b.iter(|| sum_u32(black_box(&buffer), s, read_u32_bytes_volatile)),
+        );
+    }
+
+    group.finish();
+}
+
+/// Sums every `T` in the buffer starting at offset 1, so that all reads are misaligned.
+#[inline(always)]
+fn sum_misaligned<T: Copy>(buffer: &[u8], fold: impl Fn(u64, T) -> u64) -> u64 {
+    let ptr = buffer.as_ptr();
+    let mut sum = 0u64;
+    let mut offset = 1;
+    while offset + size_of::<T>() <= buffer.len() {
+        sum = fold(sum, unsafe { ptr.read_at::<T>(offset) });
+        offset += size_of::<T>();
+    }
+    sum
+}
+
+fn bench_read_width(c: &mut Criterion) {
+    let buffer: Vec<u8> = (0..BUFFER_SIZE).map(|x| x as u8).collect();
+    let mut group = c.benchmark_group("read_width");
+    group.throughput(Throughput::Bytes(BUFFER_SIZE as u64));
+
+    group.bench_function("u16", |b| {
+        b.iter(|| sum_misaligned(black_box(&buffer), |s, v: u16| s.wrapping_add(v as u64)))
+    });
+    group.bench_function("u32", |b| {
+        b.iter(|| sum_misaligned(black_box(&buffer), |s, v: u32| s.wrapping_add(v as u64)))
+    });
+    group.bench_function("u64", |b| {
+        b.iter(|| sum_misaligned(black_box(&buffer), |s, v: u64| s.wrapping_add(v)))
+    });
+    group.bench_function("u128", |b| {
+        b.iter(|| sum_misaligned(black_box(&buffer), |s, v: u128| s.wrapping_add(v as u64)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_read_u32, bench_read_width);
+criterion_main!(benches);
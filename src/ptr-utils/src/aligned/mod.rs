@@ -0,0 +1,8 @@
+//! Reads for callers who guarantee alignment, asserted in debug builds.
+
+pub mod read;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::AlignedRead;
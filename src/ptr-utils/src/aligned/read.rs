@@ -0,0 +1,201 @@
+//! Reads that require (and assert) alignment.
+
+use core::mem::align_of;
+
+use crate::unaligned::raw::read_raw_aligned;
+use crate::{AsBytePtr, UnalignedRead};
+
+/// Trait providing reads for data the caller knows to be aligned.
+///
+/// This is the inverse of the rest of the crate: instead of tolerating any address,
+/// these methods require the effective address to be aligned for the type, check it
+/// with [`debug_assert!`] (panicking in debug builds if misused), and then perform a
+/// plain aligned load. On targets where unaligned loads are slower or must be emulated,
+/// this is the fastest read available.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::AlignedRead;
+///
+/// let values = [0x11223344u32, 0x55667788];
+/// let ptr = values.as_ptr();
+///
+/// unsafe {
+///     assert_eq!(ptr.read_u32_require_aligned_at(4), 0x55667788);
+///     // ptr.read_u32_require_aligned_at(2) would panic in debug builds.
+/// }
+/// ```
+pub trait AlignedRead: UnalignedRead + AsBytePtr + Copy {
+    /// Reads a `T` from the pointer at the given byte offset, which must be aligned for `T`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `size_of::<T>()` bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<T>()` (checked with
+    ///   [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - The bytes read must represent a valid `T`
+    #[inline(always)]
+    unsafe fn read_require_aligned_at<T: Copy>(self, byte_offset: usize) -> T {
+        let base = self.as_byte_ptr();
+        debug_check!(
+            base.add(byte_offset).cast::<T>().is_aligned(),
+            "address {:p} is not aligned to {} bytes",
+            base.add(byte_offset),
+            align_of::<T>()
+        );
+        read_raw_aligned(base, byte_offset)
+    }
+
+    // Unsigned integer types
+
+    /// Reads a [`u16`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`u16`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<u16>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u16_require_aligned_at(self, byte_offset: usize) -> u16 {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    /// Reads a [`u32`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`u32`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<u32>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u32_require_aligned_at(self, byte_offset: usize) -> u32 {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    /// Reads a [`u64`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`u64`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<u64>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u64_require_aligned_at(self, byte_offset: usize) -> u64 {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    /// Reads a [`u128`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`u128`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<u128>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u128_require_aligned_at(self, byte_offset: usize) -> u128 {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    /// Reads a [`usize`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`usize`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading [`size_of::<usize>()`] bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<usize>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_usize_require_aligned_at(self, byte_offset: usize) -> usize {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    // Signed integer types
+
+    /// Reads an [`i16`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`i16`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<i16>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_i16_require_aligned_at(self, byte_offset: usize) -> i16 {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    /// Reads an [`i32`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`i32`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<i32>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_i32_require_aligned_at(self, byte_offset: usize) -> i32 {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    /// Reads an [`i64`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`i64`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<i64>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_i64_require_aligned_at(self, byte_offset: usize) -> i64 {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    /// Reads an [`i128`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`i128`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<i128>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_i128_require_aligned_at(self, byte_offset: usize) -> i128 {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    /// Reads an [`isize`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`isize`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading [`size_of::<isize>()`] bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<isize>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_isize_require_aligned_at(self, byte_offset: usize) -> isize {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    // Floating point types
+
+    /// Reads an [`f32`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`f32`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<f32>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_f32_require_aligned_at(self, byte_offset: usize) -> f32 {
+        self.read_require_aligned_at(byte_offset)
+    }
+
+    /// Reads an [`f64`] value from the pointer at the given byte offset, which must be
+    /// aligned for [`f64`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The pointer plus byte offset must be aligned to `align_of::<f64>()` (checked with [`debug_assert!`])
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_f64_require_aligned_at(self, byte_offset: usize) -> f64 {
+        self.read_require_aligned_at(byte_offset)
+    }
+}
+
+impl<P: UnalignedRead + AsBytePtr + Copy> AlignedRead for P {}
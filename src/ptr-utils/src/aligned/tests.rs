@@ -0,0 +1,30 @@
+use super::*;
+
+#[test]
+fn test_require_aligned_reads() {
+    let values = [0x0102030405060708u64, u64::MAX];
+    let ptr = values.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_u64_require_aligned_at(0), 0x0102030405060708);
+        assert_eq!(ptr.read_i64_require_aligned_at(8), -1);
+        assert_eq!(
+            ptr.read_u32_require_aligned_at(4),
+            ptr.cast::<u32>().add(1).read()
+        );
+        assert_eq!(
+            ptr.read_u16_require_aligned_at(2),
+            ptr.cast::<u16>().add(1).read()
+        );
+    }
+}
+
+#[cfg(all(debug_assertions, not(feature = "no-panic")))]
+#[test]
+#[should_panic(expected = "is not aligned")]
+fn test_require_aligned_panics_when_misaligned() {
+    let values = [0u32; 2];
+    unsafe {
+        values.as_ptr().read_u32_require_aligned_at(1);
+    }
+}
@@ -0,0 +1,19 @@
+//! Reads and writes of fixed-point angles.
+//!
+//! Many game formats store angles as 16-bit "binary radians" (brads), where the
+//! full circle is 65536 units: `0x4000` is a quarter turn, `0x8000` a half turn.
+
+pub mod read;
+pub mod write;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::AngleRead;
+pub use write::AngleWrite;
+
+/// Radians per 16-bit brad (`2π / 65536`).
+pub const RADIANS_PER_BRAD16: f32 = core::f32::consts::TAU / 65536.0;
+
+/// Degrees per 16-bit brad (`360 / 65536`).
+pub const DEGREES_PER_BRAD16: f32 = 360.0 / 65536.0;
@@ -0,0 +1,33 @@
+//! Fixed-point angle reads.
+
+use super::{DEGREES_PER_BRAD16, RADIANS_PER_BRAD16};
+use crate::UnalignedRead;
+
+/// Trait providing reads of fixed-point angles, converted to floating point.
+pub trait AngleRead: UnalignedRead + Copy {
+    /// Reads a 16-bit brad angle (native endian [`u16`]) and returns it in radians,
+    /// in the range `0..2π`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_brad16_at(self, byte_offset: usize) -> f32 {
+        self.read_u16_at(byte_offset) as f32 * RADIANS_PER_BRAD16
+    }
+
+    /// Reads a 16-bit brad angle (native endian [`u16`]) and returns it in degrees,
+    /// in the range `0..360`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_brad16_degrees_at(self, byte_offset: usize) -> f32 {
+        self.read_u16_at(byte_offset) as f32 * DEGREES_PER_BRAD16
+    }
+}
+
+impl<P: UnalignedRead + Copy> AngleRead for P {}
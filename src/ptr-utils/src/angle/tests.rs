@@ -0,0 +1,40 @@
+use core::f32::consts::{FRAC_PI_2, PI};
+
+use super::*;
+use crate::{UnalignedRead, UnalignedWrite};
+
+#[test]
+fn test_read_brad16() {
+    let mut buffer = [0u8; 8];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_u16_at(1, 0x4000);
+        ptr.write_u16_at(3, 0x8000);
+        assert_eq!(ptr.read_brad16_at(1), FRAC_PI_2);
+        assert_eq!(ptr.read_brad16_at(3), PI);
+        assert_eq!(ptr.read_brad16_degrees_at(1), 90.0);
+        assert_eq!(ptr.read_brad16_degrees_at(5), 0.0);
+    }
+}
+
+#[test]
+fn test_write_brad16() {
+    let mut buffer = [0u8; 4];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_brad16_at(1, FRAC_PI_2);
+        assert_eq!(ptr.read_u16_at(1), 0x4000);
+
+        // Negative angles and full turns wrap.
+        ptr.write_brad16_at(1, -FRAC_PI_2);
+        assert_eq!(ptr.read_u16_at(1), 0xC000);
+        ptr.write_brad16_degrees_at(1, 450.0);
+        assert_eq!(ptr.read_u16_at(1), 0x4000);
+
+        // Rounds to the nearest brad.
+        ptr.write_brad16_degrees_at(1, 0.004);
+        assert_eq!(ptr.read_u16_at(1), 1);
+    }
+}
@@ -0,0 +1,51 @@
+//! Fixed-point angle writes.
+
+use super::{DEGREES_PER_BRAD16, RADIANS_PER_BRAD16};
+use crate::UnalignedWrite;
+
+/// Rounds `brads` to the nearest integer and wraps it onto the 16-bit circle.
+#[inline(always)]
+fn wrap_brad16(brads: f32) -> u16 {
+    // `as i64` truncates toward zero, so offsetting by a half first rounds to nearest.
+    let rounded = if brads >= 0.0 {
+        brads + 0.5
+    } else {
+        brads - 0.5
+    };
+    rounded as i64 as u16
+}
+
+/// Trait providing writes of floating point angles as fixed-point values.
+pub trait AngleWrite: UnalignedWrite + Copy {
+    /// Writes an angle in radians as a 16-bit brad (native endian [`u16`]).
+    ///
+    /// The angle is rounded to the nearest brad and wrapped, so negative angles and
+    /// angles of a full turn or more are stored modulo `2π`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_brad16_at(self, byte_offset: usize, radians: f32) {
+        self.write_u16_at(byte_offset, wrap_brad16(radians / RADIANS_PER_BRAD16));
+    }
+
+    /// Writes an angle in degrees as a 16-bit brad (native endian [`u16`]).
+    ///
+    /// The angle is rounded to the nearest brad and wrapped, so negative angles and
+    /// angles of 360 degrees or more are stored modulo 360.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_brad16_degrees_at(self, byte_offset: usize, degrees: f32) {
+        self.write_u16_at(byte_offset, wrap_brad16(degrees / DEGREES_PER_BRAD16));
+    }
+}
+
+impl<P: UnalignedWrite + Copy> AngleWrite for P {}
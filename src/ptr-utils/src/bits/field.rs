@@ -0,0 +1,86 @@
+//! Reads of bit fields at arbitrary bit offsets.
+
+use crate::UnalignedRead;
+
+/// Trait providing reads of bit fields that start at any bit and may span several bytes.
+///
+/// Bits are numbered least significant first across little-endian bytes: bit `n` is bit
+/// `n % 8` of byte `n / 8`, counting from the byte offset. This matches C bit-fields on
+/// little-endian targets, and the [`BitOrder::LsbFirst`](crate::BitOrder::LsbFirst)
+/// stream order.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::BitFieldRead;
+///
+/// // A 12-bit field holding -3, starting at bit 6.
+/// let raw = ((-3i64 as u64) & 0xFFF) << 6;
+/// let data = raw.to_le_bytes();
+///
+/// unsafe {
+///     assert_eq!(data.as_ptr().read_bits_at(0, 6, 12), 0xFFD);
+///     assert_eq!(data.as_ptr().read_signed_bits_at(0, 6, 12), -3);
+/// }
+/// ```
+pub trait BitFieldRead: UnalignedRead + Copy {
+    /// Reads the `bit_count`-bit field starting `bit_offset` bits after the given byte
+    /// offset, returning it zero-extended. A `bit_count` of 0 returns 0.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading
+    ///   `(bit_offset + bit_count).div_ceil(8)` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - `bit_count` must be no larger than 64 (checked in debug builds)
+    #[inline]
+    unsafe fn read_bits_at(self, byte_offset: usize, bit_offset: u32, bit_count: u32) -> u64 {
+        debug_check!(bit_count <= 64, "cannot read {bit_count} bits into a u64");
+        let bit_count = bit_count.min(64);
+        if bit_count == 0 {
+            return 0;
+        }
+
+        let first = byte_offset + (bit_offset / 8) as usize;
+        let shift = bit_offset % 8;
+        let byte_count = (shift + bit_count).div_ceil(8) as usize;
+
+        // At most 9 bytes: 64 bits starting at bit 7.
+        let mut bits = 0u128;
+        for x in 0..byte_count {
+            bits |= (self.read_u8_at(first + x) as u128) << (x * 8);
+        }
+        let value = (bits >> shift) as u64;
+        if bit_count == 64 {
+            value
+        } else {
+            value & ((1 << bit_count) - 1)
+        }
+    }
+
+    /// Reads the `bit_count`-bit field starting `bit_offset` bits after the given byte
+    /// offset, sign-extending it from its top bit to 64 bits. A `bit_count` of 0
+    /// returns 0.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading
+    ///   `(bit_offset + bit_count).div_ceil(8)` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - `bit_count` must be no larger than 64 (checked in debug builds)
+    #[inline]
+    unsafe fn read_signed_bits_at(
+        self,
+        byte_offset: usize,
+        bit_offset: u32,
+        bit_count: u32,
+    ) -> i64 {
+        // Read first, so `bit_count` is checked before it is used in a shift.
+        let value = self.read_bits_at(byte_offset, bit_offset, bit_count);
+        if bit_count == 0 {
+            return 0;
+        }
+        let unused = 64 - bit_count.min(64);
+        ((value << unused) as i64) >> unused
+    }
+}
+
+impl<P: UnalignedRead + Copy> BitFieldRead for P {}
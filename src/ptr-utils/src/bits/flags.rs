@@ -0,0 +1,60 @@
+//! Reads of flag fields directly into [`bitflags`] types.
+
+use bitflags::Flags;
+
+use crate::UnalignedRead;
+
+/// Trait providing reads that parse a flag field into a [`bitflags`] flag set.
+///
+/// The width of the field is that of the flag type's underlying integer
+/// ([`Flags::Bits`]), read in native endianness like the rest of [`UnalignedRead`].
+///
+/// # Example
+///
+/// ```
+/// use bitflags::bitflags;
+/// use ptr_utils::{FlagsRead, UnalignedWrite};
+///
+/// bitflags! {
+///     #[derive(Debug, PartialEq)]
+///     struct Access: u16 {
+///         const READ = 1;
+///         const WRITE = 2;
+///     }
+/// }
+///
+/// let mut buffer = [0u8; 2];
+/// let ptr = buffer.as_mut_ptr();
+/// unsafe {
+///     ptr.write_u16_at(0, 0b111);
+///     assert_eq!(ptr.read_flags_at::<Access>(0), Access::READ | Access::WRITE);
+///     assert_eq!(ptr.read_flags_strict_at::<Access>(0), None);
+/// }
+/// ```
+pub trait FlagsRead: UnalignedRead + Copy {
+    /// Reads a flag field at the given byte offset, discarding any bits that don't
+    /// correspond to a defined flag ([`Flags::from_bits_truncate`]).
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `size_of::<F::Bits>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_flags_at<F: Flags>(self, byte_offset: usize) -> F {
+        F::from_bits_truncate(self.read_at::<F::Bits>(byte_offset))
+    }
+
+    /// Reads a flag field at the given byte offset, returning [`None`] if any bit is set
+    /// that doesn't correspond to a defined flag ([`Flags::from_bits`]).
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `size_of::<F::Bits>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_flags_strict_at<F: Flags>(self, byte_offset: usize) -> Option<F> {
+        F::from_bits(self.read_at::<F::Bits>(byte_offset))
+    }
+}
+
+impl<P: UnalignedRead + Copy> FlagsRead for P {}
@@ -0,0 +1,149 @@
+//! Reads combined with a bitmask, for extracting byte-aligned flag fields.
+
+use crate::UnalignedRead;
+
+/// Trait providing reads that apply a bitmask (and optionally a shift) to the value read.
+///
+/// Values are read in native endianness, like the rest of [`UnalignedRead`].
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{MaskedRead, UnalignedWrite};
+///
+/// let mut buffer = [0u8; 4];
+/// let ptr = buffer.as_mut_ptr();
+///
+/// unsafe {
+///     // Bits 4..8 hold a 4-bit 'kind' field.
+///     ptr.write_u32_at(0, 0x0000_00A5);
+///     assert_eq!(ptr.read_u32_masked_at(0, 0xF0), 0xA0);
+///     assert_eq!(ptr.read_u32_masked_shifted_at(0, 0xF0, 4), 0xA);
+/// }
+/// ```
+pub trait MaskedRead: UnalignedRead + Copy {
+    /// Reads a [`u8`] value from the pointer at the given byte offset and returns `value & mask`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u8_masked_at(self, byte_offset: usize, mask: u8) -> u8 {
+        self.read_u8_at(byte_offset) & mask
+    }
+
+    /// Reads a [`u8`] value from the pointer at the given byte offset and returns
+    /// `(value & mask) >> shift`, extracting a whole field.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - `shift` must be less than 8
+    #[inline(always)]
+    unsafe fn read_u8_masked_shifted_at(self, byte_offset: usize, mask: u8, shift: u32) -> u8 {
+        (self.read_u8_at(byte_offset) & mask) >> shift
+    }
+
+    /// Reads a [`u16`] value from the pointer at the given byte offset and returns `value & mask`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u16_masked_at(self, byte_offset: usize, mask: u16) -> u16 {
+        self.read_u16_at(byte_offset) & mask
+    }
+
+    /// Reads a [`u16`] value from the pointer at the given byte offset and returns
+    /// `(value & mask) >> shift`, extracting a whole field.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    /// - `shift` must be less than 16
+    #[inline(always)]
+    unsafe fn read_u16_masked_shifted_at(self, byte_offset: usize, mask: u16, shift: u32) -> u16 {
+        (self.read_u16_at(byte_offset) & mask) >> shift
+    }
+
+    /// Reads a [`u32`] value from the pointer at the given byte offset and returns `value & mask`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u32_masked_at(self, byte_offset: usize, mask: u32) -> u32 {
+        self.read_u32_at(byte_offset) & mask
+    }
+
+    /// Reads a [`u32`] value from the pointer at the given byte offset and returns
+    /// `(value & mask) >> shift`, extracting a whole field.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    /// - `shift` must be less than 32
+    #[inline(always)]
+    unsafe fn read_u32_masked_shifted_at(self, byte_offset: usize, mask: u32, shift: u32) -> u32 {
+        (self.read_u32_at(byte_offset) & mask) >> shift
+    }
+
+    /// Reads a [`u64`] value from the pointer at the given byte offset and returns `value & mask`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u64_masked_at(self, byte_offset: usize, mask: u64) -> u64 {
+        self.read_u64_at(byte_offset) & mask
+    }
+
+    /// Reads a [`u64`] value from the pointer at the given byte offset and returns
+    /// `(value & mask) >> shift`, extracting a whole field.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    /// - `shift` must be less than 64
+    #[inline(always)]
+    unsafe fn read_u64_masked_shifted_at(self, byte_offset: usize, mask: u64, shift: u32) -> u64 {
+        (self.read_u64_at(byte_offset) & mask) >> shift
+    }
+
+    /// Reads a [`u128`] value from the pointer at the given byte offset and returns `value & mask`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u128_masked_at(self, byte_offset: usize, mask: u128) -> u128 {
+        self.read_u128_at(byte_offset) & mask
+    }
+
+    /// Reads a [`u128`] value from the pointer at the given byte offset and returns
+    /// `(value & mask) >> shift`, extracting a whole field.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    /// - `shift` must be less than 128
+    #[inline(always)]
+    unsafe fn read_u128_masked_shifted_at(
+        self,
+        byte_offset: usize,
+        mask: u128,
+        shift: u32,
+    ) -> u128 {
+        (self.read_u128_at(byte_offset) & mask) >> shift
+    }
+}
+
+impl<P: UnalignedRead + Copy> MaskedRead for P {}
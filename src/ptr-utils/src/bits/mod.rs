@@ -0,0 +1,22 @@
+//! Bit-level field extraction on top of the unaligned reads, and bit-packed streams.
+
+pub mod field;
+#[cfg(feature = "bitflags")]
+pub mod flags;
+pub mod masked;
+pub mod order;
+pub mod reader;
+pub mod valid;
+pub mod writer;
+
+#[cfg(test)]
+mod tests;
+
+pub use field::BitFieldRead;
+#[cfg(feature = "bitflags")]
+pub use flags::FlagsRead;
+pub use masked::MaskedRead;
+pub use order::BitOrder;
+pub use reader::BitReader;
+pub use valid::ValidBitRead;
+pub use writer::BitWriter;
@@ -0,0 +1,13 @@
+//! Bit order of bit-packed streams.
+
+/// The order in which bits are packed into each byte of a bit stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BitOrder {
+    /// The first bit of the stream is bit 7 (`0x80`) of the first byte, and fields are
+    /// stored most significant bit first. Used by JPEG, MPEG and most format headers.
+    #[default]
+    MsbFirst,
+    /// The first bit of the stream is bit 0 (`0x01`) of the first byte, and fields are
+    /// stored least significant bit first. Used by DEFLATE and LZW (GIF).
+    LsbFirst,
+}
@@ -0,0 +1,100 @@
+//! A bit-stream reader over a raw pointer.
+
+use super::BitOrder;
+use crate::UnalignedRead;
+
+/// A sequential reader unpacking fields of any bit width from the bytes behind a raw
+/// pointer, tracking a bit position from its base.
+///
+/// The read-side counterpart of [`BitWriter`](crate::BitWriter). No bounds are tracked;
+/// all reads are `unsafe`.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{BitOrder, BitReader};
+///
+/// let data = [0b1011_1110u8, 0b0001_0000];
+/// let mut reader = BitReader::new(data.as_ptr(), BitOrder::MsbFirst);
+///
+/// unsafe {
+///     assert_eq!(reader.read_bits(3), 0b101);
+///     assert_eq!(reader.read_bits(9), 0b1_1110_0001);
+/// }
+/// assert_eq!(reader.bit_position(), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitReader {
+    base: *const u8,
+    order: BitOrder,
+    bit_position: usize,
+}
+
+impl BitReader {
+    /// Creates a reader positioned at the first bit of `base`.
+    #[inline(always)]
+    pub const fn new(base: *const u8, order: BitOrder) -> Self {
+        Self {
+            base,
+            order,
+            bit_position: 0,
+        }
+    }
+
+    /// Returns the base pointer the reader was created with.
+    #[inline(always)]
+    pub const fn base(&self) -> *const u8 {
+        self.base
+    }
+
+    /// Returns the bit order the reader was created with.
+    #[inline(always)]
+    pub const fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Returns the number of bits read so far.
+    #[inline(always)]
+    pub const fn bit_position(&self) -> usize {
+        self.bit_position
+    }
+
+    /// Reads a `count`-bit field and advances past it, returning it in the low bits.
+    ///
+    /// With [`BitOrder::MsbFirst`] the field is read most significant bit first, with
+    /// [`BitOrder::LsbFirst`] least significant bit first. A `count` of 0 reads nothing
+    /// and returns 0.
+    ///
+    /// # Safety
+    /// - The bytes the field falls in must be valid for reading
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - `count` must be no larger than 64 (checked in debug builds)
+    #[inline]
+    pub unsafe fn read_bits(&mut self, count: u32) -> u64 {
+        debug_check!(count <= 64, "cannot read {count} bits into a u64");
+        let count = count.min(64);
+        // The wrapping operations below never wrap; they only keep overflow checks out of
+        // `no-panic` builds.
+        let mut value = 0u64;
+        let mut done = 0u32;
+        while done < count {
+            let byte = self.base.read_u8_at(self.bit_position / 8) as u64;
+            let used = (self.bit_position % 8) as u32;
+            let take = (count - done).min(8 - used);
+            let mask = (1u64 << take) - 1;
+            match self.order {
+                BitOrder::MsbFirst => {
+                    let chunk = byte.wrapping_shr(8 - used - take) & mask;
+                    value = value << take | chunk;
+                }
+                BitOrder::LsbFirst => {
+                    let chunk = (byte >> used) & mask;
+                    value |= chunk << done;
+                }
+            }
+            done = done.wrapping_add(take);
+            self.bit_position = self.bit_position.wrapping_add(take as usize);
+        }
+        value
+    }
+}
@@ -0,0 +1,276 @@
+use super::*;
+use crate::UnalignedWrite;
+
+#[test]
+fn test_masked_reads() {
+    unsafe {
+        let mut buffer = [0u8; 32];
+        let ptr = buffer.as_mut_ptr();
+
+        ptr.write_u8_at(0, 0b1010_0110);
+        ptr.write_u16_at(1, 0xABCD);
+        ptr.write_u32_at(3, 0x1234_5678);
+        ptr.write_u64_at(7, 0xDEAD_BEEF_0000_FFFF);
+        ptr.write_u128_at(15, u128::MAX);
+
+        assert_eq!(ptr.read_u8_masked_at(0, 0b0000_0110), 0b0000_0110);
+        assert_eq!(ptr.read_u16_masked_at(1, 0xFF00), 0xAB00);
+        assert_eq!(ptr.read_u32_masked_at(3, 0x0000_FFFF), 0x5678);
+        assert_eq!(
+            ptr.read_u64_masked_at(7, 0xFFFF_FFFF_0000_0000),
+            0xDEAD_BEEF_0000_0000
+        );
+        assert_eq!(ptr.read_u128_masked_at(15, 0xF0), 0xF0);
+    }
+}
+
+#[test]
+fn test_masked_shifted_reads() {
+    unsafe {
+        let mut buffer = [0u8; 32];
+        let ptr = buffer.as_mut_ptr();
+
+        ptr.write_u8_at(0, 0b1010_0110);
+        ptr.write_u16_at(1, 0xABCD);
+        ptr.write_u32_at(3, 0x1234_5678);
+        ptr.write_u64_at(7, 0xDEAD_BEEF_0000_FFFF);
+        ptr.write_u128_at(15, 0xFF << 100);
+
+        assert_eq!(ptr.read_u8_masked_shifted_at(0, 0b1110_0000, 5), 0b101);
+        assert_eq!(ptr.read_u16_masked_shifted_at(1, 0x0FF0, 4), 0xBC);
+        assert_eq!(ptr.read_u32_masked_shifted_at(3, 0x00FF_0000, 16), 0x34);
+        assert_eq!(
+            ptr.read_u64_masked_shifted_at(7, 0xFFFF_FFFF_0000_0000, 32),
+            0xDEAD_BEEF
+        );
+        assert_eq!(ptr.read_u128_masked_shifted_at(15, 0xF << 104, 104), 0xF);
+    }
+}
+
+#[test]
+fn test_valid_bit_reads() {
+    unsafe {
+        let mut buffer = [0u8; 40];
+        let ptr = buffer.as_mut_ptr();
+
+        ptr.write_u32_at(1, 0x8000_1234);
+        ptr.write_u32_at(5, 0x0000_1234);
+        ptr.write_u8_at(9, 0b1000_0001);
+        ptr.write_u16_at(10, 0x7FFF);
+        ptr.write_u64_at(12, 1);
+        ptr.write_u128_at(20, u128::MAX);
+
+        // Valid bit set, then clear; the data is the same either way.
+        assert_eq!(ptr.read_u32_with_valid_at(1, 1 << 31), (0x1234, true));
+        assert_eq!(ptr.read_u32_with_valid_at(5, 1 << 31), (0x1234, false));
+
+        assert_eq!(ptr.read_u8_with_valid_at(9, 0x01), (0b1000_0000, true));
+        assert_eq!(ptr.read_u16_with_valid_at(10, 0x8000), (0x7FFF, false));
+        assert_eq!(ptr.read_u64_with_valid_at(12, 1), (0, true));
+        assert_eq!(
+            ptr.read_u128_with_valid_at(20, 1 << 127),
+            (u128::MAX >> 1, true)
+        );
+    }
+}
+
+#[cfg(feature = "bitflags")]
+#[test]
+fn test_read_flags() {
+    bitflags::bitflags! {
+        #[derive(Debug, PartialEq)]
+        struct Mode: u32 {
+            const A = 1 << 0;
+            const B = 1 << 8;
+            const C = 1 << 31;
+        }
+    }
+
+    let mut buffer = [0u8; 9];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_u32_at(1, (1 << 8) | (1 << 31));
+        ptr.write_u32_at(5, 1 | (1 << 4));
+
+        assert_eq!(ptr.read_flags_at::<Mode>(1), Mode::B | Mode::C);
+        assert_eq!(ptr.read_flags_strict_at::<Mode>(1), Some(Mode::B | Mode::C));
+
+        // Bit 4 is not a defined flag.
+        assert_eq!(ptr.read_flags_at::<Mode>(5), Mode::A);
+        assert_eq!(ptr.read_flags_strict_at::<Mode>(5), None);
+    }
+}
+
+#[test]
+fn test_bit_writer_msb_first() {
+    let mut buffer = [0u8; 4];
+    let mut writer = BitWriter::new(buffer.as_mut_ptr(), BitOrder::MsbFirst);
+
+    unsafe {
+        writer.write_bits(0b1, 1);
+        writer.write_bits(0b01011, 5);
+        writer.write_bits(0x3A7, 10); // straddles the first two bytes
+        writer.write_bits(0, 0);
+        writer.write_bits(0xFFFF_FFF5, 3); // only the low 3 bits are written
+        assert_eq!(writer.bit_position(), 19);
+        assert_eq!(writer.finish(), 3);
+    }
+    // 1 01011 1110100111 101 (padding 0)
+    assert_eq!(buffer, [0b1010_1111, 0b1010_0111, 0b1010_0000, 0x00]);
+}
+
+#[test]
+fn test_bit_writer_lsb_first() {
+    let mut buffer = [0u8; 4];
+    let mut writer = BitWriter::new(buffer.as_mut_ptr(), BitOrder::LsbFirst);
+
+    unsafe {
+        writer.write_bits(0b1, 1);
+        writer.write_bits(0b01011, 5);
+        writer.write_bits(0x3A7, 10);
+        writer.write_bits(0b101, 3);
+        assert_eq!(writer.finish(), 3);
+    }
+    assert_eq!(buffer, [0b1101_0111, 0b1110_1001, 0b0000_0101, 0x00]);
+}
+
+#[test]
+fn test_bit_writer_finish_preserves_trailing_bits() {
+    unsafe {
+        let mut buffer = [0xFFu8; 2];
+        let mut writer = BitWriter::new(buffer.as_mut_ptr(), BitOrder::MsbFirst);
+        writer.write_bits(0, 12);
+        assert_eq!(writer.finish(), 2);
+        assert_eq!(buffer, [0x00, 0x0F]);
+
+        let mut buffer = [0xFFu8; 2];
+        let mut writer = BitWriter::new(buffer.as_mut_ptr(), BitOrder::LsbFirst);
+        writer.write_bits(0, 12);
+        assert_eq!(writer.finish(), 2);
+        assert_eq!(buffer, [0x00, 0xF0]);
+
+        // Whole bytes only: nothing pending to merge.
+        let mut buffer = [0xFFu8; 9];
+        let mut writer = BitWriter::new(buffer.as_mut_ptr(), BitOrder::LsbFirst);
+        writer.write_bits(0x0123_4567_89AB_CDEF, 64);
+        assert_eq!(writer.finish(), 8);
+        assert_eq!(buffer[..8], 0x0123_4567_89AB_CDEFu64.to_le_bytes());
+        assert_eq!(buffer[8], 0xFF);
+    }
+}
+
+#[test]
+fn test_bit_reader_msb_first() {
+    // 1 01011 1110100111 101, as written by `test_bit_writer_msb_first`.
+    let data = [0b1010_1111u8, 0b1010_0111, 0b1010_0000];
+    let mut reader = BitReader::new(data.as_ptr(), BitOrder::MsbFirst);
+
+    unsafe {
+        assert_eq!(reader.read_bits(1), 0b1);
+        assert_eq!(reader.read_bits(5), 0b01011);
+        assert_eq!(reader.read_bits(10), 0x3A7); // straddles the first two bytes
+        assert_eq!(reader.read_bits(0), 0);
+        assert_eq!(reader.read_bits(3), 0b101);
+    }
+    assert_eq!(reader.bit_position(), 19);
+}
+
+#[test]
+fn test_bit_reader_lsb_first() {
+    let data = [0b1101_0111u8, 0b1110_1001, 0b0000_0101];
+    let mut reader = BitReader::new(data.as_ptr(), BitOrder::LsbFirst);
+
+    unsafe {
+        assert_eq!(reader.read_bits(1), 0b1);
+        assert_eq!(reader.read_bits(5), 0b01011);
+        assert_eq!(reader.read_bits(10), 0x3A7);
+        assert_eq!(reader.read_bits(3), 0b101);
+    }
+    assert_eq!(reader.bit_position(), 19);
+}
+
+#[test]
+fn test_bit_round_trip() {
+    let fields = [
+        (0x5u64, 3),
+        (0x1FFFF, 17),
+        (0, 1),
+        (0x0123_4567_89AB_CDEF, 64),
+        (0x2A, 7),
+        (0xDEAD_BEEF, 33),
+    ];
+
+    for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+        let mut buffer = [0u8; 16];
+        let mut writer = BitWriter::new(buffer.as_mut_ptr(), order);
+        unsafe {
+            for (value, count) in fields {
+                writer.write_bits(value, count);
+            }
+            assert_eq!(writer.finish(), 16);
+        }
+
+        let mut reader = BitReader::new(buffer.as_ptr(), order);
+        for (value, count) in fields {
+            assert_eq!(unsafe { reader.read_bits(count) }, value, "{order:?}");
+        }
+        assert_eq!(reader.bit_position(), 125);
+    }
+}
+
+#[test]
+fn test_read_bits_at() {
+    let data = 0xFEDC_BA98_7654_3210_0123_4567_89AB_CDEFu128.to_le_bytes();
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_bits_at(0, 0, 0), 0);
+        assert_eq!(ptr.read_bits_at(0, 4, 8), 0xDE);
+        assert_eq!(ptr.read_bits_at(1, 4, 12), 0xABC); // bit offset past the first byte
+        assert_eq!(
+            ptr.read_bits_at(0, 7, 64),
+            (0xFEDC_BA98_7654_3210_0123_4567_89AB_CDEFu128 >> 7) as u64
+        );
+        assert_eq!(ptr.read_bits_at(8, 0, 64), 0xFEDC_BA98_7654_3210);
+    }
+}
+
+#[test]
+fn test_read_signed_bits_at() {
+    for bit_count in [5, 12, 19] {
+        for bit_offset in [0, 3, 7, 13] {
+            for value in [
+                -1i64,
+                -(1 << (bit_count - 1)),
+                -5,
+                5,
+                (1 << (bit_count - 1)) - 1,
+            ] {
+                let field = (value as u64) & ((1 << bit_count) - 1);
+                let data =
+                    ((field as u128) << bit_offset | 1 << (bit_offset + bit_count)).to_le_bytes();
+
+                let read = unsafe { data.as_ptr().read_signed_bits_at(0, bit_offset, bit_count) };
+                assert_eq!(read, value, "{bit_count} bits at bit {bit_offset}");
+            }
+        }
+    }
+
+    unsafe {
+        let data = [0xFFu8; 8];
+        assert_eq!(data.as_ptr().read_signed_bits_at(0, 0, 0), 0);
+        assert_eq!(data.as_ptr().read_signed_bits_at(0, 0, 64), -1);
+        assert_eq!(data.as_ptr().read_signed_bits_at(0, 0, 1), -1);
+    }
+}
+
+#[cfg(all(debug_assertions, not(feature = "no-panic")))]
+#[test]
+#[should_panic(expected = "cannot read 65 bits into a u64")]
+fn test_read_signed_bits_too_wide() {
+    let data = [0u8; 16];
+    unsafe {
+        data.as_ptr().read_signed_bits_at(0, 0, 65);
+    }
+}
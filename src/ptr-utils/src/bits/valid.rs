@@ -0,0 +1,106 @@
+//! Reads of register words carrying a "valid" bit alongside their data.
+
+use crate::UnalignedRead;
+
+/// Trait providing reads that split a word into its data and a valid flag.
+///
+/// Hardware registers often pair a data field with a bit signalling that the data is
+/// valid (or ready). These reads return the word with that bit cleared, plus whether it
+/// was set. Values are read in native endianness, like the rest of [`UnalignedRead`].
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{UnalignedWrite, ValidBitRead};
+///
+/// let mut register = [0u8; 4];
+/// let ptr = register.as_mut_ptr();
+///
+/// unsafe {
+///     // Bit 31 flags a valid 12-bit sample.
+///     ptr.write_u32_at(0, 0x8000_0ABC);
+///     assert_eq!(ptr.read_u32_with_valid_at(0, 1 << 31), (0x0ABC, true));
+/// }
+/// ```
+pub trait ValidBitRead: UnalignedRead + Copy {
+    /// Reads a [`u8`] value from the pointer at the given byte offset and returns it with
+    /// `valid_bit` cleared, and whether `valid_bit` was set.
+    ///
+    /// `valid_bit` is a mask, normally of a single bit; if it has several, the flag is
+    /// `true` when any of them is set, and all of them are cleared.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u8_with_valid_at(self, byte_offset: usize, valid_bit: u8) -> (u8, bool) {
+        let value = self.read_u8_at(byte_offset);
+        (value & !valid_bit, value & valid_bit != 0)
+    }
+
+    /// Reads a [`u16`] value from the pointer at the given byte offset and returns it with
+    /// `valid_bit` cleared, and whether `valid_bit` was set.
+    ///
+    /// `valid_bit` is a mask, normally of a single bit; if it has several, the flag is
+    /// `true` when any of them is set, and all of them are cleared.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u16_with_valid_at(self, byte_offset: usize, valid_bit: u16) -> (u16, bool) {
+        let value = self.read_u16_at(byte_offset);
+        (value & !valid_bit, value & valid_bit != 0)
+    }
+
+    /// Reads a [`u32`] value from the pointer at the given byte offset and returns it with
+    /// `valid_bit` cleared, and whether `valid_bit` was set.
+    ///
+    /// `valid_bit` is a mask, normally of a single bit; if it has several, the flag is
+    /// `true` when any of them is set, and all of them are cleared.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u32_with_valid_at(self, byte_offset: usize, valid_bit: u32) -> (u32, bool) {
+        let value = self.read_u32_at(byte_offset);
+        (value & !valid_bit, value & valid_bit != 0)
+    }
+
+    /// Reads a [`u64`] value from the pointer at the given byte offset and returns it with
+    /// `valid_bit` cleared, and whether `valid_bit` was set.
+    ///
+    /// `valid_bit` is a mask, normally of a single bit; if it has several, the flag is
+    /// `true` when any of them is set, and all of them are cleared.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u64_with_valid_at(self, byte_offset: usize, valid_bit: u64) -> (u64, bool) {
+        let value = self.read_u64_at(byte_offset);
+        (value & !valid_bit, value & valid_bit != 0)
+    }
+
+    /// Reads a [`u128`] value from the pointer at the given byte offset and returns it with
+    /// `valid_bit` cleared, and whether `valid_bit` was set.
+    ///
+    /// `valid_bit` is a mask, normally of a single bit; if it has several, the flag is
+    /// `true` when any of them is set, and all of them are cleared.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u128_with_valid_at(self, byte_offset: usize, valid_bit: u128) -> (u128, bool) {
+        let value = self.read_u128_at(byte_offset);
+        (value & !valid_bit, value & valid_bit != 0)
+    }
+}
+
+impl<P: UnalignedRead + Copy> ValidBitRead for P {}
@@ -0,0 +1,142 @@
+//! A bit-packing writer over a raw pointer.
+
+use super::BitOrder;
+use crate::{UnalignedRead, UnalignedWrite};
+
+/// A sequential writer packing fields of any bit width into the bytes behind a raw
+/// mutable pointer.
+///
+/// Bits are buffered until a whole byte is complete, then written. The final, partially
+/// filled byte is only written by [`finish`](Self::finish), which merges it into the
+/// byte already in memory so the bits past the end of the stream are preserved.
+///
+/// No bounds are tracked; all writes are `unsafe`.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{BitOrder, BitWriter};
+///
+/// let mut buffer = [0u8; 2];
+/// let mut writer = BitWriter::new(buffer.as_mut_ptr(), BitOrder::MsbFirst);
+///
+/// unsafe {
+///     writer.write_bits(0b101, 3);
+///     writer.write_bits(0b1_1110_0001, 9);
+///     assert_eq!(writer.finish(), 2);
+/// }
+/// assert_eq!(buffer, [0b1011_1110, 0b0001_0000]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitWriter {
+    base: *mut u8,
+    order: BitOrder,
+    /// Number of whole bytes written.
+    byte_position: usize,
+    /// Pending bits of the current byte, right-aligned.
+    pending: u8,
+    /// Number of valid bits in `pending` (always less than 8).
+    pending_bits: u32,
+}
+
+impl BitWriter {
+    /// Creates a writer positioned at the first bit of `base`.
+    #[inline(always)]
+    pub const fn new(base: *mut u8, order: BitOrder) -> Self {
+        Self {
+            base,
+            order,
+            byte_position: 0,
+            pending: 0,
+            pending_bits: 0,
+        }
+    }
+
+    /// Returns the base pointer the writer was created with.
+    #[inline(always)]
+    pub const fn base(&self) -> *mut u8 {
+        self.base
+    }
+
+    /// Returns the bit order the writer was created with.
+    #[inline(always)]
+    pub const fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Returns the number of bits written so far, including those not yet flushed.
+    #[inline(always)]
+    pub const fn bit_position(&self) -> usize {
+        self.byte_position * 8 + self.pending_bits as usize
+    }
+
+    /// Writes the low `count` bits of `value`, ignoring any bits above them.
+    ///
+    /// With [`BitOrder::MsbFirst`] the field is written most significant bit first, with
+    /// [`BitOrder::LsbFirst`] least significant bit first. A `count` of 0 writes nothing.
+    ///
+    /// # Safety
+    /// - The bytes the completed bits fall in must be valid for writing
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - `count` must be no larger than 64 (checked in debug builds)
+    #[inline]
+    pub unsafe fn write_bits(&mut self, value: u64, count: u32) {
+        debug_check!(count <= 64, "cannot write {count} bits from a u64");
+        let count = count.min(64);
+        // The wrapping operations below never wrap, and `pending_bits` is always below 8, so
+        // masking it is a no-op; both only keep overflow checks out of `no-panic` builds.
+        let mut remaining = count;
+        while remaining > 0 {
+            let used = self.pending_bits & 7;
+            let take = remaining.min(8 - used);
+            let mask = (1u64 << take) - 1;
+            match self.order {
+                BitOrder::MsbFirst => {
+                    let chunk = value.wrapping_shr(remaining - take) & mask;
+                    self.pending = ((self.pending as u64) << take | chunk) as u8;
+                }
+                BitOrder::LsbFirst => {
+                    let chunk = (value >> (count - remaining)) & mask;
+                    self.pending |= (chunk << used) as u8;
+                }
+            }
+            self.pending_bits = used + take;
+            remaining -= take;
+
+            if self.pending_bits == 8 {
+                self.base.write_u8_at(self.byte_position, self.pending);
+                self.byte_position = self.byte_position.wrapping_add(1);
+                self.pending = 0;
+                self.pending_bits = 0;
+            }
+        }
+    }
+
+    /// Flushes the partially filled final byte, if any, and returns the number of bytes
+    /// the stream occupies.
+    ///
+    /// The pending bits are merged into the byte in memory with a read-modify-write, so
+    /// its remaining bits keep their previous values.
+    ///
+    /// # Safety
+    /// - If bits are pending, the final byte must be valid for reading and writing
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    #[inline]
+    pub unsafe fn finish(self) -> usize {
+        if self.pending_bits == 0 {
+            return self.byte_position;
+        }
+
+        let (bits, mask) = match self.order {
+            BitOrder::MsbFirst => {
+                let shift = 8 - self.pending_bits;
+                (self.pending << shift, 0xFFu8 << shift)
+            }
+            BitOrder::LsbFirst => (self.pending, 0xFFu8 >> (8 - self.pending_bits)),
+        };
+        let existing = self.base.read_u8_at(self.byte_position);
+        self.base
+            .write_u8_at(self.byte_position, (existing & !mask) | bits);
+        self.byte_position.wrapping_add(1)
+    }
+}
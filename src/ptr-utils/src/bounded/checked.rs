@@ -0,0 +1,173 @@
+//! Writes to a [`Bounded`] region that are always bounds-checked.
+
+use core::mem::size_of;
+
+use super::{Bounded, WriteError};
+use crate::UnalignedWrite;
+
+impl<P: UnalignedWrite + Copy> Bounded<P> {
+    /// Returns `Ok(())` if `len` bytes starting at `byte_offset` lie within the region,
+    /// or [`WriteError::OutOfBounds`] describing the shortfall.
+    #[inline]
+    pub fn check_write(&self, byte_offset: usize, len: usize) -> Result<(), WriteError> {
+        match byte_offset.checked_add(len) {
+            Some(end) if end <= self.len() => Ok(()),
+            _ => Err(WriteError::OutOfBounds {
+                offset: byte_offset,
+                needed: len,
+                available: self.len().saturating_sub(byte_offset),
+                len: self.len(),
+            }),
+        }
+    }
+
+    /// Writes a value of any [`Copy`] type `T` at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    ///
+    /// Unlike the [`UnalignedWrite`] methods, the check is performed in release builds
+    /// too, so this is safe: validity of the region was promised to [`Bounded::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::{Bounded, WriteError};
+    ///
+    /// let mut buffer = [0u8; 4];
+    /// let ptr = unsafe { Bounded::new(buffer.as_mut_ptr(), buffer.len()) };
+    ///
+    /// assert_eq!(ptr.write_u16_checked_at(2, 0xFFFF), Ok(()));
+    /// assert_eq!(
+    ///     ptr.write_u32_checked_at(1, 0),
+    ///     Err(WriteError::OutOfBounds { offset: 1, needed: 4, available: 3, len: 4 })
+    /// );
+    /// assert_eq!(buffer, [0, 0, 0xFF, 0xFF]);
+    /// ```
+    #[inline(always)]
+    pub fn write_checked_at<T: Copy>(self, byte_offset: usize, value: T) -> Result<(), WriteError> {
+        self.check_write(byte_offset, size_of::<T>())?;
+        // SAFETY: the range was checked against the length promised to `Bounded::new`.
+        unsafe { self.ptr.write_at(byte_offset, value) };
+        Ok(())
+    }
+
+    // Unsigned integer types
+
+    /// Writes a [`u8`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_u8_checked_at(self, byte_offset: usize, value: u8) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes a [`u16`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_u16_checked_at(self, byte_offset: usize, value: u16) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes a [`u32`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_u32_checked_at(self, byte_offset: usize, value: u32) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes a [`u64`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_u64_checked_at(self, byte_offset: usize, value: u64) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes a [`u128`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_u128_checked_at(self, byte_offset: usize, value: u128) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes a [`usize`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_usize_checked_at(
+        self,
+        byte_offset: usize,
+        value: usize,
+    ) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    // Signed integer types
+
+    /// Writes an [`i8`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_i8_checked_at(self, byte_offset: usize, value: i8) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes an [`i16`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_i16_checked_at(self, byte_offset: usize, value: i16) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes an [`i32`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_i32_checked_at(self, byte_offset: usize, value: i32) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes an [`i64`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_i64_checked_at(self, byte_offset: usize, value: i64) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes an [`i128`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_i128_checked_at(self, byte_offset: usize, value: i128) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes an [`isize`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_isize_checked_at(
+        self,
+        byte_offset: usize,
+        value: isize,
+    ) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    // Floating point types
+
+    /// Writes a [`f32`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_f32_checked_at(self, byte_offset: usize, value: f32) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    /// Writes a [`f64`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_f64_checked_at(self, byte_offset: usize, value: f64) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+
+    // Boolean
+
+    /// Writes a [`bool`] value at the given byte offset, or returns
+    /// [`WriteError::OutOfBounds`] without writing anything if it does not fit.
+    #[inline(always)]
+    pub fn write_bool_checked_at(self, byte_offset: usize, value: bool) -> Result<(), WriteError> {
+        self.write_checked_at(byte_offset, value)
+    }
+}
@@ -0,0 +1,52 @@
+//! Errors returned by checked writes.
+
+use core::fmt;
+
+/// Why a checked write was refused.
+///
+/// Returned by the checked [`Bounded`](crate::Bounded) writes, and by
+/// [`EndianWrite::write_uint_dyn_with_policy_at`](crate::EndianWrite::write_uint_dyn_with_policy_at)
+/// under [`OverflowPolicy::Error`](crate::OverflowPolicy::Error).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WriteError {
+    /// The write extends past the end of the region; nothing was written.
+    OutOfBounds {
+        /// Byte offset the write started at.
+        offset: usize,
+        /// Number of bytes the write needed.
+        needed: usize,
+        /// Number of bytes available from `offset` to the end of the region.
+        available: usize,
+        /// Length of the whole region in bytes.
+        len: usize,
+    },
+    /// The value does not fit in the field being written; nothing was written.
+    Overflow {
+        /// Byte offset of the field.
+        offset: usize,
+        /// Width of the field in bytes.
+        byte_count: usize,
+    },
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            WriteError::OutOfBounds {
+                offset,
+                needed,
+                len,
+                ..
+            } => write!(
+                f,
+                "out of bounds: need {needed} bytes at offset {offset}, buffer is {len} bytes"
+            ),
+            WriteError::Overflow { offset, byte_count } => write!(
+                f,
+                "value does not fit in {byte_count} bytes at offset {offset}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for WriteError {}
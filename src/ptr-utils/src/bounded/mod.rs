@@ -0,0 +1,14 @@
+//! Raw pointers carrying a length, with accesses bounds-checked in debug builds, and
+//! checked writes that are bounds-checked always.
+
+pub mod checked;
+pub mod error;
+pub mod ptr;
+pub mod read;
+pub mod write;
+
+#[cfg(test)]
+mod tests;
+
+pub use error::WriteError;
+pub use ptr::Bounded;
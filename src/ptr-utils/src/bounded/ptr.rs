@@ -0,0 +1,77 @@
+//! The [`Bounded`] pointer wrapper.
+
+use core::mem::size_of;
+
+/// A raw pointer paired with the length of the region it points to.
+///
+/// [`Bounded`] implements [`UnalignedRead`](crate::UnalignedRead) and
+/// [`UnalignedWrite`](crate::UnalignedWrite) (and therefore every extension trait
+/// built on them), keeping the raw, `unsafe` API. Each access is checked against the
+/// length with [`debug_assert!`], so out-of-bounds accesses panic in debug builds but
+/// compile to the unchecked pointer access in release.
+///
+/// This sits between the raw pointer methods and the fully safe [`Span`](crate::Span):
+/// the length is asserted once at construction, then accesses are cheap.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{Bounded, UnalignedRead, UnalignedWrite};
+///
+/// let mut buffer = [0u8; 8];
+/// let ptr = unsafe { Bounded::new(buffer.as_mut_ptr(), buffer.len()) };
+///
+/// unsafe {
+///     ptr.write_u32_at(4, 0x12345678);
+///     assert_eq!(ptr.read_u32_at(4), 0x12345678);
+///     // ptr.read_u32_at(5) would panic in debug builds.
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounded<P> {
+    pub(crate) ptr: P,
+    len: usize,
+}
+
+impl<P> Bounded<P> {
+    /// Wraps `ptr`, recording that it points to `len` accessible bytes.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `len` bytes (and writing them, if written
+    ///   through) for as long as the [`Bounded`] is used
+    #[inline(always)]
+    pub const unsafe fn new(ptr: P, len: usize) -> Self {
+        Self { ptr, len }
+    }
+
+    /// Returns the length of the region in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the region contains no bytes.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the wrapped pointer.
+    #[inline(always)]
+    pub fn into_inner(self) -> P {
+        self.ptr
+    }
+
+    /// Asserts, in debug builds only, that a `V` at `byte_offset` lies within the region.
+    #[inline(always)]
+    pub(crate) fn debug_check<V>(&self, byte_offset: usize) {
+        debug_check!(
+            byte_offset
+                .checked_add(size_of::<V>())
+                .is_some_and(|end| end <= self.len),
+            "access of {} bytes at offset {byte_offset} out of bounds for region of {} bytes",
+            size_of::<V>(),
+            self.len
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! [`UnalignedRead`] for [`Bounded`], checked against the length in debug builds.
+
+use super::Bounded;
+use crate::UnalignedRead;
+
+impl<P: UnalignedRead + Copy> UnalignedRead for Bounded<P> {
+    // Unsigned integer types
+
+    #[inline(always)]
+    unsafe fn read_u8_at(self, byte_offset: usize) -> u8 {
+        self.debug_check::<u8>(byte_offset);
+        self.ptr.read_u8_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u16_at(self, byte_offset: usize) -> u16 {
+        self.debug_check::<u16>(byte_offset);
+        self.ptr.read_u16_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u32_at(self, byte_offset: usize) -> u32 {
+        self.debug_check::<u32>(byte_offset);
+        self.ptr.read_u32_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u64_at(self, byte_offset: usize) -> u64 {
+        self.debug_check::<u64>(byte_offset);
+        self.ptr.read_u64_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u128_at(self, byte_offset: usize) -> u128 {
+        self.debug_check::<u128>(byte_offset);
+        self.ptr.read_u128_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_usize_at(self, byte_offset: usize) -> usize {
+        self.debug_check::<usize>(byte_offset);
+        self.ptr.read_usize_at(byte_offset)
+    }
+
+    // Signed integer types
+
+    #[inline(always)]
+    unsafe fn read_i8_at(self, byte_offset: usize) -> i8 {
+        self.debug_check::<i8>(byte_offset);
+        self.ptr.read_i8_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i16_at(self, byte_offset: usize) -> i16 {
+        self.debug_check::<i16>(byte_offset);
+        self.ptr.read_i16_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i32_at(self, byte_offset: usize) -> i32 {
+        self.debug_check::<i32>(byte_offset);
+        self.ptr.read_i32_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i64_at(self, byte_offset: usize) -> i64 {
+        self.debug_check::<i64>(byte_offset);
+        self.ptr.read_i64_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i128_at(self, byte_offset: usize) -> i128 {
+        self.debug_check::<i128>(byte_offset);
+        self.ptr.read_i128_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_isize_at(self, byte_offset: usize) -> isize {
+        self.debug_check::<isize>(byte_offset);
+        self.ptr.read_isize_at(byte_offset)
+    }
+
+    // Floating point types
+
+    #[inline(always)]
+    unsafe fn read_f32_at(self, byte_offset: usize) -> f32 {
+        self.debug_check::<f32>(byte_offset);
+        self.ptr.read_f32_at(byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_f64_at(self, byte_offset: usize) -> f64 {
+        self.debug_check::<f64>(byte_offset);
+        self.ptr.read_f64_at(byte_offset)
+    }
+
+    // Boolean type
+
+    #[inline(always)]
+    unsafe fn read_bool_at(self, byte_offset: usize) -> bool {
+        self.debug_check::<bool>(byte_offset);
+        self.ptr.read_bool_at(byte_offset)
+    }
+
+    // Generic
+
+    #[inline(always)]
+    unsafe fn read_at<V: Copy>(self, byte_offset: usize) -> V {
+        self.debug_check::<V>(byte_offset);
+        self.ptr.read_at(byte_offset)
+    }
+}
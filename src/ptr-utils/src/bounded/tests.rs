@@ -0,0 +1,146 @@
+use core::mem::size_of;
+
+use super::*;
+use crate::{Endian, EndianRead, UnalignedRead, UnalignedWrite};
+
+#[test]
+fn test_bounded_read_write() {
+    let mut buffer = [0u8; 16];
+    let ptr = unsafe { Bounded::new(buffer.as_mut_ptr(), buffer.len()) };
+
+    assert_eq!(ptr.len(), 16);
+    assert!(!ptr.is_empty());
+
+    unsafe {
+        ptr.write_u64_at(8, 0x0102030405060708);
+        ptr.write_f32_at(0, 1.5);
+        assert_eq!(ptr.read_u64_at(8), 0x0102030405060708);
+        assert_eq!(ptr.read_f32_at(0), 1.5);
+        assert_eq!(ptr.read_at::<[u8; 2]>(14), [buffer[14], buffer[15]]);
+    }
+}
+
+#[test]
+fn test_bounded_extension_traits() {
+    let data = [1u8, 2, 3, 4];
+    let ptr = unsafe { Bounded::new(data.as_ptr(), data.len()) };
+
+    unsafe {
+        assert_eq!(ptr.read_u32_endian_at(0, Endian::Big), 0x01020304);
+    }
+}
+
+#[cfg(all(debug_assertions, not(feature = "no-panic")))]
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_bounded_out_of_bounds_panics_in_debug() {
+    let data = [0u8; 4];
+    let ptr = unsafe { Bounded::new(data.as_ptr(), data.len()) };
+    unsafe {
+        ptr.read_u32_at(1);
+    }
+}
+
+#[test]
+fn test_write_checked_up_to_capacity() {
+    let mut buffer = [0u8; 12];
+    let ptr = unsafe { Bounded::new(buffer.as_mut_ptr(), 8) };
+
+    // Writes ending exactly at the capacity succeed.
+    assert_eq!(ptr.write_u64_checked_at(0, u64::MAX), Ok(()));
+    assert_eq!(ptr.write_u32_checked_at(4, 0), Ok(()));
+    assert_eq!(ptr.write_i16_checked_at(6, -1), Ok(()));
+    assert_eq!(ptr.write_bool_checked_at(7, false), Ok(()));
+    assert_eq!(ptr.write_f32_checked_at(2, 0.0), Ok(()));
+
+    // One byte over must error without writing anything, including in-bounds bytes.
+    assert_eq!(
+        ptr.write_u32_checked_at(5, u32::MAX),
+        Err(WriteError::OutOfBounds {
+            offset: 5,
+            needed: 4,
+            available: 3,
+            len: 8
+        })
+    );
+    assert_eq!(
+        ptr.write_u8_checked_at(8, 0xFF),
+        Err(WriteError::OutOfBounds {
+            offset: 8,
+            needed: 1,
+            available: 0,
+            len: 8
+        })
+    );
+    assert_eq!(
+        ptr.write_checked_at(usize::MAX, 0u16),
+        Err(WriteError::OutOfBounds {
+            offset: usize::MAX,
+            needed: 2,
+            available: 0,
+            len: 8
+        })
+    );
+
+    assert_eq!(buffer, [0xFF, 0xFF, 0, 0, 0, 0, 0xFF, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_write_checked_all_types() {
+    let mut buffer = [0u8; 16];
+    let ptr = unsafe { Bounded::new(buffer.as_mut_ptr(), buffer.len()) };
+
+    assert!(ptr.write_u16_checked_at(0, 1).is_ok());
+    assert!(ptr.write_u128_checked_at(0, 1).is_ok());
+    assert!(ptr.write_i128_checked_at(1, 1).is_err());
+    assert!(ptr
+        .write_usize_checked_at(16 - size_of::<usize>(), 1)
+        .is_ok());
+    assert!(ptr
+        .write_isize_checked_at(17 - size_of::<usize>(), 1)
+        .is_err());
+    assert!(ptr.write_i8_checked_at(15, -1).is_ok());
+    assert!(ptr.write_i32_checked_at(12, -1).is_ok());
+    assert!(ptr.write_i64_checked_at(9, -1).is_err());
+    assert!(ptr.write_f64_checked_at(8, 1.0).is_ok());
+
+    unsafe {
+        assert_eq!(ptr.read_f64_at(8), 1.0);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_error_display() {
+    use std::string::ToString;
+
+    let error = WriteError::OutOfBounds {
+        offset: 12,
+        needed: 4,
+        available: 2,
+        len: 14,
+    };
+    assert_eq!(
+        error.to_string(),
+        "out of bounds: need 4 bytes at offset 12, buffer is 14 bytes"
+    );
+    // An offset past the end still reports the real length.
+    let error = WriteError::OutOfBounds {
+        offset: 20,
+        needed: 4,
+        available: 0,
+        len: 14,
+    };
+    assert_eq!(
+        error.to_string(),
+        "out of bounds: need 4 bytes at offset 20, buffer is 14 bytes"
+    );
+    let error = WriteError::Overflow {
+        offset: 0,
+        byte_count: 3,
+    };
+    assert_eq!(
+        error.to_string(),
+        "value does not fit in 3 bytes at offset 0"
+    );
+}
@@ -0,0 +1,112 @@
+//! [`UnalignedWrite`] for [`Bounded`], checked against the length in debug builds.
+
+use super::Bounded;
+use crate::UnalignedWrite;
+
+impl<P: UnalignedWrite + Copy> UnalignedWrite for Bounded<P> {
+    // Unsigned integer types
+
+    #[inline(always)]
+    unsafe fn write_u8_at(self, byte_offset: usize, value: u8) {
+        self.debug_check::<u8>(byte_offset);
+        self.ptr.write_u8_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_u16_at(self, byte_offset: usize, value: u16) {
+        self.debug_check::<u16>(byte_offset);
+        self.ptr.write_u16_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_u32_at(self, byte_offset: usize, value: u32) {
+        self.debug_check::<u32>(byte_offset);
+        self.ptr.write_u32_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_u64_at(self, byte_offset: usize, value: u64) {
+        self.debug_check::<u64>(byte_offset);
+        self.ptr.write_u64_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_u128_at(self, byte_offset: usize, value: u128) {
+        self.debug_check::<u128>(byte_offset);
+        self.ptr.write_u128_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_usize_at(self, byte_offset: usize, value: usize) {
+        self.debug_check::<usize>(byte_offset);
+        self.ptr.write_usize_at(byte_offset, value);
+    }
+
+    // Signed integer types
+
+    #[inline(always)]
+    unsafe fn write_i8_at(self, byte_offset: usize, value: i8) {
+        self.debug_check::<i8>(byte_offset);
+        self.ptr.write_i8_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_i16_at(self, byte_offset: usize, value: i16) {
+        self.debug_check::<i16>(byte_offset);
+        self.ptr.write_i16_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_i32_at(self, byte_offset: usize, value: i32) {
+        self.debug_check::<i32>(byte_offset);
+        self.ptr.write_i32_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_i64_at(self, byte_offset: usize, value: i64) {
+        self.debug_check::<i64>(byte_offset);
+        self.ptr.write_i64_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_i128_at(self, byte_offset: usize, value: i128) {
+        self.debug_check::<i128>(byte_offset);
+        self.ptr.write_i128_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_isize_at(self, byte_offset: usize, value: isize) {
+        self.debug_check::<isize>(byte_offset);
+        self.ptr.write_isize_at(byte_offset, value);
+    }
+
+    // Floating point types
+
+    #[inline(always)]
+    unsafe fn write_f32_at(self, byte_offset: usize, value: f32) {
+        self.debug_check::<f32>(byte_offset);
+        self.ptr.write_f32_at(byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_f64_at(self, byte_offset: usize, value: f64) {
+        self.debug_check::<f64>(byte_offset);
+        self.ptr.write_f64_at(byte_offset, value);
+    }
+
+    // Boolean type
+
+    #[inline(always)]
+    unsafe fn write_bool_at(self, byte_offset: usize, value: bool) {
+        self.debug_check::<bool>(byte_offset);
+        self.ptr.write_bool_at(byte_offset, value);
+    }
+
+    // Generic
+
+    #[inline(always)]
+    unsafe fn write_at<V: Copy>(self, byte_offset: usize, value: V) {
+        self.debug_check::<V>(byte_offset);
+        self.ptr.write_at(byte_offset, value);
+    }
+}
@@ -0,0 +1,23 @@
+//! `const fn` reads from byte slices, for parsing tables at compile time.
+//!
+//! Raw pointer dereferences can't be used in `const` contexts, so these functions
+//! index the slice and assemble the value instead. An out-of-bounds read panics,
+//! which in a `const` context becomes a compile error.
+//!
+//! # Example
+//!
+//! ```
+//! use ptr_utils::bytes;
+//!
+//! const TABLE: [u8; 6] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+//! const HEADER: u32 = bytes::read_u32_be(&TABLE, 1);
+//!
+//! assert_eq!(HEADER, 0x02030405);
+//! ```
+
+pub mod read;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::*;
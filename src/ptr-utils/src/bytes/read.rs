@@ -0,0 +1,157 @@
+//! Const little-endian and big-endian integer reads.
+
+/// Copies `N` bytes starting at `offset` into an array.
+#[inline(always)]
+const fn array_at<const N: usize>(bytes: &[u8], offset: usize) -> [u8; N] {
+    let mut out = [0u8; N];
+    let mut x = 0;
+    while x < N {
+        out[x] = bytes[offset + x];
+        x += 1;
+    }
+    out
+}
+
+/// Reads a little-endian [`u16`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 2` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_u16_le(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(array_at(bytes, offset))
+}
+
+/// Reads a big-endian [`u16`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 2` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_u16_be(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes(array_at(bytes, offset))
+}
+
+/// Reads a little-endian [`u32`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 4` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_u32_le(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(array_at(bytes, offset))
+}
+
+/// Reads a big-endian [`u32`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 4` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_u32_be(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(array_at(bytes, offset))
+}
+
+/// Reads a little-endian [`u64`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 8` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_u64_le(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(array_at(bytes, offset))
+}
+
+/// Reads a big-endian [`u64`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 8` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_u64_be(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_be_bytes(array_at(bytes, offset))
+}
+
+/// Reads a little-endian [`u128`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 16` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_u128_le(bytes: &[u8], offset: usize) -> u128 {
+    u128::from_le_bytes(array_at(bytes, offset))
+}
+
+/// Reads a big-endian [`u128`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 16` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_u128_be(bytes: &[u8], offset: usize) -> u128 {
+    u128::from_be_bytes(array_at(bytes, offset))
+}
+
+/// Reads a little-endian [`i16`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 2` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_i16_le(bytes: &[u8], offset: usize) -> i16 {
+    i16::from_le_bytes(array_at(bytes, offset))
+}
+
+/// Reads a big-endian [`i16`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 2` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_i16_be(bytes: &[u8], offset: usize) -> i16 {
+    i16::from_be_bytes(array_at(bytes, offset))
+}
+
+/// Reads a little-endian [`i32`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 4` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_i32_le(bytes: &[u8], offset: usize) -> i32 {
+    i32::from_le_bytes(array_at(bytes, offset))
+}
+
+/// Reads a big-endian [`i32`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 4` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_i32_be(bytes: &[u8], offset: usize) -> i32 {
+    i32::from_be_bytes(array_at(bytes, offset))
+}
+
+/// Reads a little-endian [`i64`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 8` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_i64_le(bytes: &[u8], offset: usize) -> i64 {
+    i64::from_le_bytes(array_at(bytes, offset))
+}
+
+/// Reads a big-endian [`i64`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 8` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_i64_be(bytes: &[u8], offset: usize) -> i64 {
+    i64::from_be_bytes(array_at(bytes, offset))
+}
+
+/// Reads a little-endian [`i128`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 16` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_i128_le(bytes: &[u8], offset: usize) -> i128 {
+    i128::from_le_bytes(array_at(bytes, offset))
+}
+
+/// Reads a big-endian [`i128`] from `bytes` at the given byte offset.
+///
+/// # Panics
+/// If `offset + 16` exceeds `bytes.len()`.
+#[inline(always)]
+pub const fn read_i128_be(bytes: &[u8], offset: usize) -> i128 {
+    i128::from_be_bytes(array_at(bytes, offset))
+}
@@ -0,0 +1,43 @@
+use super::*;
+
+const DATA: [u8; 17] = [
+    0xAA, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32,
+    0x10,
+];
+
+#[test]
+fn test_const_reads() {
+    const U16: u16 = read_u16_le(&DATA, 1);
+    const U32: u32 = read_u32_be(&DATA, 1);
+    const I64: i64 = read_i64_le(&DATA, 9);
+    const U128: u128 = read_u128_be(&DATA, 1);
+
+    assert_eq!(U16, 0x2301);
+    assert_eq!(U32, 0x01234567);
+    assert_eq!(
+        I64,
+        i64::from_le_bytes([0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10])
+    );
+    assert_eq!(U128, 0x0123456789ABCDEFFEDCBA9876543210);
+}
+
+#[test]
+fn test_runtime_reads_match_std() {
+    for offset in 0..=1 {
+        let b4: [u8; 4] = DATA[offset..offset + 4].try_into().unwrap();
+        let b8: [u8; 8] = DATA[offset..offset + 8].try_into().unwrap();
+        assert_eq!(read_u32_le(&DATA, offset), u32::from_le_bytes(b4));
+        assert_eq!(read_i32_be(&DATA, offset), i32::from_be_bytes(b4));
+        assert_eq!(read_u64_be(&DATA, offset), u64::from_be_bytes(b8));
+        assert_eq!(
+            read_i16_le(&DATA, offset),
+            i16::from_le_bytes([b4[0], b4[1]])
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_out_of_bounds_panics() {
+    read_u32_le(&DATA, 14);
+}
@@ -0,0 +1,16 @@
+//! Reads of consecutive values into owned collections.
+//!
+//! Each collection type is behind the feature flag of the crate providing it.
+
+#[cfg(feature = "smallvec")]
+pub mod smallvec;
+#[cfg(feature = "alloc")]
+pub mod vec;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "smallvec")]
+pub use self::smallvec::SmallVecRead;
+#[cfg(feature = "alloc")]
+pub use vec::VecRead;
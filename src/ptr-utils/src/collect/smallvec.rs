@@ -0,0 +1,272 @@
+//! Reads of consecutive values into a [`SmallVec`].
+
+use smallvec::SmallVec;
+
+use crate::{AsBytePtr, IterRead, UnalignedRead};
+
+/// Trait providing reads of `count` consecutive values into a [`SmallVec`], which stays
+/// on the stack while `count <= N` and only allocates for larger counts.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::SmallVecRead;
+///
+/// let data = [1u32, 2, 3].map(u32::to_ne_bytes).concat();
+/// let values = unsafe { data.as_ptr().read_smallvec_u32_at::<4>(0, 3) };
+///
+/// assert_eq!(values.as_slice(), &[1, 2, 3]);
+/// assert!(!values.spilled());
+/// ```
+pub trait SmallVecRead: UnalignedRead + AsBytePtr + Copy {
+    /// Reads `count` consecutive `T` values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<T>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    /// - Every value read must be a valid `T`
+    #[inline]
+    unsafe fn read_smallvec_at<T: Copy, const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[T; N]> {
+        let mut values = SmallVec::with_capacity(count);
+        values.extend(self.iter_at::<T>(byte_offset, count));
+        values
+    }
+
+    // Unsigned integer types
+
+    /// Reads `count` consecutive [`u8`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u8>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_u8_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[u8; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`u16`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u16>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_u16_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[u16; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`u32`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_u32_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[u32; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`u64`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_u64_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[u64; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`u128`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u128>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_u128_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[u128; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`usize`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<usize>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_usize_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[usize; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    // Signed integer types
+
+    /// Reads `count` consecutive [`i8`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i8>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_i8_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[i8; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`i16`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i16>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_i16_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[i16; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`i32`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_i32_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[i32; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`i64`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_i64_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[i64; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`i128`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i128>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_i128_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[i128; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`isize`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<isize>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_isize_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[isize; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    // Floating point types
+
+    /// Reads `count` consecutive [`f32`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<f32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_f32_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[f32; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`f64`] values starting at the given byte offset into a
+    /// [`SmallVec`] with inline capacity `N`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<f64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_smallvec_f64_at<const N: usize>(
+        self,
+        byte_offset: usize,
+        count: usize,
+    ) -> SmallVec<[f64; N]> {
+        self.read_smallvec_at(byte_offset, count)
+    }
+}
+
+impl<P: UnalignedRead + AsBytePtr + Copy> SmallVecRead for P {}
@@ -0,0 +1,57 @@
+#[allow(unused_imports)]
+use super::*;
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_read_smallvec() {
+    use crate::UnalignedWrite;
+
+    let mut buffer = [0u8; 41];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        for x in 0..10 {
+            ptr.write_u32_at(1 + x * 4, x as u32 * 3);
+        }
+
+        let inline = ptr.read_smallvec_u32_at::<4>(1, 4);
+        assert_eq!(inline.as_slice(), &[0, 3, 6, 9]);
+        assert!(!inline.spilled());
+
+        let spilled = ptr.read_smallvec_u32_at::<4>(1, 10);
+        assert_eq!(spilled.len(), 10);
+        assert_eq!(spilled[9], 27);
+        assert!(spilled.spilled());
+
+        let empty = ptr.read_smallvec_at::<u16, 2>(0, 0);
+        assert!(empty.is_empty());
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_read_vec() {
+    use crate::{Endian, UnalignedWrite};
+
+    let mut buffer = [0u8; 17];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        for x in 0..4 {
+            ptr.write_u32_at(1 + x * 4, (0x01020304 * x as u32).to_le());
+        }
+
+        let values = ptr.read_vec_u32_endian_at(1, 4, Endian::Little);
+        assert_eq!(values, [0, 0x01020304, 0x02040608, 0x0306090C]);
+        assert_eq!(
+            ptr.read_vec_u32_endian_at(5, 2, Endian::Big),
+            [0x04030201, 0x08060402]
+        );
+        assert_eq!(
+            ptr.read_vec_u32_at(5, 3),
+            ptr.read_vec_u32_endian_at(5, 3, Endian::NATIVE)
+        );
+        assert_eq!(ptr.read_vec_u8_at(5, 4), [0x04, 0x03, 0x02, 0x01]);
+        assert!(ptr.read_vec_at::<u16>(1, 0).is_empty());
+    }
+}
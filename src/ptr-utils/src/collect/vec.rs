@@ -0,0 +1,404 @@
+//! Reads of consecutive values into a [`Vec`].
+
+use alloc::vec::Vec;
+use core::mem::size_of;
+
+use crate::{AsBytePtr, Endian, IterRead, Numeric, NumericRead, UnalignedRead};
+
+/// Trait providing reads of `count` consecutive values into an owned [`Vec`], the
+/// allocating counterpart to reading into a caller-provided slice.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{Endian, VecRead};
+///
+/// let data = [0x00u8, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02];
+/// let ptr = data.as_ptr();
+///
+/// unsafe {
+///     assert_eq!(ptr.read_vec_u32_endian_at(0, 2, Endian::Big), [1, 2]);
+///     assert_eq!(ptr.read_vec_u8_at(3, 2), [0x01, 0x00]);
+/// }
+/// ```
+pub trait VecRead: UnalignedRead + AsBytePtr + Copy {
+    /// Reads `count` consecutive `T` values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<T>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    /// - Every value read must be a valid `T`
+    #[inline]
+    unsafe fn read_vec_at<T: Copy>(self, byte_offset: usize, count: usize) -> Vec<T> {
+        let mut values = Vec::with_capacity(count);
+        values.extend(self.iter_at::<T>(byte_offset, count));
+        values
+    }
+
+    /// Reads `count` consecutive `T` values in the given byte order starting at the given
+    /// byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<T>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_vec_endian_at<T: Numeric>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<T> {
+        (0..count)
+            .map(|x| self.read_numeric_at(byte_offset + x * size_of::<T>(), endian))
+            .collect()
+    }
+
+    // Unsigned integer types
+
+    /// Reads `count` consecutive [`u8`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u8>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_u8_at(self, byte_offset: usize, count: usize) -> Vec<u8> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`u16`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u16>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_u16_at(self, byte_offset: usize, count: usize) -> Vec<u16> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`u16`] values in the given byte order starting at the
+    /// given byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u16>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_u16_endian_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<u16> {
+        self.read_vec_endian_at(byte_offset, count, endian)
+    }
+
+    /// Reads `count` consecutive [`u32`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_u32_at(self, byte_offset: usize, count: usize) -> Vec<u32> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`u32`] values in the given byte order starting at the
+    /// given byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_u32_endian_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<u32> {
+        self.read_vec_endian_at(byte_offset, count, endian)
+    }
+
+    /// Reads `count` consecutive [`u64`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_u64_at(self, byte_offset: usize, count: usize) -> Vec<u64> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`u64`] values in the given byte order starting at the
+    /// given byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_u64_endian_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<u64> {
+        self.read_vec_endian_at(byte_offset, count, endian)
+    }
+
+    /// Reads `count` consecutive [`u128`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u128>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_u128_at(self, byte_offset: usize, count: usize) -> Vec<u128> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`u128`] values in the given byte order starting at the
+    /// given byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u128>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_u128_endian_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<u128> {
+        self.read_vec_endian_at(byte_offset, count, endian)
+    }
+
+    /// Reads `count` consecutive [`usize`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<usize>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_usize_at(self, byte_offset: usize, count: usize) -> Vec<usize> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    // Signed integer types
+
+    /// Reads `count` consecutive [`i8`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i8>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_i8_at(self, byte_offset: usize, count: usize) -> Vec<i8> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`i16`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i16>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_i16_at(self, byte_offset: usize, count: usize) -> Vec<i16> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`i16`] values in the given byte order starting at the
+    /// given byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i16>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_i16_endian_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<i16> {
+        self.read_vec_endian_at(byte_offset, count, endian)
+    }
+
+    /// Reads `count` consecutive [`i32`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_i32_at(self, byte_offset: usize, count: usize) -> Vec<i32> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`i32`] values in the given byte order starting at the
+    /// given byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_i32_endian_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<i32> {
+        self.read_vec_endian_at(byte_offset, count, endian)
+    }
+
+    /// Reads `count` consecutive [`i64`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_i64_at(self, byte_offset: usize, count: usize) -> Vec<i64> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`i64`] values in the given byte order starting at the
+    /// given byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_i64_endian_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<i64> {
+        self.read_vec_endian_at(byte_offset, count, endian)
+    }
+
+    /// Reads `count` consecutive [`i128`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i128>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_i128_at(self, byte_offset: usize, count: usize) -> Vec<i128> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`i128`] values in the given byte order starting at the
+    /// given byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i128>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_i128_endian_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<i128> {
+        self.read_vec_endian_at(byte_offset, count, endian)
+    }
+
+    /// Reads `count` consecutive [`isize`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<isize>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_isize_at(self, byte_offset: usize, count: usize) -> Vec<isize> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    // Floating point types
+
+    /// Reads `count` consecutive [`f32`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<f32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_f32_at(self, byte_offset: usize, count: usize) -> Vec<f32> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`f32`] values in the given byte order starting at the
+    /// given byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<f32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_f32_endian_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<f32> {
+        self.read_vec_endian_at(byte_offset, count, endian)
+    }
+
+    /// Reads `count` consecutive [`f64`] values starting at the given byte offset into a
+    /// [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<f64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_f64_at(self, byte_offset: usize, count: usize) -> Vec<f64> {
+        self.read_vec_at(byte_offset, count)
+    }
+
+    /// Reads `count` consecutive [`f64`] values in the given byte order starting at the
+    /// given byte offset into a [`Vec`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<f64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_vec_f64_endian_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Vec<f64> {
+        self.read_vec_endian_at(byte_offset, count, endian)
+    }
+}
+
+impl<P: UnalignedRead + AsBytePtr + Copy> VecRead for P {}
@@ -0,0 +1,247 @@
+//! Writes that return the offset just past the written value.
+
+use core::mem::size_of;
+
+use crate::UnalignedWrite;
+
+/// Trait providing writes that return the byte offset following the written value.
+///
+/// A lightweight alternative to [`WriteCursor`](crate::WriteCursor) for simple sequential
+/// encoding: the returned offset is passed to the next write.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{AdvanceWrite, UnalignedRead};
+///
+/// let mut buffer = [0u8; 7];
+/// let ptr = buffer.as_mut_ptr();
+///
+/// unsafe {
+///     let offset = ptr.write_u32_advance_at(0, 0xAABBCCDD);
+///     let offset = ptr.write_u16_advance_at(offset, 0x1122);
+///     let offset = ptr.write_u8_advance_at(offset, 0x33);
+///
+///     assert_eq!(offset, 7);
+///     assert_eq!(ptr.read_u16_at(4), 0x1122);
+/// }
+/// ```
+pub trait AdvanceWrite: UnalignedWrite + Copy {
+    // Unsigned integer types
+
+    /// Writes a [`u8`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn write_u8_advance_at(self, byte_offset: usize, value: u8) -> usize {
+        self.write_u8_at(byte_offset, value);
+        byte_offset + 1
+    }
+
+    /// Writes a [`u16`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_u16_advance_at(self, byte_offset: usize, value: u16) -> usize {
+        self.write_u16_at(byte_offset, value);
+        byte_offset + 2
+    }
+
+    /// Writes a [`u32`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_u32_advance_at(self, byte_offset: usize, value: u32) -> usize {
+        self.write_u32_at(byte_offset, value);
+        byte_offset + 4
+    }
+
+    /// Writes a [`u64`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_u64_advance_at(self, byte_offset: usize, value: u64) -> usize {
+        self.write_u64_at(byte_offset, value);
+        byte_offset + 8
+    }
+
+    /// Writes a [`u128`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_u128_advance_at(self, byte_offset: usize, value: u128) -> usize {
+        self.write_u128_at(byte_offset, value);
+        byte_offset + 16
+    }
+
+    /// Writes a [`usize`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing [`size_of::<usize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_usize_advance_at(self, byte_offset: usize, value: usize) -> usize {
+        self.write_usize_at(byte_offset, value);
+        byte_offset + size_of::<usize>()
+    }
+
+    // Signed integer types
+
+    /// Writes an [`i8`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn write_i8_advance_at(self, byte_offset: usize, value: i8) -> usize {
+        self.write_i8_at(byte_offset, value);
+        byte_offset + 1
+    }
+
+    /// Writes an [`i16`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_i16_advance_at(self, byte_offset: usize, value: i16) -> usize {
+        self.write_i16_at(byte_offset, value);
+        byte_offset + 2
+    }
+
+    /// Writes an [`i32`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_i32_advance_at(self, byte_offset: usize, value: i32) -> usize {
+        self.write_i32_at(byte_offset, value);
+        byte_offset + 4
+    }
+
+    /// Writes an [`i64`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_i64_advance_at(self, byte_offset: usize, value: i64) -> usize {
+        self.write_i64_at(byte_offset, value);
+        byte_offset + 8
+    }
+
+    /// Writes an [`i128`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_i128_advance_at(self, byte_offset: usize, value: i128) -> usize {
+        self.write_i128_at(byte_offset, value);
+        byte_offset + 16
+    }
+
+    /// Writes an [`isize`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing [`size_of::<isize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_isize_advance_at(self, byte_offset: usize, value: isize) -> usize {
+        self.write_isize_at(byte_offset, value);
+        byte_offset + size_of::<isize>()
+    }
+
+    // Floating point types
+
+    /// Writes a [`f32`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_f32_advance_at(self, byte_offset: usize, value: f32) -> usize {
+        self.write_f32_at(byte_offset, value);
+        byte_offset + 4
+    }
+
+    /// Writes a [`f64`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_f64_advance_at(self, byte_offset: usize, value: f64) -> usize {
+        self.write_f64_at(byte_offset, value);
+        byte_offset + 8
+    }
+
+    // Boolean
+
+    /// Writes a [`bool`] value at the given byte offset and returns the offset just past it.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn write_bool_advance_at(self, byte_offset: usize, value: bool) -> usize {
+        self.write_bool_at(byte_offset, value);
+        byte_offset + 1
+    }
+
+    // Generic
+
+    /// Writes a value of any [`Copy`] type `T` at the given byte offset and returns the
+    /// offset just past it, `byte_offset + size_of::<T>()`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing [`size_of::<T>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_advance_at<T: Copy>(self, byte_offset: usize, value: T) -> usize {
+        self.write_at(byte_offset, value);
+        byte_offset + size_of::<T>()
+    }
+}
+
+impl<P: UnalignedWrite + Copy> AdvanceWrite for P {}
@@ -0,0 +1,239 @@
+//! Pointer-advancing reads, mirroring the C `*p++` idiom.
+
+use core::mem::size_of;
+
+use crate::unaligned::raw::read_raw;
+
+/// Trait providing reads that advance the pointer they read from.
+///
+/// This is implemented for `&mut *const u8`, so a moving pointer can be read
+/// from and bumped past the read value in a single call; a common pattern when
+/// porting C parsing code.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::AdvancingRead;
+///
+/// let buffer = [0x01u8, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00];
+/// let mut ptr = buffer.as_ptr();
+///
+/// unsafe {
+///     assert_eq!((&mut ptr).read_u8(), 0x01);
+///     assert_eq!((&mut ptr).read_u16(), u16::from_ne_bytes([0x02, 0x00]));
+///     assert_eq!((&mut ptr).read_u32(), u32::from_ne_bytes([0x03, 0x00, 0x00, 0x00]));
+/// }
+///
+/// assert_eq!(ptr, buffer.as_ptr().wrapping_add(7));
+/// ```
+pub trait AdvancingRead {
+    // Unsigned integer types
+
+    /// Reads a [`u8`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    unsafe fn read_u8(self) -> u8;
+
+    /// Reads a [`u16`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_u16(self) -> u16;
+
+    /// Reads a [`u32`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_u32(self) -> u32;
+
+    /// Reads a [`u64`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_u64(self) -> u64;
+
+    /// Reads a [`u128`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_u128(self) -> u128;
+
+    /// Reads a [`usize`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading [`size_of::<usize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_usize(self) -> usize;
+
+    // Signed integer types
+
+    /// Reads an [`i8`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    unsafe fn read_i8(self) -> i8;
+
+    /// Reads an [`i16`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_i16(self) -> i16;
+
+    /// Reads an [`i32`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_i32(self) -> i32;
+
+    /// Reads an [`i64`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_i64(self) -> i64;
+
+    /// Reads an [`i128`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_i128(self) -> i128;
+
+    /// Reads an [`isize`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading [`size_of::<isize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_isize(self) -> isize;
+
+    // Floating point types
+
+    /// Reads an [`f32`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_f32(self) -> f32;
+
+    /// Reads an [`f64`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_f64(self) -> f64;
+
+    // Boolean type
+
+    /// Reads a [`bool`] value at the pointer and advances the pointer past it.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - The byte value must represent a valid [`bool`] (0 or 1)
+    unsafe fn read_bool(self) -> bool;
+}
+
+impl AdvancingRead for &mut *const u8 {
+    #[inline(always)]
+    unsafe fn read_u8(self) -> u8 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u16(self) -> u16 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u32(self) -> u32 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u64(self) -> u64 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u128(self) -> u128 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_usize(self) -> usize {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i8(self) -> i8 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i16(self) -> i16 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i32(self) -> i32 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i64(self) -> i64 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i128(self) -> i128 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_isize(self) -> isize {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_f32(self) -> f32 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_f64(self) -> f64 {
+        read_advance(self)
+    }
+
+    #[inline(always)]
+    unsafe fn read_bool(self) -> bool {
+        read_advance(self)
+    }
+}
+
+#[inline(always)]
+unsafe fn read_advance<V: Copy>(ptr: &mut *const u8) -> V {
+    let value = read_raw::<V>(*ptr, 0);
+    *ptr = ptr.add(size_of::<V>());
+    value
+}
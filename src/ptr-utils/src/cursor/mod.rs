@@ -0,0 +1,18 @@
+//! Sequential reads and writes that track their own position.
+//!
+//! Unlike [`UnalignedRead`](crate::UnalignedRead), which takes an explicit byte offset
+//! for every access, the types in this module advance automatically after each read
+//! or write.
+
+pub mod advance_write;
+pub mod advancing;
+pub mod ptr_cursor;
+pub mod write_cursor;
+
+#[cfg(test)]
+mod tests;
+
+pub use advance_write::AdvanceWrite;
+pub use advancing::AdvancingRead;
+pub use ptr_cursor::{Checkpoint, PtrCursor};
+pub use write_cursor::WriteCursor;
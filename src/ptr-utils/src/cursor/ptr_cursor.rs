@@ -0,0 +1,370 @@
+//! A raw-pointer cursor with explicit position tracking.
+
+use core::mem::size_of;
+
+use crate::unaligned::raw::read_raw;
+
+/// A sequential reader over a raw pointer, tracking a byte position from its base.
+///
+/// Unlike [`AdvancingRead`](crate::AdvancingRead), the base pointer is retained, so the
+/// cursor can report and change its position. No bounds are tracked; all reads are `unsafe`.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::PtrCursor;
+///
+/// let data = [0x01u8, 0x02, 0x00, 0xFF];
+/// let mut cursor = PtrCursor::new(data.as_ptr());
+///
+/// unsafe {
+///     assert_eq!(cursor.read_u8(), 0x01);
+///     assert_eq!(cursor.read_u16(), u16::from_ne_bytes([0x02, 0x00]));
+/// }
+/// assert_eq!(cursor.position(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PtrCursor {
+    base: *const u8,
+    position: usize,
+}
+
+/// A saved [`PtrCursor`] position, created by [`PtrCursor::checkpoint`] and consumed by
+/// [`PtrCursor::restore`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    base: *const u8,
+    position: usize,
+}
+
+impl PtrCursor {
+    /// Creates a cursor positioned at the start of `base`.
+    #[inline(always)]
+    pub const fn new(base: *const u8) -> Self {
+        Self { base, position: 0 }
+    }
+
+    /// Returns the base pointer the cursor was created with.
+    #[inline(always)]
+    pub const fn base(&self) -> *const u8 {
+        self.base
+    }
+
+    /// Returns the current byte position relative to the base pointer.
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bytes consumed since the base pointer, for advancing an
+    /// outer stream past a framed message.
+    ///
+    /// An alias of [`position`](Self::position), named for framing code; reads,
+    /// [`skip`](Self::skip) and [`seek`](Self::seek) all count. `seek(0)` returns the
+    /// cursor to the start of the message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::PtrCursor;
+    ///
+    /// // A message: a 1-byte type, a 2-byte length, then the payload.
+    /// let stream = [0x07u8, 0x02, 0x00, 0xAA, 0xBB, 0x07];
+    /// let mut cursor = PtrCursor::new(stream.as_ptr());
+    ///
+    /// unsafe {
+    ///     cursor.read_u8();
+    ///     let len = u16::from_le(cursor.read_u16());
+    ///     cursor.skip(len as usize);
+    /// }
+    /// assert_eq!(cursor.bytes_consumed(), 5);
+    /// ```
+    #[inline(always)]
+    pub const fn bytes_consumed(&self) -> usize {
+        self.position
+    }
+
+    /// Returns a pointer to the current position.
+    #[inline(always)]
+    pub fn current_ptr(&self) -> *const u8 {
+        self.base.wrapping_add(self.position)
+    }
+
+    /// Moves the cursor to the given byte position relative to the base pointer.
+    #[inline(always)]
+    pub fn seek(&mut self, position: usize) {
+        self.position = position;
+    }
+
+    /// Advances the cursor by `len` bytes without reading.
+    #[inline(always)]
+    pub fn skip(&mut self, len: usize) {
+        self.position += len;
+    }
+
+    /// Saves the current position, to be passed to [`restore`](Self::restore) to rewind
+    /// the cursor after a speculative parse fails.
+    ///
+    /// The [`Checkpoint`] also records the cursor's base pointer, so restoring it into a
+    /// cursor over different memory is caught in debug builds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::PtrCursor;
+    ///
+    /// let data = [0x02u8, 0x34, 0x12];
+    /// let mut cursor = PtrCursor::new(data.as_ptr());
+    ///
+    /// let checkpoint = cursor.checkpoint();
+    /// let tag = unsafe { cursor.read_u8() };
+    /// if tag != 0x01 {
+    ///     // Not the expected branch; rewind and try another.
+    ///     cursor.restore(checkpoint);
+    /// }
+    /// assert_eq!(cursor.position(), 0);
+    /// ```
+    #[inline(always)]
+    pub const fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            base: self.base,
+            position: self.position,
+        }
+    }
+
+    /// Rewinds (or advances) the cursor to a position saved with
+    /// [`checkpoint`](Self::checkpoint).
+    ///
+    /// # Panics
+    /// In debug builds, if `checkpoint` was taken from a cursor with a different base
+    /// pointer.
+    #[inline(always)]
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        debug_check!(
+            checkpoint.base == self.base,
+            "checkpoint was taken from a cursor over different memory"
+        );
+        self.position = checkpoint.position;
+    }
+
+    /// Reads a native endian [`u32`] offset at the current position and moves the cursor to
+    /// `relative_to + offset`, following a field that says where the real data is.
+    ///
+    /// `relative_to` is a position from the base pointer, such as the start of the
+    /// enclosing table; pass 0 for offsets from the start of the file. Save a
+    /// [`checkpoint`](Self::checkpoint) first to return after visiting the target.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::PtrCursor;
+    ///
+    /// // A table at offset 2 whose first field holds the offset of its data, from the table.
+    /// let mut file = [0u8; 12];
+    /// file[2..6].copy_from_slice(&6u32.to_ne_bytes());
+    /// file[8] = 0x7F;
+    ///
+    /// let mut cursor = PtrCursor::new(file.as_ptr());
+    /// cursor.seek(2);
+    /// unsafe {
+    ///     cursor.follow_u32_offset(2);
+    ///     assert_eq!(cursor.position(), 8);
+    ///     assert_eq!(cursor.read_u8(), 0x7F);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub unsafe fn follow_u32_offset(&mut self, relative_to: usize) {
+        let offset = self.read_u32();
+        self.seek(relative_to + offset as usize);
+    }
+
+    /// Reads a value of any [`Copy`] type `T` at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading [`size_of::<T>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - The bytes read must represent a valid value of `T`
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read<T: Copy>(&mut self) -> T {
+        let value = read_raw::<T>(self.base, self.position);
+        self.position += size_of::<T>();
+        value
+    }
+
+    // Unsigned integer types
+
+    /// Reads a [`u8`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    pub unsafe fn read_u8(&mut self) -> u8 {
+        self.read()
+    }
+
+    /// Reads a [`u16`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_u16(&mut self) -> u16 {
+        self.read()
+    }
+
+    /// Reads a [`u32`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_u32(&mut self) -> u32 {
+        self.read()
+    }
+
+    /// Reads a [`u64`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_u64(&mut self) -> u64 {
+        self.read()
+    }
+
+    /// Reads a [`u128`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_u128(&mut self) -> u128 {
+        self.read()
+    }
+
+    /// Reads a [`usize`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading [`size_of::<usize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_usize(&mut self) -> usize {
+        self.read()
+    }
+
+    // Signed integer types
+
+    /// Reads an [`i8`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    pub unsafe fn read_i8(&mut self) -> i8 {
+        self.read()
+    }
+
+    /// Reads an [`i16`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_i16(&mut self) -> i16 {
+        self.read()
+    }
+
+    /// Reads an [`i32`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_i32(&mut self) -> i32 {
+        self.read()
+    }
+
+    /// Reads an [`i64`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_i64(&mut self) -> i64 {
+        self.read()
+    }
+
+    /// Reads an [`i128`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_i128(&mut self) -> i128 {
+        self.read()
+    }
+
+    /// Reads an [`isize`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading [`size_of::<isize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_isize(&mut self) -> isize {
+        self.read()
+    }
+
+    // Floating point types
+
+    /// Reads an [`f32`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_f32(&mut self) -> f32 {
+        self.read()
+    }
+
+    /// Reads an [`f64`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_f64(&mut self) -> f64 {
+        self.read()
+    }
+
+    // Boolean type
+
+    /// Reads a [`bool`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - The byte value must represent a valid [`bool`] (0 or 1)
+    #[inline(always)]
+    pub unsafe fn read_bool(&mut self) -> bool {
+        self.read()
+    }
+}
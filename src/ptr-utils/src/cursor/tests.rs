@@ -0,0 +1,271 @@
+use core::mem::size_of;
+
+use super::*;
+use crate::{UnalignedRead, UnalignedWrite};
+
+#[test]
+fn test_advancing_read_all_types() {
+    unsafe {
+        let mut buffer = [0u8; 128];
+        let ptr = buffer.as_mut_ptr();
+
+        ptr.write_u8_at(0, 0x12);
+        ptr.write_u16_at(1, 0x1234);
+        ptr.write_u32_at(3, 0x12345678);
+        ptr.write_u64_at(7, 0x123456789ABCDEF0);
+        ptr.write_u128_at(15, 0x123456789ABCDEF0FEDCBA0987654321);
+        ptr.write_usize_at(31, 0x12345678);
+        let mut offset = 31 + size_of::<usize>();
+        ptr.write_i8_at(offset, -12);
+        ptr.write_i16_at(offset + 1, -1234);
+        ptr.write_i32_at(offset + 3, -12345678);
+        ptr.write_i64_at(offset + 7, -123456789012345678);
+        ptr.write_i128_at(offset + 15, -12345678901234567890123456789012345678);
+        ptr.write_isize_at(offset + 31, -12345678);
+        offset += 31 + size_of::<isize>();
+        ptr.write_f32_at(offset, 1.5);
+        ptr.write_f64_at(offset + 4, -2.25);
+        ptr.write_bool_at(offset + 12, true);
+        let end = offset + 13;
+
+        let mut cur = buffer.as_ptr();
+        assert_eq!((&mut cur).read_u8(), 0x12);
+        assert_eq!((&mut cur).read_u16(), 0x1234);
+        assert_eq!((&mut cur).read_u32(), 0x12345678);
+        assert_eq!((&mut cur).read_u64(), 0x123456789ABCDEF0);
+        assert_eq!((&mut cur).read_u128(), 0x123456789ABCDEF0FEDCBA0987654321);
+        assert_eq!((&mut cur).read_usize(), 0x12345678);
+        assert_eq!((&mut cur).read_i8(), -12);
+        assert_eq!((&mut cur).read_i16(), -1234);
+        assert_eq!((&mut cur).read_i32(), -12345678);
+        assert_eq!((&mut cur).read_i64(), -123456789012345678);
+        assert_eq!(
+            (&mut cur).read_i128(),
+            -12345678901234567890123456789012345678
+        );
+        assert_eq!((&mut cur).read_isize(), -12345678);
+        assert_eq!((&mut cur).read_f32(), 1.5);
+        assert_eq!((&mut cur).read_f64(), -2.25);
+        assert!((&mut cur).read_bool());
+
+        assert_eq!(cur, buffer.as_ptr().add(end));
+    }
+}
+
+#[test]
+fn test_ptr_cursor() {
+    unsafe {
+        let mut buffer = [0u8; 32];
+        let ptr = buffer.as_mut_ptr();
+        ptr.write_u8_at(0, 0x12);
+        ptr.write_u32_at(1, 0x12345678);
+        ptr.write_i64_at(5, -5);
+        ptr.write_f32_at(13, 0.5);
+        ptr.write_bool_at(17, true);
+
+        let mut cursor = PtrCursor::new(buffer.as_ptr());
+        assert_eq!(cursor.read_u8(), 0x12);
+        assert_eq!(cursor.read_u32(), 0x12345678);
+        assert_eq!(cursor.read_i64(), -5);
+        assert_eq!(cursor.read_f32(), 0.5);
+        assert!(cursor.read_bool());
+        assert_eq!(cursor.position(), 18);
+        assert_eq!(cursor.current_ptr(), buffer.as_ptr().add(18));
+
+        cursor.seek(1);
+        assert_eq!(cursor.read::<u32>(), 0x12345678);
+        cursor.skip(8);
+        assert_eq!(cursor.read_f32(), 0.5);
+    }
+}
+
+#[test]
+fn test_ptr_cursor_checkpoint_restore() {
+    /// Parses `[0x01, len: u8, bytes...]`, returning the byte sum.
+    unsafe fn parse_short(cursor: &mut PtrCursor) -> Option<u32> {
+        if cursor.read_u8() != 0x01 {
+            return None;
+        }
+        let len = cursor.read_u8();
+        Some((0..len).map(|_| cursor.read_u8() as u32).sum())
+    }
+
+    /// Parses `[0x02, value: u16]`.
+    unsafe fn parse_long(cursor: &mut PtrCursor) -> Option<u32> {
+        if cursor.read_u8() != 0x02 {
+            return None;
+        }
+        Some(cursor.read_u16() as u32)
+    }
+
+    let mut data = [0u8; 4];
+    data[0] = 0x02;
+    data[1..3].copy_from_slice(&0x1234u16.to_ne_bytes());
+    data[3] = 0xFF;
+    let mut cursor = PtrCursor::new(data.as_ptr());
+
+    unsafe {
+        // The first branch consumes the tag before failing; restoring rewinds it.
+        let checkpoint = cursor.checkpoint();
+        assert_eq!(parse_short(&mut cursor), None);
+        assert_eq!(cursor.position(), 1);
+
+        cursor.restore(checkpoint);
+        assert_eq!(parse_long(&mut cursor), Some(0x1234));
+        assert_eq!(cursor.position(), 3);
+        assert_eq!(cursor.read_u8(), 0xFF);
+    }
+}
+
+#[test]
+fn test_write_cursor() {
+    unsafe {
+        let mut buffer = [0u8; 32];
+        let mut cursor = WriteCursor::new(buffer.as_mut_ptr());
+
+        cursor.write_u8(0x12);
+        cursor.write_u32(0x12345678);
+        cursor.write_i64(-5);
+        cursor.write_f32(0.5);
+        cursor.write_bool(true);
+        assert_eq!(cursor.position(), 18);
+
+        let ptr = buffer.as_ptr();
+        assert_eq!(ptr.read_u8_at(0), 0x12);
+        assert_eq!(ptr.read_u32_at(1), 0x12345678);
+        assert_eq!(ptr.read_i64_at(5), -5);
+        assert_eq!(ptr.read_f32_at(13), 0.5);
+        assert!(ptr.read_bool_at(17));
+    }
+}
+
+#[test]
+fn test_write_cursor_padding() {
+    let mut buffer = [0u8; 16];
+    unsafe {
+        let mut cursor = WriteCursor::new(buffer.as_mut_ptr());
+
+        cursor.write_u8(1);
+        assert_eq!(cursor.write_padding_to(8, 0xAA), 7);
+        assert_eq!(cursor.position(), 8);
+
+        // Already aligned: nothing written
+        assert_eq!(cursor.write_padding_to(8, 0xBB), 0);
+        assert_eq!(cursor.write_padding_to(1, 0xBB), 0);
+        assert_eq!(cursor.position(), 8);
+
+        cursor.write_u16(2);
+        assert_eq!(cursor.write_padding_to(4, 0xCC), 2);
+        assert_eq!(cursor.position(), 12);
+    }
+    assert_eq!(
+        buffer[..12],
+        [1, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 2, 0, 0xCC, 0xCC]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_write_padding_to_zero_align() {
+    let mut buffer = [0u8; 8];
+    unsafe {
+        WriteCursor::new(buffer.as_mut_ptr()).write_padding_to(0, 0);
+    }
+}
+
+#[test]
+fn test_advance_write_chain() {
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    #[repr(C)]
+    struct Pair {
+        a: u16,
+        b: u16,
+    }
+
+    let mut buffer = [0u8; 64];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        let offset = ptr.write_u8_advance_at(0, 0x12);
+        let offset = ptr.write_u32_advance_at(offset, 0x3456789A);
+        let offset = ptr.write_i16_advance_at(offset, -2);
+        let offset = ptr.write_f64_advance_at(offset, 1.5);
+        let offset = ptr.write_usize_advance_at(offset, usize::MAX);
+        let offset = ptr.write_bool_advance_at(offset, true);
+        let end = ptr.write_advance_at(offset, Pair { a: 1, b: 2 });
+
+        assert_eq!(end, 1 + 4 + 2 + 8 + size_of::<usize>() + 1 + 4);
+        assert_eq!(ptr.read_u8_at(0), 0x12);
+        assert_eq!(ptr.read_u32_at(1), 0x3456789A);
+        assert_eq!(ptr.read_i16_at(5), -2);
+        assert_eq!(ptr.read_f64_at(7), 1.5);
+        assert_eq!(ptr.read_usize_at(15), usize::MAX);
+        assert!(ptr.read_bool_at(offset - 1));
+        assert_eq!(ptr.read_at::<Pair>(offset), Pair { a: 1, b: 2 });
+    }
+}
+
+#[cfg(all(debug_assertions, not(feature = "no-panic")))]
+#[test]
+#[should_panic(expected = "cursor over different memory")]
+fn test_ptr_cursor_restore_foreign_checkpoint() {
+    let a = [0u8; 4];
+    let b = [0u8; 4];
+    let checkpoint = PtrCursor::new(a.as_ptr()).checkpoint();
+    PtrCursor::new(b.as_ptr()).restore(checkpoint);
+}
+
+#[test]
+fn test_ptr_cursor_bytes_consumed() {
+    // Two framed messages: a u8 tag and a u16 payload length, then the payload.
+    let stream = [0x01u8, 0x03, 0x00, 0xAA, 0xBB, 0xCC, 0x02, 0x00, 0x00];
+
+    unsafe {
+        let mut cursor = PtrCursor::new(stream.as_ptr());
+        assert_eq!(cursor.bytes_consumed(), 0);
+
+        assert_eq!(cursor.read_u8(), 0x01);
+        let len = u16::from_le(cursor.read_u16()) as usize;
+        assert_eq!(cursor.bytes_consumed(), 3);
+        cursor.skip(len);
+        assert_eq!(cursor.bytes_consumed(), 6);
+
+        // Advance the outer stream past the first message and parse the next.
+        let mut next = PtrCursor::new(stream.as_ptr().add(cursor.bytes_consumed()));
+        assert_eq!(next.read_u8(), 0x02);
+        assert_eq!(next.read_u16(), 0);
+        assert_eq!(next.bytes_consumed(), 3);
+
+        cursor.seek(0);
+        assert_eq!(cursor.bytes_consumed(), 0);
+        assert_eq!(cursor.read_u8(), 0x01);
+    }
+}
+
+#[test]
+fn test_ptr_cursor_follow_u32_offset() {
+    // A header with a file-relative offset at 0, and a table at 8 whose field at 12
+    // holds a table-relative offset.
+    let mut file = [0u8; 24];
+    file[0..4].copy_from_slice(&8u32.to_ne_bytes());
+    file[12..16].copy_from_slice(&12u32.to_ne_bytes());
+    file[20..22].copy_from_slice(&0xBEEFu16.to_ne_bytes());
+
+    unsafe {
+        let mut cursor = PtrCursor::new(file.as_ptr());
+        cursor.follow_u32_offset(0);
+        assert_eq!(cursor.position(), 8);
+
+        let table = cursor.position();
+        cursor.skip(4);
+        let checkpoint = cursor.checkpoint();
+        cursor.follow_u32_offset(table);
+        assert_eq!(cursor.position(), 20);
+        assert_eq!(cursor.read_u16(), 0xBEEF);
+
+        // Return to just past the followed field.
+        cursor.restore(checkpoint);
+        cursor.skip(4);
+        assert_eq!(cursor.position(), 16);
+    }
+}
@@ -0,0 +1,273 @@
+//! A raw-pointer write cursor with explicit position tracking.
+
+use core::mem::size_of;
+
+use crate::unaligned::raw::write_raw;
+use crate::RegionWrite;
+
+/// A sequential writer over a raw mutable pointer, tracking a byte position from its base.
+///
+/// The write-side counterpart of [`PtrCursor`](crate::PtrCursor). No bounds are tracked;
+/// all writes are `unsafe`.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::WriteCursor;
+///
+/// let mut buffer = [0xFFu8; 8];
+/// let mut cursor = WriteCursor::new(buffer.as_mut_ptr());
+///
+/// unsafe {
+///     cursor.write_u8(0x01);
+///     assert_eq!(cursor.write_padding_to(4, 0x00), 3);
+///     cursor.write_u32(0x12345678);
+/// }
+/// assert_eq!(cursor.position(), 8);
+/// assert_eq!(buffer[..4], [0x01, 0x00, 0x00, 0x00]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteCursor {
+    base: *mut u8,
+    position: usize,
+}
+
+impl WriteCursor {
+    /// Creates a cursor positioned at the start of `base`.
+    #[inline(always)]
+    pub const fn new(base: *mut u8) -> Self {
+        Self { base, position: 0 }
+    }
+
+    /// Returns the base pointer the cursor was created with.
+    #[inline(always)]
+    pub const fn base(&self) -> *mut u8 {
+        self.base
+    }
+
+    /// Returns the current byte position relative to the base pointer.
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns a pointer to the current position.
+    #[inline(always)]
+    pub fn current_ptr(&self) -> *mut u8 {
+        self.base.wrapping_add(self.position)
+    }
+
+    /// Moves the cursor to the given byte position relative to the base pointer.
+    #[inline(always)]
+    pub fn seek(&mut self, position: usize) {
+        self.position = position;
+    }
+
+    /// Advances the cursor by `len` bytes without writing.
+    #[inline(always)]
+    pub fn skip(&mut self, len: usize) {
+        self.position += len;
+    }
+
+    /// Writes `fill` bytes until the position is a multiple of `align`, returning the
+    /// number of padding bytes written.
+    ///
+    /// Alignment is relative to the base pointer, matching how formats specify record
+    /// alignment as file offsets. An already aligned position writes nothing.
+    ///
+    /// # Panics
+    /// If `align` is 0.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing up to `align - 1` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    #[inline]
+    pub unsafe fn write_padding_to(&mut self, align: usize, fill: u8) -> usize {
+        let padding = self.position.next_multiple_of(align) - self.position;
+        self.base.fill_at(self.position, padding, fill);
+        self.position += padding;
+        padding
+    }
+
+    /// Writes a value of any [`Copy`] type `T` at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing [`size_of::<T>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write<T: Copy>(&mut self, value: T) {
+        write_raw(self.base, self.position, value);
+        self.position += size_of::<T>();
+    }
+
+    // Unsigned integer types
+
+    /// Writes a [`u8`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    #[inline(always)]
+    pub unsafe fn write_u8(&mut self, value: u8) {
+        self.write(value);
+    }
+
+    /// Writes a [`u16`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_u16(&mut self, value: u16) {
+        self.write(value);
+    }
+
+    /// Writes a [`u32`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_u32(&mut self, value: u32) {
+        self.write(value);
+    }
+
+    /// Writes a [`u64`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_u64(&mut self, value: u64) {
+        self.write(value);
+    }
+
+    /// Writes a [`u128`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_u128(&mut self, value: u128) {
+        self.write(value);
+    }
+
+    /// Writes a [`usize`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing [`size_of::<usize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_usize(&mut self, value: usize) {
+        self.write(value);
+    }
+
+    // Signed integer types
+
+    /// Writes an [`i8`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    #[inline(always)]
+    pub unsafe fn write_i8(&mut self, value: i8) {
+        self.write(value);
+    }
+
+    /// Writes an [`i16`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_i16(&mut self, value: i16) {
+        self.write(value);
+    }
+
+    /// Writes an [`i32`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_i32(&mut self, value: i32) {
+        self.write(value);
+    }
+
+    /// Writes an [`i64`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_i64(&mut self, value: i64) {
+        self.write(value);
+    }
+
+    /// Writes an [`i128`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_i128(&mut self, value: i128) {
+        self.write(value);
+    }
+
+    /// Writes an [`isize`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing [`size_of::<isize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_isize(&mut self, value: isize) {
+        self.write(value);
+    }
+
+    // Floating point types
+
+    /// Writes an [`f32`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_f32(&mut self, value: f32) {
+        self.write(value);
+    }
+
+    /// Writes an [`f64`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    pub unsafe fn write_f64(&mut self, value: f64) {
+        self.write(value);
+    }
+
+    // Boolean type
+
+    /// Writes a [`bool`] value at the current position and advances past it.
+    ///
+    /// # Safety
+    /// - The current position must be valid for writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    #[inline(always)]
+    pub unsafe fn write_bool(&mut self, value: bool) {
+        self.write(value);
+    }
+}
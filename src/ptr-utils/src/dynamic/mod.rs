@@ -0,0 +1,13 @@
+//! Reads whose type is chosen at runtime, for inspectors and schema-driven tools.
+//!
+//! A [`ValueKind`] (e.g. loaded from a schema) selects which typed read to perform, and
+//! the result is returned as a [`DynValue`], which can be matched on or displayed.
+
+pub mod read;
+pub mod value;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::DynRead;
+pub use value::{DynValue, ValueKind};
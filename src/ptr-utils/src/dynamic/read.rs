@@ -0,0 +1,104 @@
+//! Reads dispatched on a runtime [`ValueKind`].
+
+use super::{DynValue, ValueKind};
+use crate::{EndianRead, UnalignedRead};
+
+/// Trait providing reads whose type is selected at runtime by a [`ValueKind`].
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::dynamic::{DynRead, DynValue, ValueKind};
+/// use ptr_utils::Endian;
+///
+/// let data = [0x00u8, 0x2A, 0xFF];
+/// let ptr = data.as_ptr();
+///
+/// unsafe {
+///     let value = ptr.read_dyn_at(0, ValueKind::U16(Endian::Big));
+///     assert_eq!(value, DynValue::U16(42));
+///     assert_eq!(value.to_string(), "42u16");
+///     assert_eq!(ptr.read_dyn_at(2, ValueKind::I8).to_string(), "-1i8");
+/// }
+/// ```
+pub trait DynRead: UnalignedRead + Copy {
+    /// Reads a value of the given kind from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `kind.size()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_dyn_at(self, byte_offset: usize, kind: ValueKind) -> DynValue {
+        match kind {
+            ValueKind::U8 => DynValue::U8(self.read_u8_at(byte_offset)),
+            ValueKind::U16(e) => DynValue::U16(self.read_u16_endian_at(byte_offset, e)),
+            ValueKind::U32(e) => DynValue::U32(self.read_u32_endian_at(byte_offset, e)),
+            ValueKind::U64(e) => DynValue::U64(self.read_u64_endian_at(byte_offset, e)),
+            ValueKind::I8 => DynValue::I8(self.read_i8_at(byte_offset)),
+            ValueKind::I16(e) => DynValue::I16(self.read_i16_endian_at(byte_offset, e)),
+            ValueKind::I32(e) => DynValue::I32(self.read_i32_endian_at(byte_offset, e)),
+            ValueKind::I64(e) => DynValue::I64(self.read_i64_endian_at(byte_offset, e)),
+            ValueKind::F32(e) => DynValue::F32(self.read_f32_endian_at(byte_offset, e)),
+            ValueKind::F64(e) => DynValue::F64(self.read_f64_endian_at(byte_offset, e)),
+            // Read as a byte so that values other than 0/1 are not undefined behaviour.
+            ValueKind::Bool => DynValue::Bool(self.read_u8_at(byte_offset) != 0),
+        }
+    }
+
+    /// Reads each `(offset, kind)` field, relative to `base_offset`, into the
+    /// corresponding element of `out`.
+    ///
+    /// This supports schemas loaded at runtime, where a record's layout is data rather
+    /// than a Rust type. `out` must have room for every field; any excess elements are
+    /// left untouched.
+    ///
+    /// # Safety
+    /// - For each field, the pointer plus `base_offset + offset` must be valid for reading
+    ///   `kind.size()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the reads
+    /// - No alignment requirements - this performs unaligned reads
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::dynamic::{DynRead, DynValue, ValueKind};
+    /// use ptr_utils::Endian;
+    ///
+    /// // A record of { id: u16 (big-endian), flag: bool, scale: f32 (little-endian) }.
+    /// let schema = [
+    ///     (0, ValueKind::U16(Endian::Big)),
+    ///     (2, ValueKind::Bool),
+    ///     (3, ValueKind::F32(Endian::Little)),
+    /// ];
+    ///
+    /// let mut record = [0u8; 7];
+    /// record[..2].copy_from_slice(&7u16.to_be_bytes());
+    /// record[2] = 1;
+    /// record[3..].copy_from_slice(&0.5f32.to_le_bytes());
+    ///
+    /// let mut out = [DynValue::U8(0); 3];
+    /// unsafe { record.as_ptr().read_fields_into(0, &schema, &mut out) };
+    ///
+    /// assert_eq!(out, [DynValue::U16(7), DynValue::Bool(true), DynValue::F32(0.5)]);
+    /// ```
+    #[inline]
+    unsafe fn read_fields_into(
+        self,
+        base_offset: usize,
+        fields: &[(usize, ValueKind)],
+        out: &mut [DynValue],
+    ) {
+        debug_check!(
+            out.len() >= fields.len(),
+            "output slice holds {} values but there are {} fields",
+            out.len(),
+            fields.len()
+        );
+        for (&(offset, kind), slot) in fields.iter().zip(out.iter_mut()) {
+            *slot = self.read_dyn_at(base_offset + offset, kind);
+        }
+    }
+}
+
+impl<P: UnalignedRead + Copy> DynRead for P {}
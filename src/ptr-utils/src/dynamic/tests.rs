@@ -0,0 +1,81 @@
+use super::*;
+use crate::Endian;
+
+#[test]
+fn test_read_dyn() {
+    let mut data = [0u8; 16];
+    data[0] = 0x80;
+    data[1..5].copy_from_slice(&1.5f32.to_le_bytes());
+    data[5..9].copy_from_slice(&(-7i32).to_be_bytes());
+    data[9] = 2;
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_dyn_at(0, ValueKind::U8), DynValue::U8(0x80));
+        assert_eq!(ptr.read_dyn_at(0, ValueKind::I8), DynValue::I8(-128));
+        assert_eq!(
+            ptr.read_dyn_at(1, ValueKind::F32(Endian::Little)),
+            DynValue::F32(1.5)
+        );
+        assert_eq!(
+            ptr.read_dyn_at(5, ValueKind::I32(Endian::Big)),
+            DynValue::I32(-7)
+        );
+        assert_eq!(ptr.read_dyn_at(9, ValueKind::Bool), DynValue::Bool(true));
+        assert_eq!(ptr.read_dyn_at(10, ValueKind::Bool), DynValue::Bool(false));
+    }
+
+    assert_eq!(ValueKind::U8.size(), 1);
+    assert_eq!(ValueKind::I16(Endian::Big).size(), 2);
+    assert_eq!(ValueKind::F64(Endian::Little).size(), 8);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_dyn_value_display() {
+    use std::string::ToString;
+
+    assert_eq!(DynValue::U32(7).to_string(), "7u32");
+    assert_eq!(DynValue::I64(-3).to_string(), "-3i64");
+    assert_eq!(DynValue::F32(1.0).to_string(), "1.0f32");
+    assert_eq!(DynValue::F64(-0.5).to_string(), "-0.5f64");
+    assert_eq!(DynValue::Bool(false).to_string(), "false");
+    assert_eq!(DynValue::U8(1).type_name(), "u8");
+}
+
+#[test]
+fn test_read_fields_into() {
+    let fields = [
+        (4, ValueKind::I32(Endian::Big)),
+        (0, ValueKind::U8),
+        (1, ValueKind::U16(Endian::Little)),
+    ];
+    let mut data = [0u8; 10];
+    data[2] = 0xAA;
+    data[3] = 0x01;
+    data[4] = 0x02;
+    data[6..10].copy_from_slice(&(-3i32).to_be_bytes());
+
+    let mut out = [DynValue::Bool(false); 4];
+    unsafe { data.as_ptr().read_fields_into(2, &fields, &mut out) };
+
+    assert_eq!(
+        out,
+        [
+            DynValue::I32(-3),
+            DynValue::U8(0xAA),
+            DynValue::U16(0x0201),
+            DynValue::Bool(false),
+        ]
+    );
+}
+
+#[test]
+#[cfg(all(debug_assertions, not(feature = "no-panic")))]
+#[should_panic(expected = "output slice holds 1 values but there are 2 fields")]
+fn test_read_fields_into_short_output_panics() {
+    let data = [0u8; 2];
+    let fields = [(0, ValueKind::U8), (1, ValueKind::U8)];
+    let mut out = [DynValue::U8(0); 1];
+    unsafe { data.as_ptr().read_fields_into(0, &fields, &mut out) };
+}
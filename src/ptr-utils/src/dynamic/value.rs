@@ -0,0 +1,116 @@
+//! The [`ValueKind`] and [`DynValue`] types.
+
+use core::fmt;
+
+use crate::Endian;
+
+/// The type of a value to read, selected at runtime.
+///
+/// Multi-byte kinds carry the [`Endian`] they are stored in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    /// A [`u8`].
+    U8,
+    /// A [`u16`] in the given byte order.
+    U16(Endian),
+    /// A [`u32`] in the given byte order.
+    U32(Endian),
+    /// A [`u64`] in the given byte order.
+    U64(Endian),
+    /// An [`i8`].
+    I8,
+    /// An [`i16`] in the given byte order.
+    I16(Endian),
+    /// An [`i32`] in the given byte order.
+    I32(Endian),
+    /// An [`i64`] in the given byte order.
+    I64(Endian),
+    /// An [`f32`] in the given byte order.
+    F32(Endian),
+    /// An [`f64`] in the given byte order.
+    F64(Endian),
+    /// A [`bool`] stored as one byte; any nonzero byte is `true`.
+    Bool,
+}
+
+impl ValueKind {
+    /// Returns the number of bytes a value of this kind occupies.
+    #[inline]
+    pub const fn size(self) -> usize {
+        match self {
+            ValueKind::U8 | ValueKind::I8 | ValueKind::Bool => 1,
+            ValueKind::U16(_) | ValueKind::I16(_) => 2,
+            ValueKind::U32(_) | ValueKind::I32(_) | ValueKind::F32(_) => 4,
+            ValueKind::U64(_) | ValueKind::I64(_) | ValueKind::F64(_) => 8,
+        }
+    }
+}
+
+/// A value read according to a [`ValueKind`].
+///
+/// [`Display`](fmt::Display) formats it like a Rust literal with a type suffix
+/// (e.g. `42u16`, `-1i8`, `1.5f32`, `true`), so the type is visible alongside the value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DynValue {
+    /// A [`u8`] value.
+    U8(u8),
+    /// A [`u16`] value.
+    U16(u16),
+    /// A [`u32`] value.
+    U32(u32),
+    /// A [`u64`] value.
+    U64(u64),
+    /// An [`i8`] value.
+    I8(i8),
+    /// An [`i16`] value.
+    I16(i16),
+    /// An [`i32`] value.
+    I32(i32),
+    /// An [`i64`] value.
+    I64(i64),
+    /// An [`f32`] value.
+    F32(f32),
+    /// An [`f64`] value.
+    F64(f64),
+    /// A [`bool`] value.
+    Bool(bool),
+}
+
+impl DynValue {
+    /// Returns the Rust name of the value's type (e.g. `"u16"`).
+    #[inline]
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            DynValue::U8(_) => "u8",
+            DynValue::U16(_) => "u16",
+            DynValue::U32(_) => "u32",
+            DynValue::U64(_) => "u64",
+            DynValue::I8(_) => "i8",
+            DynValue::I16(_) => "i16",
+            DynValue::I32(_) => "i32",
+            DynValue::I64(_) => "i64",
+            DynValue::F32(_) => "f32",
+            DynValue::F64(_) => "f64",
+            DynValue::Bool(_) => "bool",
+        }
+    }
+}
+
+impl fmt::Display for DynValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let suffix = self.type_name();
+        match self {
+            DynValue::U8(v) => write!(f, "{v}{suffix}"),
+            DynValue::U16(v) => write!(f, "{v}{suffix}"),
+            DynValue::U32(v) => write!(f, "{v}{suffix}"),
+            DynValue::U64(v) => write!(f, "{v}{suffix}"),
+            DynValue::I8(v) => write!(f, "{v}{suffix}"),
+            DynValue::I16(v) => write!(f, "{v}{suffix}"),
+            DynValue::I32(v) => write!(f, "{v}{suffix}"),
+            DynValue::I64(v) => write!(f, "{v}{suffix}"),
+            DynValue::F32(v) => write!(f, "{v:?}{suffix}"),
+            DynValue::F64(v) => write!(f, "{v:?}{suffix}"),
+            DynValue::Bool(v) => write!(f, "{v}"),
+        }
+    }
+}
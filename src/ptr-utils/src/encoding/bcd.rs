@@ -0,0 +1,86 @@
+//! Packed binary-coded decimal (BCD) reads.
+
+use crate::UnalignedRead;
+
+/// Maximum number of BCD digits that always fit in a [`u64`].
+pub const MAX_BCD_DIGITS: usize = 19;
+
+/// Trait providing reads of packed binary-coded decimal values.
+///
+/// Each byte holds two decimal digits, most significant digit in the high nibble.
+/// Nibbles above 9 are illegal and cause the reads to return [`None`].
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::BcdRead;
+///
+/// let data = [0x12u8, 0x34, 0x56];
+/// let ptr = data.as_ptr();
+///
+/// unsafe {
+///     assert_eq!(ptr.read_bcd_u8_at(0), Some(12));
+///     assert_eq!(ptr.read_bcd_at(0, 6), Some(123456));
+///     assert_eq!(ptr.read_bcd_at(1, 3), Some(456)); // high nibble of 0x34 is padding
+/// }
+/// ```
+pub trait BcdRead: UnalignedRead + Copy {
+    /// Reads a byte of packed BCD from the pointer at the given byte offset,
+    /// returning its value (0..=99).
+    ///
+    /// Returns [`None`] if either nibble is greater than 9.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_bcd_u8_at(self, byte_offset: usize) -> Option<u8> {
+        decode_bcd_byte(self.read_u8_at(byte_offset))
+    }
+
+    /// Reads a `digits`-digit packed BCD number from the pointer at the given byte offset.
+    ///
+    /// Digits are stored most significant first, two per byte, across
+    /// `digits.div_ceil(2)` bytes. For an odd digit count, the high nibble of the
+    /// first byte is padding and is ignored.
+    ///
+    /// Returns [`None`] if any decoded nibble is greater than 9.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `digits.div_ceil(2)` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - `digits` must be no larger than [`MAX_BCD_DIGITS`]
+    #[inline]
+    unsafe fn read_bcd_at(self, byte_offset: usize, digits: usize) -> Option<u64> {
+        debug_check!(digits <= MAX_BCD_DIGITS, "BCD value would overflow u64");
+
+        let byte_count = digits.div_ceil(2);
+        let has_padding = !digits.is_multiple_of(2);
+        let mut value = 0u64;
+        for x in 0..byte_count {
+            let byte = self.read_u8_at(byte_offset + x);
+            let (high, low) = (byte >> 4, byte & 0x0F);
+            let skip_high = x == 0 && has_padding;
+            if low > 9 || (high > 9 && !skip_high) {
+                return None;
+            }
+            // Wrapping only matters past `MAX_BCD_DIGITS`, where the result is unspecified.
+            if !skip_high {
+                value = value.wrapping_mul(10).wrapping_add(high as u64);
+            }
+            value = value.wrapping_mul(10).wrapping_add(low as u64);
+        }
+        Some(value)
+    }
+}
+
+impl<P: UnalignedRead + Copy> BcdRead for P {}
+
+#[inline(always)]
+fn decode_bcd_byte(byte: u8) -> Option<u8> {
+    let (high, low) = (byte >> 4, byte & 0x0F);
+    if high > 9 || low > 9 {
+        return None;
+    }
+    Some(high * 10 + low)
+}
@@ -0,0 +1,133 @@
+//! Interleaved complex (I/Q) sample reads and writes.
+
+use crate::{UnalignedRead, UnalignedWrite};
+
+/// Trait providing reads of complex numbers stored as interleaved `(re, im)` [`f32`] pairs,
+/// as used by SDR and DSP sample formats.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{ComplexRead, ComplexWrite};
+///
+/// let mut samples = [0u8; 16];
+/// let ptr = samples.as_mut_ptr();
+///
+/// unsafe {
+///     ptr.write_complex_f32_at(0, (1.0, -1.0));
+///     ptr.write_complex_f32_at(8, (0.5, 0.25));
+///
+///     let mut block = [(0.0, 0.0); 2];
+///     ptr.read_complex_f32_slice_into_at(0, &mut block);
+///     assert_eq!(block, [(1.0, -1.0), (0.5, 0.25)]);
+/// }
+/// ```
+pub trait ComplexRead: UnalignedRead + Copy {
+    /// Reads a complex sample as `(re, im)` from two consecutive native-endian [`f32`] values
+    /// at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_complex_f32_at(self, byte_offset: usize) -> (f32, f32) {
+        (
+            self.read_f32_at(byte_offset),
+            self.read_f32_at(byte_offset + 4),
+        )
+    }
+
+    /// Reads a complex sample as `(re, im)` from two consecutive little-endian [`f32`] values
+    /// at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_complex_f32_le_at(self, byte_offset: usize) -> (f32, f32) {
+        (
+            f32::from_bits(u32::from_le(self.read_u32_at(byte_offset))),
+            f32::from_bits(u32::from_le(self.read_u32_at(byte_offset + 4))),
+        )
+    }
+
+    /// Reads a complex sample as `(re, im)` from two consecutive big-endian [`f32`] values
+    /// at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_complex_f32_be_at(self, byte_offset: usize) -> (f32, f32) {
+        (
+            f32::from_bits(u32::from_be(self.read_u32_at(byte_offset))),
+            f32::from_bits(u32::from_be(self.read_u32_at(byte_offset + 4))),
+        )
+    }
+
+    /// Reads `out.len()` consecutive native-endian complex samples starting at the given
+    /// byte offset into `out`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `out.len() * 8` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_complex_f32_slice_into_at(self, byte_offset: usize, out: &mut [(f32, f32)]) {
+        for (x, sample) in out.iter_mut().enumerate() {
+            *sample = self.read_complex_f32_at(byte_offset + x * 8);
+        }
+    }
+}
+
+impl<P: UnalignedRead + Copy> ComplexRead for P {}
+
+/// Trait providing writes of complex numbers as interleaved `(re, im)` [`f32`] pairs.
+pub trait ComplexWrite: UnalignedWrite + Copy {
+    /// Writes a complex sample as two consecutive native-endian [`f32`] values
+    /// at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_complex_f32_at(self, byte_offset: usize, value: (f32, f32)) {
+        self.write_f32_at(byte_offset, value.0);
+        self.write_f32_at(byte_offset + 4, value.1);
+    }
+
+    /// Writes a complex sample as two consecutive little-endian [`f32`] values
+    /// at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_complex_f32_le_at(self, byte_offset: usize, value: (f32, f32)) {
+        self.write_u32_at(byte_offset, value.0.to_bits().to_le());
+        self.write_u32_at(byte_offset + 4, value.1.to_bits().to_le());
+    }
+
+    /// Writes a complex sample as two consecutive big-endian [`f32`] values
+    /// at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_complex_f32_be_at(self, byte_offset: usize, value: (f32, f32)) {
+        self.write_u32_at(byte_offset, value.0.to_bits().to_be());
+        self.write_u32_at(byte_offset + 4, value.1.to_bits().to_be());
+    }
+}
+
+impl<P: UnalignedWrite + Copy> ComplexWrite for P {}
@@ -0,0 +1,17 @@
+//! Reads of domain-specific numeric encodings.
+//!
+//! These helpers decode values whose on-disk representation differs from a plain
+//! integer or float, where the conversion is easy to get subtly wrong.
+
+pub mod bcd;
+pub mod complex;
+pub mod rle;
+pub mod utf16;
+
+#[cfg(test)]
+mod tests;
+
+pub use bcd::{BcdRead, MAX_BCD_DIGITS};
+pub use complex::{ComplexRead, ComplexWrite};
+pub use rle::RleRead;
+pub use utf16::Utf16Read;
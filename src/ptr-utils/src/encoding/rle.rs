@@ -0,0 +1,55 @@
+//! Run-length-encoded (RLE) byte stream reads.
+
+use crate::UnalignedRead;
+
+/// Trait providing decoding of run-length-encoded byte streams, as used by old bitmap
+/// and sprite formats.
+///
+/// The stream is a sequence of `(count, value)` byte pairs, each expanding to `count`
+/// copies of `value`. A `count` of 0 is a terminator, and is a single byte.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::RleRead;
+///
+/// let data = [3u8, b'a', 1, b'b', 2, b'c', 0];
+/// let mut out = [0u8; 16];
+///
+/// let (consumed, written) = unsafe { data.as_ptr().read_rle_at(0, &mut out) };
+/// assert_eq!(&out[..written], b"aaabcc");
+/// assert_eq!(consumed, 7);
+/// ```
+pub trait RleRead: UnalignedRead + Copy {
+    /// Decodes the RLE stream at the given byte offset into `out`, until the terminator
+    /// is reached or `out` is full, returning `(bytes consumed, bytes written)`.
+    ///
+    /// The terminator counts as consumed. A run that does not fit in the remaining output
+    /// is cut short; its pair counts as consumed and the rest of the run is dropped.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading every pair up to and
+    ///   including the terminator, or the pair that fills `out`
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn read_rle_at(self, byte_offset: usize, out: &mut [u8]) -> (usize, usize) {
+        let mut consumed = 0;
+        let mut written = 0;
+        while written < out.len() {
+            let count = self.read_u8_at(byte_offset + consumed) as usize;
+            consumed += 1;
+            if count == 0 {
+                break;
+            }
+
+            let value = self.read_u8_at(byte_offset + consumed);
+            consumed += 1;
+            let end = out.len().min(written + count);
+            out[written..end].fill(value);
+            written = end;
+        }
+        (consumed, written)
+    }
+}
+
+impl<P: UnalignedRead + Copy> RleRead for P {}
@@ -0,0 +1,184 @@
+use super::bcd::MAX_BCD_DIGITS;
+use super::*;
+use crate::Endian;
+
+#[test]
+fn test_bcd_u8() {
+    let data = [0x00u8, 0x42, 0x99, 0x1A, 0xA1];
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_bcd_u8_at(0), Some(0));
+        assert_eq!(ptr.read_bcd_u8_at(1), Some(42));
+        assert_eq!(ptr.read_bcd_u8_at(2), Some(99));
+        assert_eq!(ptr.read_bcd_u8_at(3), None);
+        assert_eq!(ptr.read_bcd_u8_at(4), None);
+    }
+}
+
+#[test]
+fn test_bcd_multi_byte() {
+    let data = [0x20u8, 0x24, 0x12, 0x31, 0xF5, 0x99];
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_bcd_at(0, 8), Some(20241231));
+        assert_eq!(ptr.read_bcd_at(0, 0), Some(0));
+
+        // Odd digit count: padding nibble is ignored, even if illegal
+        assert_eq!(ptr.read_bcd_at(4, 3), Some(599));
+
+        // Illegal nibble in a digit position
+        assert_eq!(ptr.read_bcd_at(3, 4), None);
+    }
+
+    let max = [0x09u8, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99];
+    unsafe {
+        assert_eq!(
+            max.as_ptr().read_bcd_at(0, MAX_BCD_DIGITS),
+            Some(9_999_999_999_999_999_999)
+        );
+    }
+}
+
+#[test]
+fn test_complex_round_trip() {
+    unsafe {
+        let mut buffer = [0u8; 33];
+        let ptr = buffer.as_mut_ptr();
+
+        ptr.write_complex_f32_at(1, (1.5, -0.25));
+        ptr.write_complex_f32_le_at(9, (3.0, 4.0));
+        ptr.write_complex_f32_be_at(17, (-8.0, 0.125));
+
+        assert_eq!(ptr.read_complex_f32_at(1), (1.5, -0.25));
+        assert_eq!(ptr.read_complex_f32_le_at(9), (3.0, 4.0));
+        assert_eq!(ptr.read_complex_f32_be_at(17), (-8.0, 0.125));
+        assert_eq!(buffer[17..21], (-8.0f32).to_be_bytes());
+        assert_eq!(buffer[9..13], 3.0f32.to_le_bytes());
+
+        let mut block = [(0.0, 0.0); 2];
+        buffer
+            .as_ptr()
+            .read_complex_f32_slice_into_at(1, &mut block[..1]);
+        assert_eq!(block, [(1.5, -0.25), (0.0, 0.0)]);
+    }
+}
+
+#[test]
+fn test_utf16_bmp_and_astral_chars() {
+    let text = "é😀";
+    let mut le = [0u8; 6];
+    let mut be = [0u8; 6];
+    for (i, unit) in text.encode_utf16().enumerate() {
+        le[i * 2..i * 2 + 2].copy_from_slice(&unit.to_le_bytes());
+        be[i * 2..i * 2 + 2].copy_from_slice(&unit.to_be_bytes());
+    }
+
+    unsafe {
+        assert_eq!(le.as_ptr().read_u16_utf16_at(0, Endian::Little), 0x00E9);
+        assert_eq!(be.as_ptr().read_u16_utf16_at(0, Endian::Big), 0x00E9);
+
+        for (data, endian) in [(le, Endian::Little), (be, Endian::Big)] {
+            let ptr = data.as_ptr();
+            assert_eq!(ptr.read_utf16_char_at(0, endian), (Some('é'), 2));
+            assert_eq!(ptr.read_utf16_char_at(2, endian), (Some('😀'), 4));
+        }
+    }
+}
+
+#[test]
+fn test_utf16_unpaired_surrogates() {
+    let units: [u16; 4] = [0xD83D, 0x0041, 0xDE00, 0xDBFF];
+    let mut data = [0u8; 10];
+    for (i, unit) in units.iter().enumerate() {
+        data[i * 2..i * 2 + 2].copy_from_slice(&unit.to_le_bytes());
+    }
+    let ptr = data.as_ptr();
+
+    unsafe {
+        // High surrogate followed by a non-surrogate, and a lone low surrogate.
+        assert_eq!(ptr.read_utf16_char_at(0, Endian::Little), (None, 2));
+        assert_eq!(ptr.read_utf16_char_at(2, Endian::Little), (Some('A'), 2));
+        assert_eq!(ptr.read_utf16_char_at(4, Endian::Little), (None, 2));
+
+        // Highest high surrogate paired with the trailing zero unit is still unpaired.
+        assert_eq!(ptr.read_utf16_char_at(6, Endian::Little), (None, 2));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_read_wcstr() {
+    fn encode(text: &str, endian: Endian) -> alloc::vec::Vec<u8> {
+        text.encode_utf16()
+            .chain([0])
+            .flat_map(|unit| match endian {
+                Endian::Little => unit.to_le_bytes(),
+                Endian::Big => unit.to_be_bytes(),
+            })
+            .collect()
+    }
+
+    unsafe {
+        // ASCII in UTF-16, in both byte orders.
+        let data = encode("C:\\Windows", Endian::Little);
+        let text = data.as_ptr().read_wcstr_at(0, 64, Endian::Little);
+        assert_eq!(text.as_deref(), Some("C:\\Windows"));
+        let data = encode("C:\\Windows", Endian::Big);
+        let text = data.as_ptr().read_wcstr_at(0, 64, Endian::Big);
+        assert_eq!(text.as_deref(), Some("C:\\Windows"));
+
+        // A surrogate pair.
+        let data = encode("ok 😀!", Endian::Little);
+        let text = data.as_ptr().read_wcstr_at(0, 64, Endian::Little);
+        assert_eq!(text.as_deref(), Some("ok 😀!"));
+
+        // `max_units` stops before the terminator; a pair split by it is invalid.
+        let text = data.as_ptr().read_wcstr_at(0, 2, Endian::Little);
+        assert_eq!(text.as_deref(), Some("ok"));
+        assert_eq!(data.as_ptr().read_wcstr_at(0, 4, Endian::Little), None);
+        assert_eq!(
+            data.as_ptr().read_wcstr_at(0, 5, Endian::Little).as_deref(),
+            Some("ok 😀")
+        );
+
+        // Empty, and an unpaired low surrogate.
+        assert_eq!(
+            [0u8, 0]
+                .as_ptr()
+                .read_wcstr_at(0, 8, Endian::Little)
+                .as_deref(),
+            Some("")
+        );
+        let data = [0x41u8, 0x00, 0x00, 0xDC, 0x00, 0x00];
+        assert_eq!(data.as_ptr().read_wcstr_at(0, 8, Endian::Little), None);
+    }
+}
+
+#[test]
+fn test_read_rle() {
+    // Runs of 2 and 4 at an offset, then the terminator and trailing data.
+    let data = [0xFFu8, 2, 0x11, 4, 0x22, 0, 9, 0x33];
+    let ptr = data.as_ptr();
+
+    unsafe {
+        let mut out = [0xAAu8; 8];
+        assert_eq!(ptr.read_rle_at(1, &mut out), (5, 6));
+        assert_eq!(out, [0x11, 0x11, 0x22, 0x22, 0x22, 0x22, 0xAA, 0xAA]);
+
+        // Filling the output exactly stops before the terminator.
+        let mut out = [0u8; 6];
+        assert_eq!(ptr.read_rle_at(1, &mut out), (4, 6));
+        assert_eq!(out, [0x11, 0x11, 0x22, 0x22, 0x22, 0x22]);
+
+        // A run longer than the output is cut short.
+        let mut out = [0u8; 3];
+        assert_eq!(ptr.read_rle_at(6, &mut out), (2, 3));
+        assert_eq!(out, [0x33; 3]);
+
+        // An immediate terminator, and an empty output that reads nothing.
+        assert_eq!(ptr.read_rle_at(5, &mut [0u8; 4]), (1, 0));
+        assert_eq!(ptr.read_rle_at(1, &mut []), (0, 0));
+    }
+}
@@ -0,0 +1,130 @@
+//! UTF-16 code unit and character reads.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::{Endian, EndianRead, UnalignedRead};
+
+/// Trait providing reads of UTF-16 text, one character at a time.
+///
+/// Characters outside the Basic Multilingual Plane are stored as a surrogate pair: a high
+/// surrogate (`0xD800..=0xDBFF`) followed by a low surrogate (`0xDC00..=0xDFFF`).
+/// [`read_utf16_char_at`](Utf16Read::read_utf16_char_at) decodes these pairs.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{Endian, Utf16Read};
+///
+/// // "A😀" in UTF-16LE.
+/// let data = [0x41u8, 0x00, 0x3D, 0xD8, 0x00, 0xDE];
+/// let ptr = data.as_ptr();
+///
+/// unsafe {
+///     assert_eq!(ptr.read_utf16_char_at(0, Endian::Little), (Some('A'), 2));
+///     assert_eq!(ptr.read_utf16_char_at(2, Endian::Little), (Some('😀'), 4));
+/// }
+/// ```
+pub trait Utf16Read: UnalignedRead + Copy {
+    /// Reads a single UTF-16 code unit in the given byte order at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u16_utf16_at(self, byte_offset: usize, endian: Endian) -> u16 {
+        self.read_u16_endian_at(byte_offset, endian)
+    }
+
+    /// Reads one character of UTF-16 in the given byte order at the given byte offset,
+    /// returning it with the number of bytes consumed (2 or 4).
+    ///
+    /// A high surrogate followed by a low surrogate is decoded as one character and
+    /// consumes 4 bytes. An unpaired surrogate returns [`None`] and consumes 2 bytes, so
+    /// decoding can resume at the next code unit.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes, or 4 bytes if
+    ///   the first code unit is a high surrogate
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_utf16_char_at(
+        self,
+        byte_offset: usize,
+        endian: Endian,
+    ) -> (Option<char>, usize) {
+        let unit = self.read_u16_utf16_at(byte_offset, endian);
+        match unit {
+            0xD800..=0xDBFF => {
+                let low = self.read_u16_utf16_at(byte_offset + 2, endian);
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return (None, 2);
+                }
+
+                let scalar = 0x10000 + (((unit as u32) - 0xD800) << 10) + ((low as u32) - 0xDC00);
+                (char::from_u32(scalar), 4)
+            }
+            0xDC00..=0xDFFF => (None, 2),
+            _ => (char::from_u32(unit as u32), 2),
+        }
+    }
+
+    /// Reads a NUL-terminated UTF-16 string (a Windows "wide" string) in the given byte
+    /// order at the given byte offset.
+    ///
+    /// Reads code units until a zero unit or until `max_units` units have been read,
+    /// whichever comes first; the terminator is not included. Returns [`None`] if the text
+    /// contains an unpaired surrogate, including a high surrogate in the last unit allowed
+    /// by `max_units`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::{Endian, Utf16Read};
+    ///
+    /// // L"Hi" followed by the terminator and unrelated data.
+    /// let data = [0x48u8, 0x00, 0x69, 0x00, 0x00, 0x00, 0xFF, 0xFF];
+    ///
+    /// unsafe {
+    ///     let text = data.as_ptr().read_wcstr_at(0, 4, Endian::Little);
+    ///     assert_eq!(text.as_deref(), Some("Hi"));
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading up to the terminator, or
+    ///   `max_units * 2` bytes if none is found before then
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[cfg(feature = "alloc")]
+    #[inline]
+    unsafe fn read_wcstr_at(
+        self,
+        byte_offset: usize,
+        max_units: usize,
+        endian: Endian,
+    ) -> Option<String> {
+        let mut text = String::new();
+        let mut units = 0;
+        while units < max_units {
+            let offset = byte_offset + units * 2;
+            let unit = self.read_u16_utf16_at(offset, endian);
+            if unit == 0 {
+                break;
+            }
+            if (0xD800..=0xDBFF).contains(&unit) && units + 1 == max_units {
+                // The low surrogate would lie past the limit.
+                return None;
+            }
+
+            let (c, consumed) = self.read_utf16_char_at(offset, endian);
+            text.push(c?);
+            units += consumed / 2;
+        }
+        Some(text)
+    }
+}
+
+impl<P: UnalignedRead + Copy> Utf16Read for P {}
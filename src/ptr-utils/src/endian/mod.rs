@@ -0,0 +1,49 @@
+//! Runtime byte order selection.
+//!
+//! The core [`UnalignedRead`](crate::UnalignedRead) methods use native endianness.
+//! This module adds reads (and writes) whose byte order is chosen at runtime via [`Endian`],
+//! e.g. after detecting it from a file header, and a [`Reader`] whose byte order is
+//! instead fixed at compile time.
+
+pub mod read;
+pub mod reader;
+pub mod swapped;
+pub mod u24;
+pub mod write;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::EndianRead;
+pub use reader::{BigEndian, ByteOrder, LittleEndian, NativeEndian, Reader};
+pub use swapped::SwappedRead;
+pub use u24::{U24Read, U24Write};
+pub use write::{EndianWrite, OverflowPolicy};
+
+/// A byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+impl Endian {
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Endian = Endian::Little;
+
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Endian = Endian::Big;
+
+    /// Returns `true` if this is the byte order of the target platform.
+    #[inline(always)]
+    pub const fn is_native(self) -> bool {
+        matches!(
+            (self, Endian::NATIVE),
+            (Endian::Little, Endian::Little) | (Endian::Big, Endian::Big)
+        )
+    }
+}
@@ -0,0 +1,211 @@
+//! Reads with a runtime-selected byte order.
+
+use super::Endian;
+use crate::{NumericRead, UnalignedRead};
+
+/// Trait providing reads whose byte order is selected at runtime.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{Endian, EndianRead};
+///
+/// // TIFF files start with "II" (little-endian) or "MM" (big-endian), then the magic 42.
+/// let file = [b'M', b'M', 0x00, 0x2A, 0x00, 0x00, 0x00, 0x08];
+/// let ptr = file.as_ptr();
+///
+/// unsafe {
+///     let endian = ptr.detect_endian_u16_at(2, 42).unwrap();
+///     assert_eq!(endian, Endian::Big);
+///     assert_eq!(ptr.read_u32_endian_at(4, endian), 8);
+/// }
+/// ```
+pub trait EndianRead: UnalignedRead + Copy {
+    /// Reads a [`u16`] value in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u16_endian_at(self, byte_offset: usize, endian: Endian) -> u16 {
+        self.read_numeric_at(byte_offset, endian)
+    }
+
+    /// Reads a [`u32`] value in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u32_endian_at(self, byte_offset: usize, endian: Endian) -> u32 {
+        self.read_numeric_at(byte_offset, endian)
+    }
+
+    /// Reads a [`u64`] value in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u64_endian_at(self, byte_offset: usize, endian: Endian) -> u64 {
+        self.read_numeric_at(byte_offset, endian)
+    }
+
+    /// Reads a [`u128`] value in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u128_endian_at(self, byte_offset: usize, endian: Endian) -> u128 {
+        self.read_numeric_at(byte_offset, endian)
+    }
+
+    /// Reads an [`i16`] value in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i16_endian_at(self, byte_offset: usize, endian: Endian) -> i16 {
+        self.read_numeric_at(byte_offset, endian)
+    }
+
+    /// Reads an [`i32`] value in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i32_endian_at(self, byte_offset: usize, endian: Endian) -> i32 {
+        self.read_numeric_at(byte_offset, endian)
+    }
+
+    /// Reads an [`i64`] value in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i64_endian_at(self, byte_offset: usize, endian: Endian) -> i64 {
+        self.read_numeric_at(byte_offset, endian)
+    }
+
+    /// Reads an [`i128`] value in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i128_endian_at(self, byte_offset: usize, endian: Endian) -> i128 {
+        self.read_numeric_at(byte_offset, endian)
+    }
+
+    /// Reads an [`f32`] value in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f32_endian_at(self, byte_offset: usize, endian: Endian) -> f32 {
+        self.read_numeric_at(byte_offset, endian)
+    }
+
+    /// Reads an [`f64`] value in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f64_endian_at(self, byte_offset: usize, endian: Endian) -> f64 {
+        self.read_numeric_at(byte_offset, endian)
+    }
+
+    /// Reads an unsigned integer `byte_count` bytes wide (`0..=8`) in the given byte order,
+    /// zero-extended to a [`u64`].
+    ///
+    /// For variable-width fields whose width is only known at runtime (e.g. from a header).
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `byte_count` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - `byte_count` must be at most 8 (checked with [`debug_assert!`])
+    #[inline]
+    unsafe fn read_uint_dyn_at(self, byte_offset: usize, byte_count: usize, endian: Endian) -> u64 {
+        debug_check!(byte_count <= 8, "byte_count {byte_count} exceeds 8");
+        // Clamped, so a larger `byte_count` can't overflow the shift once the check is
+        // compiled out.
+        let byte_count = byte_count.min(8);
+        let mut value = 0u64;
+        for x in 0..byte_count {
+            let byte = self.read_u8_at(byte_offset + x) as u64;
+            match endian {
+                Endian::Little => value |= byte << (x * 8),
+                Endian::Big => value = (value << 8) | byte,
+            }
+        }
+        value
+    }
+
+    /// Determines the byte order of a [`u16`] field by comparing it against its known
+    /// value (`magic`) when read in each byte order.
+    ///
+    /// Returns [`None`] if neither byte order yields `magic`. If both do (the magic is a
+    /// byte palindrome), [`Endian::NATIVE`] is returned.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn detect_endian_u16_at(self, byte_offset: usize, magic: u16) -> Option<Endian> {
+        let value = self.read_u16_at(byte_offset);
+        if value == magic {
+            Some(Endian::NATIVE)
+        } else if value.swap_bytes() == magic {
+            Some(match Endian::NATIVE {
+                Endian::Little => Endian::Big,
+                Endian::Big => Endian::Little,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Determines the byte order of a [`u32`] field by comparing it against its known
+    /// value (`magic`) when read in each byte order.
+    ///
+    /// Returns [`None`] if neither byte order yields `magic`. If both do (the magic is a
+    /// byte palindrome), [`Endian::NATIVE`] is returned.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn detect_endian_u32_at(self, byte_offset: usize, magic: u32) -> Option<Endian> {
+        let value = self.read_u32_at(byte_offset);
+        if value == magic {
+            Some(Endian::NATIVE)
+        } else if value.swap_bytes() == magic {
+            Some(match Endian::NATIVE {
+                Endian::Little => Endian::Big,
+                Endian::Big => Endian::Little,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<P: UnalignedRead + Copy> EndianRead for P {}
@@ -0,0 +1,221 @@
+//! Readers with the byte order fixed at compile time.
+
+use core::marker::PhantomData;
+
+use super::Endian;
+use crate::{AsBytePtr, EndianRead, UnalignedRead};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A byte order known at compile time; implemented by [`LittleEndian`] and [`BigEndian`].
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait ByteOrder: sealed::Sealed + Copy {
+    /// The runtime equivalent of this byte order.
+    const ENDIAN: Endian;
+}
+
+/// Marker type for little-endian byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LittleEndian;
+
+/// Marker type for big-endian byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BigEndian;
+
+/// Marker type for the byte order of the target platform.
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+
+/// Marker type for the byte order of the target platform.
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
+impl sealed::Sealed for LittleEndian {}
+impl sealed::Sealed for BigEndian {}
+
+impl ByteOrder for LittleEndian {
+    const ENDIAN: Endian = Endian::Little;
+}
+
+impl ByteOrder for BigEndian {
+    const ENDIAN: Endian = Endian::Big;
+}
+
+/// A pointer whose reads use the byte order `E`, fixed in the type.
+///
+/// The type-level alternative to passing an [`Endian`] to every read: each method is
+/// monomorphized for `E`, so the byte order conversion has no runtime branch.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::endian::{BigEndian, Reader};
+///
+/// let data = [0x00u8, 0x00, 0x01, 0x00];
+/// let reader = Reader::<BigEndian>::new(data.as_ptr());
+///
+/// unsafe {
+///     assert_eq!(reader.read_u32_at(0), 256);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reader<E: ByteOrder> {
+    ptr: *const u8,
+    _order: PhantomData<E>,
+}
+
+impl<E: ByteOrder> Reader<E> {
+    /// Creates a reader over the memory at `ptr`.
+    #[inline(always)]
+    pub fn new(ptr: impl AsBytePtr) -> Self {
+        Self {
+            ptr: ptr.as_byte_ptr(),
+            _order: PhantomData,
+        }
+    }
+
+    /// Returns the base pointer of the reader.
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *const u8 {
+        self.ptr
+    }
+
+    /// Reads a [`u8`] value at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    pub unsafe fn read_u8_at(&self, byte_offset: usize) -> u8 {
+        self.ptr.read_u8_at(byte_offset)
+    }
+
+    /// Reads an [`i8`] value at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    pub unsafe fn read_i8_at(&self, byte_offset: usize) -> i8 {
+        self.ptr.read_i8_at(byte_offset)
+    }
+
+    // Unsigned integer types
+
+    /// Reads a [`u16`] value in byte order `E` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_u16_at(&self, byte_offset: usize) -> u16 {
+        self.ptr.read_u16_endian_at(byte_offset, E::ENDIAN)
+    }
+
+    /// Reads a [`u32`] value in byte order `E` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_u32_at(&self, byte_offset: usize) -> u32 {
+        self.ptr.read_u32_endian_at(byte_offset, E::ENDIAN)
+    }
+
+    /// Reads a [`u64`] value in byte order `E` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_u64_at(&self, byte_offset: usize) -> u64 {
+        self.ptr.read_u64_endian_at(byte_offset, E::ENDIAN)
+    }
+
+    /// Reads a [`u128`] value in byte order `E` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_u128_at(&self, byte_offset: usize) -> u128 {
+        self.ptr.read_u128_endian_at(byte_offset, E::ENDIAN)
+    }
+
+    // Signed integer types
+
+    /// Reads an [`i16`] value in byte order `E` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_i16_at(&self, byte_offset: usize) -> i16 {
+        self.ptr.read_i16_endian_at(byte_offset, E::ENDIAN)
+    }
+
+    /// Reads an [`i32`] value in byte order `E` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_i32_at(&self, byte_offset: usize) -> i32 {
+        self.ptr.read_i32_endian_at(byte_offset, E::ENDIAN)
+    }
+
+    /// Reads an [`i64`] value in byte order `E` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_i64_at(&self, byte_offset: usize) -> i64 {
+        self.ptr.read_i64_endian_at(byte_offset, E::ENDIAN)
+    }
+
+    /// Reads an [`i128`] value in byte order `E` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_i128_at(&self, byte_offset: usize) -> i128 {
+        self.ptr.read_i128_endian_at(byte_offset, E::ENDIAN)
+    }
+
+    // Floating point types
+
+    /// Reads an [`f32`] value in byte order `E` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_f32_at(&self, byte_offset: usize) -> f32 {
+        self.ptr.read_f32_endian_at(byte_offset, E::ENDIAN)
+    }
+
+    /// Reads an [`f64`] value in byte order `E` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    pub unsafe fn read_f64_at(&self, byte_offset: usize) -> f64 {
+        self.ptr.read_f64_endian_at(byte_offset, E::ENDIAN)
+    }
+}
@@ -0,0 +1,104 @@
+//! Bulk reads of arrays stored in the opposite byte order.
+
+use crate::UnalignedRead;
+
+/// Trait providing bulk reads of arrays in the non-native byte order, swapping the bytes
+/// of each element as it is read.
+///
+/// Swapping while reading fuses the two passes of a read-then-swap loop into one. To
+/// read big-endian data portably, use these only when
+/// [`Endian::Big.is_native()`](crate::Endian::is_native) is `false`.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::SwappedRead;
+///
+/// let data = [0x00u8, 0x01, 0x00, 0x02];
+/// let mut out = [0u16; 2];
+/// unsafe { data.as_ptr().read_u16_array_swapped_at(0, &mut out) };
+///
+/// assert_eq!(out, [u16::from_ne_bytes([0x01, 0x00]), u16::from_ne_bytes([0x02, 0x00])]);
+/// ```
+pub trait SwappedRead: UnalignedRead + Copy {
+    // Unsigned integer types
+
+    /// Reads `out.len()` consecutive byte-swapped [`u16`] values starting at the given
+    /// byte offset into `out`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `out.len() * 2` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_u16_array_swapped_at(self, byte_offset: usize, out: &mut [u16]) {
+        for (x, slot) in out.iter_mut().enumerate() {
+            let offset = byte_offset + x * 2;
+            *slot = self.read_u16_at(offset).swap_bytes();
+        }
+    }
+
+    /// Reads `out.len()` consecutive byte-swapped [`u32`] values starting at the given
+    /// byte offset into `out`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `out.len() * 4` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_u32_array_swapped_at(self, byte_offset: usize, out: &mut [u32]) {
+        for (x, slot) in out.iter_mut().enumerate() {
+            let offset = byte_offset + x * 4;
+            *slot = self.read_u32_at(offset).swap_bytes();
+        }
+    }
+
+    /// Reads `out.len()` consecutive byte-swapped [`u64`] values starting at the given
+    /// byte offset into `out`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `out.len() * 8` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_u64_array_swapped_at(self, byte_offset: usize, out: &mut [u64]) {
+        for (x, slot) in out.iter_mut().enumerate() {
+            let offset = byte_offset + x * 8;
+            *slot = self.read_u64_at(offset).swap_bytes();
+        }
+    }
+
+    // Floating point types
+
+    /// Reads `out.len()` consecutive byte-swapped [`f32`] values starting at the given
+    /// byte offset into `out`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `out.len() * 4` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_f32_array_swapped_at(self, byte_offset: usize, out: &mut [f32]) {
+        for (x, slot) in out.iter_mut().enumerate() {
+            let offset = byte_offset + x * 4;
+            *slot = f32::from_bits(self.read_u32_at(offset).swap_bytes());
+        }
+    }
+
+    /// Reads `out.len()` consecutive byte-swapped [`f64`] values starting at the given
+    /// byte offset into `out`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `out.len() * 8` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_f64_array_swapped_at(self, byte_offset: usize, out: &mut [f64]) {
+        for (x, slot) in out.iter_mut().enumerate() {
+            let offset = byte_offset + x * 8;
+            *slot = f64::from_bits(self.read_u64_at(offset).swap_bytes());
+        }
+    }
+}
+
+impl<P: UnalignedRead + Copy> SwappedRead for P {}
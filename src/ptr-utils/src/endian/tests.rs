@@ -0,0 +1,276 @@
+use super::*;
+use crate::WriteError;
+
+#[test]
+fn test_native_endian() {
+    assert!(Endian::NATIVE.is_native());
+    #[cfg(target_endian = "little")]
+    assert!(!Endian::Big.is_native());
+    #[cfg(target_endian = "big")]
+    assert!(!Endian::Little.is_native());
+}
+
+#[test]
+fn test_endian_reads() {
+    let data = [0x01u8, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09];
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_u16_endian_at(1, Endian::Little), 0x0302);
+        assert_eq!(ptr.read_u16_endian_at(1, Endian::Big), 0x0203);
+        assert_eq!(ptr.read_u32_endian_at(1, Endian::Big), 0x02030405);
+        assert_eq!(
+            ptr.read_i64_endian_at(1, Endian::Little),
+            0x0908070605040302
+        );
+        assert_eq!(
+            ptr.read_f32_endian_at(0, Endian::Big),
+            f32::from_be_bytes([0x01, 0x02, 0x03, 0x04])
+        );
+    }
+}
+
+#[test]
+fn test_detect_endian() {
+    const MAGIC: u32 = 0xA1B2C3D4; // pcap
+    let le = MAGIC.to_le_bytes();
+    let be = MAGIC.to_be_bytes();
+    let neither = [0u8; 4];
+
+    unsafe {
+        assert_eq!(
+            le.as_ptr().detect_endian_u32_at(0, MAGIC),
+            Some(Endian::Little)
+        );
+        assert_eq!(
+            be.as_ptr().detect_endian_u32_at(0, MAGIC),
+            Some(Endian::Big)
+        );
+        assert_eq!(neither.as_ptr().detect_endian_u32_at(0, MAGIC), None);
+
+        // Once detected, the same endianness reads the field back.
+        let endian = be.as_ptr().detect_endian_u32_at(0, MAGIC).unwrap();
+        assert_eq!(be.as_ptr().read_u32_endian_at(0, endian), MAGIC);
+
+        let tiff = [b'I', b'I', 0x2A, 0x00];
+        assert_eq!(
+            tiff.as_ptr().detect_endian_u16_at(2, 42),
+            Some(Endian::Little)
+        );
+    }
+}
+
+#[test]
+fn test_read_uint_dyn() {
+    let data = [0x01u8, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09];
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_uint_dyn_at(1, 3, Endian::Little), 0x040302);
+        assert_eq!(ptr.read_uint_dyn_at(1, 3, Endian::Big), 0x020304);
+        assert_eq!(ptr.read_uint_dyn_at(0, 1, Endian::Big), 0x01);
+        assert_eq!(ptr.read_uint_dyn_at(1, 8, Endian::Big), 0x0203040506070809);
+        assert_eq!(ptr.read_uint_dyn_at(0, 0, Endian::Little), 0);
+    }
+}
+
+#[test]
+fn test_write_uint_dyn_round_trip() {
+    for endian in [Endian::Little, Endian::Big] {
+        let mut buffer = [0u8; 10];
+        let ptr = buffer.as_mut_ptr();
+
+        unsafe {
+            ptr.write_uint_dyn_at(1, 0xABCDEF, 3, endian);
+            ptr.write_uint_dyn_at(4, 0x12_3456_789A, 5, endian);
+            assert_eq!(ptr.read_uint_dyn_at(1, 3, endian), 0xABCDEF);
+            assert_eq!(ptr.read_uint_dyn_at(4, 5, endian), 0x12_3456_789A);
+        }
+
+        match endian {
+            Endian::Little => assert_eq!(buffer[1..4], [0xEF, 0xCD, 0xAB]),
+            Endian::Big => assert_eq!(buffer[1..4], [0xAB, 0xCD, 0xEF]),
+        }
+        assert_eq!(buffer[0], 0);
+        assert_eq!(buffer[9], 0);
+    }
+}
+
+#[test]
+fn test_write_uint_dyn_overflow_policies() {
+    let mut buffer = [0u8; 4];
+    let ptr = buffer.as_mut_ptr();
+    let overflowing = 0xAB_CDEF12u64;
+
+    unsafe {
+        assert_eq!(
+            ptr.write_uint_dyn_with_policy_at(
+                0,
+                overflowing,
+                3,
+                Endian::Big,
+                OverflowPolicy::Error
+            ),
+            Err(WriteError::Overflow {
+                offset: 0,
+                byte_count: 3
+            })
+        );
+        assert_eq!(buffer, [0, 0, 0, 0]);
+
+        assert_eq!(
+            ptr.write_uint_dyn_with_policy_at(
+                0,
+                overflowing,
+                3,
+                Endian::Big,
+                OverflowPolicy::Truncate
+            ),
+            Ok(())
+        );
+        assert_eq!(buffer, [0xCD, 0xEF, 0x12, 0]);
+
+        // Values that fit are written under every policy.
+        for policy in [
+            OverflowPolicy::Truncate,
+            OverflowPolicy::Error,
+            OverflowPolicy::DebugAssert,
+        ] {
+            assert_eq!(
+                ptr.write_uint_dyn_with_policy_at(1, 0x0102, 2, Endian::Little, policy),
+                Ok(())
+            );
+            assert_eq!(ptr.read_uint_dyn_at(1, 2, Endian::Little), 0x0102);
+        }
+
+        // A zero-width field only holds zero.
+        assert!(ptr
+            .write_uint_dyn_with_policy_at(0, 1, 0, Endian::Little, OverflowPolicy::Error)
+            .is_err());
+    }
+}
+
+#[test]
+#[cfg(all(debug_assertions, not(feature = "no-panic")))]
+#[should_panic(expected = "does not fit in 2 bytes")]
+fn test_write_uint_dyn_overflow_debug_assert_panics() {
+    let mut buffer = [0u8; 2];
+    unsafe {
+        let _ = buffer.as_mut_ptr().write_uint_dyn_with_policy_at(
+            0,
+            0x10000,
+            2,
+            Endian::Little,
+            OverflowPolicy::DebugAssert,
+        );
+    }
+}
+
+#[test]
+fn test_typed_readers() {
+    let data = [0x01u8, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09];
+    let little = Reader::<LittleEndian>::new(data.as_ptr());
+    let big = Reader::<BigEndian>::new(data.as_ptr());
+    let native = Reader::<NativeEndian>::new(data.as_ptr());
+
+    unsafe {
+        assert_eq!(little.read_u16_at(1), 0x0302);
+        assert_eq!(big.read_u16_at(1), 0x0203);
+        assert_eq!(big.read_i64_at(1), 0x0203040506070809);
+        assert_eq!(little.read_u8_at(8), 9);
+        assert_eq!(native.read_u32_at(0), u32::from_ne_bytes([1, 2, 3, 4]));
+        assert_eq!(big.read_f32_at(0), f32::from_be_bytes([1, 2, 3, 4]));
+    }
+
+    assert_eq!(LittleEndian::ENDIAN, Endian::Little);
+    assert_eq!(big.as_ptr(), data.as_ptr());
+}
+
+#[test]
+#[cfg(target_endian = "little")]
+fn test_read_arrays_swapped_from_big_endian() {
+    let values32 = [1u32, 0xDEADBEEF, u32::MAX - 1];
+    let mut data = [0u8; 1 + 12];
+    for (i, v) in values32.iter().enumerate() {
+        data[1 + i * 4..5 + i * 4].copy_from_slice(&v.to_be_bytes());
+    }
+
+    let mut out = [0u32; 3];
+    unsafe { data.as_ptr().read_u32_array_swapped_at(1, &mut out) };
+    assert_eq!(out, values32);
+
+    let values16 = [0x0102u16, 0xA0B0];
+    let values64 = [0x0102030405060708u64, 9];
+    let floats32 = [1.5f32, -0.25];
+    let floats64 = [core::f64::consts::PI, -1.0e300];
+    let mut data = [0u8; 4 + 16 + 8 + 16];
+    for (i, v) in values16.iter().enumerate() {
+        data[i * 2..i * 2 + 2].copy_from_slice(&v.to_be_bytes());
+    }
+    for (i, v) in values64.iter().enumerate() {
+        data[4 + i * 8..12 + i * 8].copy_from_slice(&v.to_be_bytes());
+    }
+    for (i, v) in floats32.iter().enumerate() {
+        data[20 + i * 4..24 + i * 4].copy_from_slice(&v.to_be_bytes());
+    }
+    for (i, v) in floats64.iter().enumerate() {
+        data[28 + i * 8..36 + i * 8].copy_from_slice(&v.to_be_bytes());
+    }
+    let ptr = data.as_ptr();
+
+    let (mut out16, mut out64, mut outf32, mut outf64) =
+        ([0u16; 2], [0u64; 2], [0f32; 2], [0f64; 2]);
+    unsafe {
+        ptr.read_u16_array_swapped_at(0, &mut out16);
+        ptr.read_u64_array_swapped_at(4, &mut out64);
+        ptr.read_f32_array_swapped_at(20, &mut outf32);
+        ptr.read_f64_array_swapped_at(28, &mut outf64);
+        ptr.read_u32_array_swapped_at(0, &mut []);
+    }
+    assert_eq!(out16, values16);
+    assert_eq!(out64, values64);
+    assert_eq!(outf32, floats32);
+    assert_eq!(outf64, floats64);
+}
+
+#[test]
+fn test_u24() {
+    let mut buffer = [0u8; 8];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_u24_le_at(1, 0x123456);
+        ptr.write_u24_be_at(4, 0x123456);
+        assert_eq!(buffer[1..7], [0x56, 0x34, 0x12, 0x12, 0x34, 0x56]);
+        assert_eq!(ptr.read_u24_le_at(1), 0x123456);
+        assert_eq!(ptr.read_u24_be_at(4), 0x123456);
+
+        // Writes truncate to 3 bytes, leaving the neighbours intact.
+        ptr.write_u24_le_at(1, 0xAABBCCDD);
+        assert_eq!(ptr.read_u24_le_at(1), 0xBBCCDD);
+        assert_eq!(buffer[4], 0x12);
+    }
+}
+
+#[test]
+fn test_i24_sign_boundary() {
+    let mut buffer = [0u8; 7];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        for value in [-0x800000, -0x7FFFFF, -1, 0, 1, 0x7FFFFE, 0x7FFFFF] {
+            ptr.write_i24_le_at(1, value);
+            ptr.write_i24_be_at(4, value);
+            assert_eq!(ptr.read_i24_le_at(1), value);
+            assert_eq!(ptr.read_i24_be_at(4), value);
+        }
+
+        // Bit 23 set is negative; the same bits unsigned are not.
+        ptr.write_u24_be_at(4, 0x800000);
+        assert_eq!(ptr.read_i24_be_at(4), -0x800000);
+        assert_eq!(ptr.read_u24_be_at(4), 0x800000);
+        ptr.write_u24_le_at(1, 0x7FFFFF);
+        assert_eq!(ptr.read_i24_le_at(1), 0x7FFFFF);
+        assert_eq!(buffer[1..4], [0xFF, 0xFF, 0x7F]);
+    }
+}
@@ -0,0 +1,139 @@
+//! 24-bit integer reads and writes.
+
+use crate::{UnalignedRead, UnalignedWrite};
+
+/// Trait providing reads of 3-byte integers, widened to 32 bits.
+///
+/// 24-bit fields are common in audio samples, colour data and MIDI. The signed reads
+/// sign-extend from bit 23.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::U24Read;
+///
+/// // A 24-bit PCM sample of -2, little-endian.
+/// let data = [0xFEu8, 0xFF, 0xFF];
+/// let ptr = data.as_ptr();
+///
+/// unsafe {
+///     assert_eq!(ptr.read_i24_le_at(0), -2);
+///     assert_eq!(ptr.read_u24_le_at(0), 0xFFFFFE);
+///     assert_eq!(ptr.read_u24_be_at(0), 0xFEFFFF);
+/// }
+/// ```
+pub trait U24Read: UnalignedRead + Copy {
+    /// Reads a little-endian 24-bit unsigned integer from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u24_le_at(self, byte_offset: usize) -> u32 {
+        let [b0, b1, b2] = self.read_at::<[u8; 3]>(byte_offset);
+        u32::from_le_bytes([b0, b1, b2, 0])
+    }
+
+    /// Reads a big-endian 24-bit unsigned integer from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u24_be_at(self, byte_offset: usize) -> u32 {
+        let [b0, b1, b2] = self.read_at::<[u8; 3]>(byte_offset);
+        u32::from_be_bytes([0, b0, b1, b2])
+    }
+
+    /// Reads a little-endian 24-bit signed integer from the pointer at the given byte offset,
+    /// sign-extended to an [`i32`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i24_le_at(self, byte_offset: usize) -> i32 {
+        sign_extend_i24(self.read_u24_le_at(byte_offset))
+    }
+
+    /// Reads a big-endian 24-bit signed integer from the pointer at the given byte offset,
+    /// sign-extended to an [`i32`].
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i24_be_at(self, byte_offset: usize) -> i32 {
+        sign_extend_i24(self.read_u24_be_at(byte_offset))
+    }
+}
+
+impl<P: UnalignedRead + Copy> U24Read for P {}
+
+/// Trait providing writes of 3-byte integers.
+///
+/// Values are truncated to their low 24 bits, so any [`i32`] in `-0x800000..=0x7FFFFF`
+/// round-trips through the signed reads of [`U24Read`].
+pub trait U24Write: UnalignedWrite + Copy {
+    /// Writes the low 24 bits of `value` little-endian to the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_u24_le_at(self, byte_offset: usize, value: u32) {
+        let [b0, b1, b2, _] = value.to_le_bytes();
+        self.write_at(byte_offset, [b0, b1, b2]);
+    }
+
+    /// Writes the low 24 bits of `value` big-endian to the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_u24_be_at(self, byte_offset: usize, value: u32) {
+        let [_, b0, b1, b2] = value.to_be_bytes();
+        self.write_at(byte_offset, [b0, b1, b2]);
+    }
+
+    /// Writes the low 24 bits of `value` little-endian to the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_i24_le_at(self, byte_offset: usize, value: i32) {
+        self.write_u24_le_at(byte_offset, value as u32);
+    }
+
+    /// Writes the low 24 bits of `value` big-endian to the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_i24_be_at(self, byte_offset: usize, value: i32) {
+        self.write_u24_be_at(byte_offset, value as u32);
+    }
+}
+
+impl<P: UnalignedWrite + Copy> U24Write for P {}
+
+/// Sign-extends the low 24 bits of `value` by shifting bit 23 into the sign bit and back.
+#[inline(always)]
+const fn sign_extend_i24(value: u32) -> i32 {
+    ((value << 8) as i32) >> 8
+}
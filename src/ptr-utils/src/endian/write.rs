@@ -0,0 +1,119 @@
+//! Writes with a runtime-selected byte order.
+
+use super::Endian;
+use crate::{UnalignedWrite, WriteError};
+
+/// Trait providing writes whose byte order is selected at runtime.
+pub trait EndianWrite: UnalignedWrite + Copy {
+    /// Writes the low `byte_count` bytes (`0..=8`) of `value` in the given byte order.
+    ///
+    /// The counterpart to [`EndianRead::read_uint_dyn_at`](crate::EndianRead::read_uint_dyn_at).
+    /// `value` must fit in `byte_count` bytes; this is checked with [`debug_assert!`], and in
+    /// release builds any higher bytes are silently truncated. Use
+    /// [`write_uint_dyn_with_policy_at`](EndianWrite::write_uint_dyn_with_policy_at) to
+    /// choose the behaviour explicitly.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `byte_count` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - `byte_count` must be at most 8 (checked with [`debug_assert!`])
+    #[inline]
+    unsafe fn write_uint_dyn_at(
+        self,
+        byte_offset: usize,
+        value: u64,
+        byte_count: usize,
+        endian: Endian,
+    ) {
+        // Never fails under `DebugAssert`.
+        let _ = self.write_uint_dyn_with_policy_at(
+            byte_offset,
+            value,
+            byte_count,
+            endian,
+            OverflowPolicy::DebugAssert,
+        );
+    }
+
+    /// Writes the low `byte_count` bytes (`0..=8`) of `value` in the given byte order,
+    /// handling a `value` too wide for `byte_count` bytes according to `policy`.
+    ///
+    /// Returns [`WriteError::Overflow`] (having written nothing) only under
+    /// [`OverflowPolicy::Error`]; the other policies always return `Ok(())`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `byte_count` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - `byte_count` must be at most 8 (checked with [`debug_assert!`])
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::{Endian, EndianWrite, OverflowPolicy, WriteError};
+    ///
+    /// let mut buffer = [0u8; 3];
+    /// let ptr = buffer.as_mut_ptr();
+    ///
+    /// unsafe {
+    ///     let result = ptr.write_uint_dyn_with_policy_at(
+    ///         0, 0x1_000000, 3, Endian::Little, OverflowPolicy::Error,
+    ///     );
+    ///     assert_eq!(result, Err(WriteError::Overflow { offset: 0, byte_count: 3 }));
+    /// }
+    /// assert_eq!(buffer, [0, 0, 0]);
+    /// ```
+    #[inline]
+    unsafe fn write_uint_dyn_with_policy_at(
+        self,
+        byte_offset: usize,
+        value: u64,
+        byte_count: usize,
+        endian: Endian,
+        policy: OverflowPolicy,
+    ) -> Result<(), WriteError> {
+        debug_check!(byte_count <= 8, "byte_count {byte_count} exceeds 8");
+        // Clamped, so a larger `byte_count` can't overflow the shift once the check is
+        // compiled out.
+        let byte_count = byte_count.min(8);
+        let fits = byte_count >= 8 || value >> (byte_count * 8) == 0;
+        match policy {
+            OverflowPolicy::Truncate => {}
+            OverflowPolicy::Error if !fits => {
+                return Err(WriteError::Overflow {
+                    offset: byte_offset,
+                    byte_count,
+                });
+            }
+            OverflowPolicy::Error => {}
+            OverflowPolicy::DebugAssert => {
+                debug_check!(fits, "value {value:#x} does not fit in {byte_count} bytes");
+            }
+        }
+
+        for x in 0..byte_count {
+            let shift = match endian {
+                Endian::Little => x * 8,
+                Endian::Big => (byte_count - 1 - x) * 8,
+            };
+            self.write_u8_at(byte_offset + x, (value >> shift) as u8);
+        }
+        Ok(())
+    }
+}
+
+impl<P: UnalignedWrite + Copy> EndianWrite for P {}
+
+/// What a dynamic-width write does with a value too wide for its field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverflowPolicy {
+    /// Write the low bytes, silently discarding the rest.
+    Truncate,
+    /// Write nothing and return [`WriteError::Overflow`].
+    Error,
+    /// Panic in debug builds; truncate in release builds, or always under the `no-panic`
+    /// feature.
+    #[default]
+    DebugAssert,
+}
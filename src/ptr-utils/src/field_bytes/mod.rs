@@ -0,0 +1,10 @@
+//! Reading and writing the raw bytes of fields, for forwarding them uninterpreted.
+
+pub mod read;
+pub mod write;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::FieldBytesRead;
+pub use write::FieldBytesWrite;
@@ -0,0 +1,168 @@
+//! Reading the raw bytes of fields without interpreting them.
+
+use core::mem::size_of;
+
+use crate::UnalignedRead;
+
+/// Trait for reading the bytes of a field in memory order, rather than its value.
+///
+/// Equivalent to `read_at::<[u8; N]>`, but the method name documents the intent
+/// ("forward these bytes as-is") and avoids the turbofish. For a field stored
+/// little-endian, the result is its little-endian byte representation.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{FieldBytesRead, FieldBytesWrite};
+///
+/// let src = [0xAAu8, 0x01, 0x02, 0x03, 0x04];
+/// let mut dst = [0u8; 4];
+///
+/// unsafe {
+///     let bytes = src.as_ptr().read_u32_bytes_at(1);
+///     assert_eq!(bytes, [0x01, 0x02, 0x03, 0x04]);
+///     dst.as_mut_ptr().write_bytes_u32_at(0, bytes);
+/// }
+/// assert_eq!(dst, [0x01, 0x02, 0x03, 0x04]);
+/// ```
+pub trait FieldBytesRead: UnalignedRead + Copy {
+    // Unsigned integer types
+
+    /// Reads the bytes of a [`u16`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u16_bytes_at(self, byte_offset: usize) -> [u8; 2] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads the bytes of a [`u32`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u32_bytes_at(self, byte_offset: usize) -> [u8; 4] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads the bytes of a [`u64`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u64_bytes_at(self, byte_offset: usize) -> [u8; 8] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads the bytes of a [`u128`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u128_bytes_at(self, byte_offset: usize) -> [u8; 16] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads the bytes of a [`usize`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading [`size_of::<usize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_usize_bytes_at(self, byte_offset: usize) -> [u8; size_of::<usize>()] {
+        self.read_at(byte_offset)
+    }
+
+    // Signed integer types
+
+    /// Reads the bytes of an [`i16`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i16_bytes_at(self, byte_offset: usize) -> [u8; 2] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads the bytes of an [`i32`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i32_bytes_at(self, byte_offset: usize) -> [u8; 4] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads the bytes of an [`i64`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i64_bytes_at(self, byte_offset: usize) -> [u8; 8] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads the bytes of an [`i128`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i128_bytes_at(self, byte_offset: usize) -> [u8; 16] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads the bytes of an [`isize`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading [`size_of::<isize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_isize_bytes_at(self, byte_offset: usize) -> [u8; size_of::<isize>()] {
+        self.read_at(byte_offset)
+    }
+
+    // Floating point types
+
+    /// Reads the bytes of an [`f32`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f32_bytes_at(self, byte_offset: usize) -> [u8; 4] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads the bytes of an [`f64`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f64_bytes_at(self, byte_offset: usize) -> [u8; 8] {
+        self.read_at(byte_offset)
+    }
+}
+
+impl<P: UnalignedRead + Copy> FieldBytesRead for P {}
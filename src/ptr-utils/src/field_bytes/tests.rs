@@ -0,0 +1,33 @@
+use super::*;
+use crate::UnalignedWrite;
+
+#[test]
+fn test_field_bytes_memory_order() {
+    let mut buffer = [0u8; 16];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_u32_at(1, 0x12345678);
+        assert_eq!(ptr.read_u32_bytes_at(1), 0x12345678u32.to_ne_bytes());
+
+        ptr.write_f64_at(5, 1.5);
+        assert_eq!(ptr.read_f64_bytes_at(5), 1.5f64.to_ne_bytes());
+
+        ptr.write_bytes_u16_at(14, [0xAB, 0xCD]);
+        assert_eq!(ptr.read_u16_bytes_at(14), [0xAB, 0xCD]);
+        assert_eq!(buffer[14..], [0xAB, 0xCD]);
+    }
+}
+
+#[test]
+fn test_field_bytes_forwarding() {
+    let src = 0x0102030405060708u64.to_le_bytes();
+    let mut dst = [0u8; 9];
+
+    unsafe {
+        let bytes = src.as_ptr().read_i64_bytes_at(0);
+        dst.as_mut_ptr().write_bytes_i64_at(1, bytes);
+    }
+
+    assert_eq!(dst[1..], src);
+}
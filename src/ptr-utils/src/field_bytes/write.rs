@@ -0,0 +1,162 @@
+//! Writing the raw bytes of fields without interpreting them.
+
+use core::mem::size_of;
+
+use crate::UnalignedWrite;
+
+/// Trait for writing the bytes of a field in memory order, rather than its value.
+///
+/// The counterpart to [`FieldBytesRead`](crate::FieldBytesRead).
+pub trait FieldBytesWrite: UnalignedWrite + Copy {
+    // Unsigned integer types
+
+    /// Writes the bytes of a [`u16`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_u16_at(self, byte_offset: usize, bytes: [u8; 2]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    /// Writes the bytes of a [`u32`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_u32_at(self, byte_offset: usize, bytes: [u8; 4]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    /// Writes the bytes of a [`u64`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_u64_at(self, byte_offset: usize, bytes: [u8; 8]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    /// Writes the bytes of a [`u128`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_u128_at(self, byte_offset: usize, bytes: [u8; 16]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    /// Writes the bytes of a [`usize`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing [`size_of::<usize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_usize_at(self, byte_offset: usize, bytes: [u8; size_of::<usize>()]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    // Signed integer types
+
+    /// Writes the bytes of an [`i16`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_i16_at(self, byte_offset: usize, bytes: [u8; 2]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    /// Writes the bytes of an [`i32`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_i32_at(self, byte_offset: usize, bytes: [u8; 4]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    /// Writes the bytes of an [`i64`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_i64_at(self, byte_offset: usize, bytes: [u8; 8]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    /// Writes the bytes of an [`i128`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_i128_at(self, byte_offset: usize, bytes: [u8; 16]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    /// Writes the bytes of an [`isize`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing [`size_of::<isize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_isize_at(self, byte_offset: usize, bytes: [u8; size_of::<isize>()]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    // Floating point types
+
+    /// Writes the bytes of an [`f32`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_f32_at(self, byte_offset: usize, bytes: [u8; 4]) {
+        self.write_at(byte_offset, bytes);
+    }
+
+    /// Writes the bytes of an [`f64`] field at the given byte offset, in memory order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_bytes_f64_at(self, byte_offset: usize, bytes: [u8; 8]) {
+        self.write_at(byte_offset, bytes);
+    }
+}
+
+impl<P: UnalignedWrite + Copy> FieldBytesWrite for P {}
@@ -0,0 +1,100 @@
+//! Finite and clamped float reads.
+
+use crate::UnalignedRead;
+
+/// Trait providing float reads that reject or clamp non-finite values.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::FiniteRead;
+///
+/// let data = f32::NAN.to_ne_bytes();
+/// unsafe {
+///     assert_eq!(data.as_ptr().read_f32_finite_at(0), None);
+///     assert_eq!(data.as_ptr().read_f32_clamped_at(0, -1.0, 1.0), -1.0);
+/// }
+/// ```
+pub trait FiniteRead: UnalignedRead + Copy {
+    /// Reads an [`f32`] value at the given byte offset, returning [`None`] if it is NaN or
+    /// infinite.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f32_finite_at(self, byte_offset: usize) -> Option<f32> {
+        let value = self.read_f32_at(byte_offset);
+        value.is_finite().then_some(value)
+    }
+
+    /// Reads an [`f32`] value at the given byte offset, clamped to `min..=max`.
+    ///
+    /// Infinities clamp to the nearest bound. NaN maps to `min`.
+    ///
+    /// # Panics
+    /// If `min > max`, or if either bound is NaN. Under the `no-panic` feature, an invalid
+    /// range does not panic, and the result is only guaranteed to be one of the inputs.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f32_clamped_at(self, byte_offset: usize, min: f32, max: f32) -> f32 {
+        let value = self.read_f32_at(byte_offset);
+        if cfg!(feature = "no-panic") {
+            // `max` and `min` never panic; NaN is ignored by `max`, so it maps to `min`.
+            return value.max(min).min(max);
+        }
+        if value.is_nan() {
+            // Validate the bounds as `clamp` would, so NaN input doesn't mask a bad range.
+            assert!(min <= max, "invalid clamp range");
+            return min;
+        }
+        value.clamp(min, max)
+    }
+
+    /// Reads an [`f64`] value at the given byte offset, returning [`None`] if it is NaN or
+    /// infinite.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f64_finite_at(self, byte_offset: usize) -> Option<f64> {
+        let value = self.read_f64_at(byte_offset);
+        value.is_finite().then_some(value)
+    }
+
+    /// Reads an [`f64`] value at the given byte offset, clamped to `min..=max`.
+    ///
+    /// Infinities clamp to the nearest bound. NaN maps to `min`.
+    ///
+    /// # Panics
+    /// If `min > max`, or if either bound is NaN. Under the `no-panic` feature, an invalid
+    /// range does not panic, and the result is only guaranteed to be one of the inputs.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f64_clamped_at(self, byte_offset: usize, min: f64, max: f64) -> f64 {
+        let value = self.read_f64_at(byte_offset);
+        if cfg!(feature = "no-panic") {
+            // `max` and `min` never panic; NaN is ignored by `max`, so it maps to `min`.
+            return value.max(min).min(max);
+        }
+        if value.is_nan() {
+            // Validate the bounds as `clamp` would, so NaN input doesn't mask a bad range.
+            assert!(min <= max, "invalid clamp range");
+            return min;
+        }
+        value.clamp(min, max)
+    }
+}
+
+impl<P: UnalignedRead + Copy> FiniteRead for P {}
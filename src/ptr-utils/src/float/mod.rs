@@ -0,0 +1,11 @@
+//! Float reads that sanitize values from untrusted data.
+//!
+//! NaN and infinity read from a file propagate silently through later arithmetic;
+//! these reads reject or clamp them at the point they enter the program.
+
+pub mod finite;
+
+#[cfg(test)]
+mod tests;
+
+pub use finite::FiniteRead;
@@ -0,0 +1,42 @@
+use super::*;
+use crate::UnalignedWrite;
+
+#[test]
+fn test_finite_reads() {
+    let mut buffer = [0u8; 32];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_f32_at(1, 1.5);
+        ptr.write_u32_at(5, 0x7FC0_0000); // quiet NaN
+        ptr.write_u32_at(9, 0x7F80_0000); // +inf
+        ptr.write_u64_at(13, 0xFFF0_0000_0000_0000); // -inf
+        ptr.write_f64_at(21, -2.0);
+
+        assert_eq!(ptr.read_f32_finite_at(1), Some(1.5));
+        assert_eq!(ptr.read_f32_finite_at(5), None);
+        assert_eq!(ptr.read_f32_finite_at(9), None);
+        assert_eq!(ptr.read_f64_finite_at(13), None);
+        assert_eq!(ptr.read_f64_finite_at(21), Some(-2.0));
+    }
+}
+
+#[test]
+fn test_clamped_reads() {
+    let mut buffer = [0u8; 32];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_f32_at(1, 1.5);
+        ptr.write_u32_at(5, 0x7FC0_0000); // quiet NaN
+        ptr.write_u32_at(9, 0x7F80_0000); // +inf
+        ptr.write_u64_at(13, 0xFFF0_0000_0000_0000); // -inf
+        ptr.write_f64_at(21, 0.25);
+
+        assert_eq!(ptr.read_f32_clamped_at(1, 0.0, 1.0), 1.0);
+        assert_eq!(ptr.read_f32_clamped_at(5, 0.0, 1.0), 0.0);
+        assert_eq!(ptr.read_f32_clamped_at(9, 0.0, 1.0), 1.0);
+        assert_eq!(ptr.read_f64_clamped_at(13, -1.0, 1.0), -1.0);
+        assert_eq!(ptr.read_f64_clamped_at(21, -1.0, 1.0), 0.25);
+    }
+}
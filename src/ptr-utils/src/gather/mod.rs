@@ -0,0 +1,11 @@
+//! Reads that assemble a value from bytes at non-contiguous positions.
+//!
+//! Useful for scrambled or interleaved layouts, where the bytes of one value are
+//! spread across a record rather than stored next to each other.
+
+pub mod read;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::GatherRead;
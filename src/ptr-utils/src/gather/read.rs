@@ -0,0 +1,59 @@
+//! Gathering reads.
+
+use crate::UnalignedRead;
+
+/// Trait providing reads of values whose bytes are scattered across memory.
+pub trait GatherRead: UnalignedRead + Copy {
+    /// Reads one byte from each of the given byte offsets, in order.
+    ///
+    /// # Safety
+    /// - The pointer plus each byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_bytes_from_at<const N: usize>(self, offsets: [usize; N]) -> [u8; N] {
+        offsets.map(|offset| self.read_u8_at(offset))
+    }
+
+    /// Reads a [`u16`] from the bytes at the given offsets, least significant byte first.
+    ///
+    /// # Safety
+    /// - The pointer plus each byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u16_from_at(self, offsets: [usize; 2]) -> u16 {
+        u16::from_le_bytes(self.read_bytes_from_at(offsets))
+    }
+
+    /// Reads a [`u32`] from the bytes at the given offsets, least significant byte first.
+    ///
+    /// # Safety
+    /// - The pointer plus each byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::GatherRead;
+    ///
+    /// // The bytes of 0x44332211 stored in the order 3, 1, 0, 2.
+    /// let data = [0x44u8, 0x22, 0x11, 0x33];
+    /// let value = unsafe { data.as_ptr().read_u32_from_at([2, 1, 3, 0]) };
+    /// assert_eq!(value, 0x44332211);
+    /// ```
+    #[inline(always)]
+    unsafe fn read_u32_from_at(self, offsets: [usize; 4]) -> u32 {
+        u32::from_le_bytes(self.read_bytes_from_at(offsets))
+    }
+
+    /// Reads a [`u64`] from the bytes at the given offsets, least significant byte first.
+    ///
+    /// # Safety
+    /// - The pointer plus each byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u64_from_at(self, offsets: [usize; 8]) -> u64 {
+        u64::from_le_bytes(self.read_bytes_from_at(offsets))
+    }
+}
+
+impl<P: UnalignedRead + Copy> GatherRead for P {}
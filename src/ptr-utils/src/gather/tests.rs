@@ -0,0 +1,31 @@
+use super::*;
+
+#[test]
+fn test_read_scrambled_u32() {
+    // Two interleaved u32 values: 0xDDCCBBAA at even offsets, 0x44332211 at odd offsets.
+    let data = [0xAAu8, 0x11, 0xBB, 0x22, 0xCC, 0x33, 0xDD, 0x44];
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_u32_from_at([0, 2, 4, 6]), 0xDDCCBBAA);
+        assert_eq!(ptr.read_u32_from_at([1, 3, 5, 7]), 0x44332211);
+        assert_eq!(ptr.read_u16_from_at([7, 0]), 0xAA44);
+    }
+}
+
+#[test]
+fn test_read_scrambled_u64() {
+    let value = 0x0807060504030201u64;
+    let order = [5usize, 2, 7, 0, 3, 6, 1, 4];
+
+    // Scatter byte `i` of the value to position `order[i]`.
+    let mut data = [0u8; 8];
+    for (i, &position) in order.iter().enumerate() {
+        data[position] = value.to_le_bytes()[i];
+    }
+
+    unsafe {
+        assert_eq!(data.as_ptr().read_u64_from_at(order), value);
+        assert_eq!(data.as_ptr().read_bytes_from_at([0, 0]), [data[0]; 2]);
+    }
+}
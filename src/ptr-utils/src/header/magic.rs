@@ -0,0 +1,76 @@
+//! Magic number (file signature) checks.
+
+use crate::{AsBytePtr, RegionRead, UnalignedRead};
+
+/// Trait providing the "verify the file signature first" step of format parsing.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{MagicRead, UnalignedRead};
+///
+/// const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+///
+/// let mut file = [0u8; 16];
+/// file[..8].copy_from_slice(PNG_SIGNATURE);
+/// file[8..12].copy_from_slice(&13u32.to_be_bytes()); // IHDR chunk length
+///
+/// let ptr = file.as_ptr();
+/// let chunk_len = unsafe {
+///     ptr.expect_magic_then(0, PNG_SIGNATURE, |p| u32::from_be(p.read_u32_at(8)))
+/// };
+/// assert_eq!(chunk_len, Some(13));
+///
+/// let not_png = [0u8; 16];
+/// let result = unsafe { not_png.as_ptr().expect_magic_then(0, PNG_SIGNATURE, |_| ()) };
+/// assert_eq!(result, None);
+/// ```
+pub trait MagicRead: UnalignedRead + AsBytePtr + Copy {
+    /// Returns `true` if the bytes at the given byte offset match the `expected` signature.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `expected.len()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn check_magic_at(self, byte_offset: usize, expected: &[u8]) -> bool {
+        self.bytes_eq_at(byte_offset, expected)
+    }
+
+    /// Reads a `(major, minor)` version pair stored as two consecutive [`u16`] values
+    /// (native endianness) at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_version_at(self, byte_offset: usize) -> (u16, u16) {
+        (
+            self.read_u16_at(byte_offset),
+            self.read_u16_at(byte_offset + 2),
+        )
+    }
+
+    /// Runs `f` on the pointer only if the bytes at the given byte offset match `magic`.
+    ///
+    /// Returns [`None`] without calling `f` if the signature does not match.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `magic.len()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn expect_magic_then<T>(
+        self,
+        byte_offset: usize,
+        magic: &[u8],
+        f: impl FnOnce(Self) -> T,
+    ) -> Option<T> {
+        if self.check_magic_at(byte_offset, magic) {
+            Some(f(self))
+        } else {
+            None
+        }
+    }
+}
+
+impl<P: UnalignedRead + AsBytePtr + Copy> MagicRead for P {}
@@ -0,0 +1,10 @@
+//! Validation of file signatures and format headers.
+
+pub mod magic;
+pub mod record;
+
+#[cfg(test)]
+mod tests;
+
+pub use magic::MagicRead;
+pub use record::RecordRead;
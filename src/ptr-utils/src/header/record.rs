@@ -0,0 +1,62 @@
+//! Whole-record validation.
+
+use crate::{Pod, UnalignedRead};
+
+/// Trait providing the "read a header struct, then sanity-check it" step of format
+/// parsing.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{Pod, RecordRead};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// #[repr(C)]
+/// struct Header {
+///     version: u16,
+///     reserved: u16,
+///     entry_count: u32,
+/// }
+///
+/// // SAFETY: all fields are `Pod`, and there is no padding.
+/// unsafe impl Pod for Header {}
+///
+/// fn check(header: &Header) -> Result<(), &'static str> {
+///     if !(1..=3).contains(&header.version) {
+///         return Err("unsupported version");
+///     }
+///     if header.reserved != 0 {
+///         return Err("reserved field is not zero");
+///     }
+///     Ok(())
+/// }
+///
+/// let header = Header { version: 2, reserved: 0, entry_count: 5 };
+/// let ptr = (&raw const header).cast::<u8>();
+/// assert_eq!(unsafe { ptr.validate_record_at(0, check) }, Ok(header));
+///
+/// let bad = Header { reserved: 1, ..header };
+/// let ptr = (&raw const bad).cast::<u8>();
+/// assert_eq!(unsafe { ptr.validate_record_at(0, check) }, Err("reserved field is not zero"));
+/// ```
+pub trait RecordRead: UnalignedRead + Copy {
+    /// Reads a `T` at the given byte offset and runs `check` on it, returning the record
+    /// if the check passes, or the check's error.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `size_of::<T>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn validate_record_at<T: Pod>(
+        self,
+        byte_offset: usize,
+        check: impl Fn(&T) -> Result<(), &'static str>,
+    ) -> Result<T, &'static str> {
+        let record = self.read_at::<T>(byte_offset);
+        check(&record)?;
+        Ok(record)
+    }
+}
+
+impl<P: UnalignedRead + Copy> RecordRead for P {}
@@ -0,0 +1,83 @@
+use super::*;
+use crate::{UnalignedRead, UnalignedWrite};
+
+#[test]
+fn test_check_magic_and_version() {
+    unsafe {
+        let mut buffer = [0u8; 12];
+        buffer[1..5].copy_from_slice(b"RIFF");
+        let ptr = buffer.as_mut_ptr();
+        ptr.write_u16_at(5, 2);
+        ptr.write_u16_at(7, 7);
+
+        assert!(ptr.check_magic_at(1, b"RIFF"));
+        assert!(!ptr.check_magic_at(0, b"RIFF"));
+        assert_eq!(ptr.read_version_at(5), (2, 7));
+    }
+}
+
+#[test]
+fn test_expect_magic_then() {
+    unsafe {
+        let mut buffer = [0u8; 8];
+        buffer[..4].copy_from_slice(b"\x7fELF");
+        let ptr = buffer.as_ptr();
+
+        let mut called = false;
+        assert_eq!(
+            ptr.expect_magic_then(0, b"\x7fELF", |p| p.read_u8_at(0)),
+            Some(0x7F)
+        );
+        assert_eq!(ptr.expect_magic_then(0, b"MZ", |_| called = true), None);
+        assert!(!called);
+    }
+}
+
+#[test]
+fn test_validate_record_at() {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Record {
+        version: u32,
+        reserved: u32,
+    }
+
+    // SAFETY: both fields are `Pod`, and there is no padding.
+    unsafe impl crate::Pod for Record {}
+
+    let check = |record: &Record| {
+        if !(1..=4).contains(&record.version) {
+            return Err("version out of range");
+        }
+        if record.reserved != 0 {
+            return Err("reserved field is not zero");
+        }
+        Ok(())
+    };
+
+    unsafe {
+        // Records at an unaligned offset.
+        let mut buffer = [0u8; 25];
+        let ptr = buffer.as_mut_ptr();
+        ptr.write_u32_at(1, 3);
+        ptr.write_u32_at(5, 0);
+        ptr.write_u32_at(9, 9);
+        ptr.write_u32_at(13, 0);
+        ptr.write_u32_at(17, 1);
+        ptr.write_u32_at(21, 0xFF);
+
+        let expected = Record {
+            version: 3,
+            reserved: 0,
+        };
+        assert_eq!(ptr.validate_record_at(1, check), Ok(expected));
+        assert_eq!(
+            ptr.validate_record_at(9, check),
+            Err("version out of range")
+        );
+        assert_eq!(
+            ptr.validate_record_at(17, check),
+            Err("reserved field is not zero")
+        );
+    }
+}
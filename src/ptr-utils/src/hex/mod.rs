@@ -0,0 +1,10 @@
+//! Hex formatting of byte regions, for debugging parsers.
+//!
+//! Uses only [`core::fmt`], so it is available in `no_std` builds.
+
+pub mod slice;
+
+#[cfg(test)]
+mod tests;
+
+pub use slice::HexSlice;
@@ -0,0 +1,39 @@
+//! The [`HexSlice`] formatting wrapper.
+
+use core::fmt;
+
+/// Formats a byte slice as lowercase hex, for inspecting raw data.
+///
+/// [`Debug`](fmt::Debug) and [`Display`](fmt::Display) both render the bytes as one
+/// compact string (`deadbeef`). The alternate form (`{:#?}` or `{:#}`) separates the
+/// bytes with spaces (`de ad be ef`).
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::HexSlice;
+///
+/// let data = [0xDEu8, 0xAD, 0xBE, 0xEF];
+/// assert_eq!(format!("{:?}", HexSlice(&data)), "deadbeef");
+/// assert_eq!(format!("{:#?}", HexSlice(&data[1..3])), "ad be");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexSlice<'a>(pub &'a [u8]);
+
+impl fmt::Display for HexSlice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (x, byte) in self.0.iter().enumerate() {
+            if f.alternate() && x > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for HexSlice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,18 @@
+#[cfg(feature = "std")]
+#[test]
+fn test_hex_slice_format() {
+    use super::*;
+    use std::format;
+
+    let data = [0x00u8, 0x01, 0x7F, 0x80, 0xAB, 0xFF];
+
+    assert_eq!(format!("{:?}", HexSlice(&data)), "00017f80abff");
+    assert_eq!(format!("{}", HexSlice(&data)), "00017f80abff");
+    assert_eq!(format!("{:#?}", HexSlice(&data)), "00 01 7f 80 ab ff");
+    assert_eq!(format!("{:#}", HexSlice(&data[4..])), "ab ff");
+
+    // Single and empty slices have no separators.
+    assert_eq!(format!("{:#?}", HexSlice(&data[2..3])), "7f");
+    assert_eq!(format!("{:?}", HexSlice(&[])), "");
+    assert_eq!(format!("{:#?}", HexSlice(&[])), "");
+}
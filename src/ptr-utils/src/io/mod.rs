@@ -0,0 +1,13 @@
+//! Adapters bridging raw memory regions to the [`std::io`] traits.
+//!
+//! These let a region be passed to any API expecting `impl Read` or `impl Write`
+//! (decompressors, deserializers) without first copying it into a `Vec`.
+
+pub mod reader;
+pub mod writer;
+
+#[cfg(test)]
+mod tests;
+
+pub use reader::PtrReader;
+pub use writer::PtrWriter;
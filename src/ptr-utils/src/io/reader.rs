@@ -0,0 +1,109 @@
+//! [`std::io::Read`] over a pointer region.
+
+use core::mem::size_of;
+use core::slice;
+use std::io;
+
+use crate::{RegionRead, UnalignedRead};
+
+/// A reader over `len` bytes at a raw pointer, implementing [`io::Read`].
+///
+/// Each read copies from the current position and advances it; reads past the end
+/// return `Ok(0)`, as for any exhausted reader.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Read;
+/// use ptr_utils::PtrReader;
+///
+/// let data = [1u8, 2, 3, 4, 5];
+/// let mut reader = unsafe { PtrReader::new(data.as_ptr(), data.len()) };
+///
+/// let mut head = [0u8; 2];
+/// reader.read_exact(&mut head).unwrap();
+/// assert_eq!(head, [1, 2]);
+/// assert_eq!(reader.remaining(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PtrReader {
+    ptr: *const u8,
+    len: usize,
+    position: usize,
+}
+
+impl PtrReader {
+    /// Creates a reader over the `len` bytes at `ptr`, positioned at the start.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `len` bytes for as long as the reader is used
+    #[inline(always)]
+    pub const unsafe fn new(ptr: *const u8, len: usize) -> Self {
+        Self {
+            ptr,
+            len,
+            position: 0,
+        }
+    }
+
+    /// Returns the length of the region in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the region contains no bytes.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the current byte position from the start of the region.
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bytes left to read.
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize {
+        self.len - self.position
+    }
+
+    /// Returns the next `len` bytes without advancing, or fewer if less than `len`
+    /// bytes remain.
+    ///
+    /// Allows lookahead before choosing how to parse the next chunk, which
+    /// [`io::Read`] can't express.
+    #[inline]
+    pub fn peek(&self, len: usize) -> &[u8] {
+        let count = len.min(self.remaining());
+        // SAFETY: `position + count <= len`, and the region was promised valid to `new`.
+        unsafe { slice::from_raw_parts(self.ptr.add(self.position), count) }
+    }
+
+    /// Reads a native endian [`u32`] at the current position without advancing, or
+    /// returns [`None`] if less than 4 bytes remain.
+    #[inline]
+    pub fn peek_u32(&self) -> Option<u32> {
+        if self.remaining() < size_of::<u32>() {
+            return None;
+        }
+        // SAFETY: at least 4 bytes remain in the region promised valid to `new`.
+        Some(unsafe { self.ptr.read_u32_at(self.position) })
+    }
+}
+
+impl io::Read for PtrReader {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = buf.len().min(self.remaining());
+        // SAFETY: `position + count <= len`, and the region was promised valid to `new`.
+        unsafe {
+            self.ptr
+                .copy_bytes_fast_at(self.position, buf.as_mut_ptr(), count)
+        };
+        self.position += count;
+        Ok(count)
+    }
+}
@@ -0,0 +1,110 @@
+use std::io::{Read, Write};
+use std::vec::Vec;
+
+use super::*;
+
+#[test]
+fn test_ptr_reader_read_to_end() {
+    let data: [u8; 300] = core::array::from_fn(|i| i as u8);
+    let mut reader = unsafe { PtrReader::new(data.as_ptr(), data.len()) };
+
+    let mut first = [0u8; 7];
+    assert_eq!(reader.read(&mut first).unwrap(), 7);
+    assert_eq!(first, [0, 1, 2, 3, 4, 5, 6]);
+
+    let mut rest = Vec::new();
+    assert_eq!(reader.read_to_end(&mut rest).unwrap(), 293);
+    assert_eq!(rest.as_slice(), &data[7..]);
+    assert_eq!(reader.position(), 300);
+    assert_eq!(reader.remaining(), 0);
+
+    // An exhausted reader keeps returning zero bytes.
+    assert_eq!(reader.read(&mut first).unwrap(), 0);
+}
+
+#[test]
+fn test_ptr_reader_short_read() {
+    let data = [9u8, 8, 7];
+    let mut reader = unsafe { PtrReader::new(data.as_ptr(), data.len()) };
+
+    let mut buf = [0u8; 8];
+    assert_eq!(reader.read(&mut buf).unwrap(), 3);
+    assert_eq!(&buf[..3], &data);
+    assert!(reader.read_exact(&mut buf[..1]).is_err());
+}
+
+#[test]
+fn test_ptr_writer_write_macro() {
+    let mut buffer = [0xAAu8; 32];
+    let mut writer = unsafe { PtrWriter::new(buffer.as_mut_ptr(), 24) };
+
+    let key = "key";
+    write!(writer, "{key}:{:04X}", 0xBEEFu16).unwrap();
+    writer.write_all(&[0, 1]).unwrap();
+    writer.flush().unwrap();
+
+    assert_eq!(writer.position(), 10);
+    assert_eq!(writer.remaining(), 14);
+    assert_eq!(&buffer[..10], b"key:BEEF\0\x01");
+    assert_eq!(buffer[10], 0xAA);
+}
+
+#[test]
+fn test_ptr_writer_capacity_exhausted() {
+    let mut buffer = [0u8; 8];
+    let mut writer = unsafe { PtrWriter::new(buffer.as_mut_ptr(), 4) };
+
+    // A write larger than the remaining capacity is short; further writes write nothing.
+    assert_eq!(writer.write(b"abcdef").unwrap(), 4);
+    assert_eq!(writer.write(b"g").unwrap(), 0);
+    let error = writer.write_all(b"h").unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::WriteZero);
+    assert!(write!(writer, "x").is_err());
+
+    assert_eq!(&buffer, b"abcd\0\0\0\0");
+}
+
+mod fmt_write {
+    // Shadows the `io::Write` import, which would make `write!` ambiguous.
+    use core::fmt::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_ptr_writer_fmt_write() {
+        let mut buffer = [0xAAu8; 8];
+        let mut writer = unsafe { PtrWriter::new(buffer.as_mut_ptr(), 6) };
+
+        write!(writer, "{}", -1234).unwrap();
+        assert_eq!(&buffer[..5], b"-1234");
+        assert_eq!(writer.remaining(), 1);
+
+        // A string that does not fit is not written at all.
+        assert!(write!(writer, "{}", 56).is_err());
+        assert_eq!(writer.position(), 5);
+        writer.write_char('7').unwrap();
+        assert!(writer.write_char('8').is_err());
+
+        assert_eq!(&buffer, b"-12347\xAA\xAA");
+    }
+}
+
+#[test]
+fn test_ptr_reader_peek() {
+    let data = [0x01u8, 0x02, 0x03, 0x04, 0x05, 0x06];
+    let mut reader = unsafe { PtrReader::new(data.as_ptr(), data.len()) };
+
+    assert_eq!(reader.peek(2), &[0x01, 0x02]);
+    assert_eq!(reader.peek_u32(), Some(u32::from_ne_bytes([1, 2, 3, 4])));
+    assert_eq!(reader.position(), 0);
+
+    // Peeking returns the same bytes the next read consumes.
+    let mut buf = [0u8; 3];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [1, 2, 3]);
+
+    // Peeks are bounded by the remaining length.
+    assert_eq!(reader.peek(10), &[0x04, 0x05, 0x06]);
+    assert_eq!(reader.peek_u32(), None);
+    assert_eq!(reader.position(), 3);
+}
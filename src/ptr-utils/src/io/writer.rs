@@ -0,0 +1,102 @@
+//! [`std::io::Write`] over a mutable pointer region.
+
+use core::fmt;
+use std::io;
+
+use crate::RegionWrite;
+
+/// A writer into `capacity` bytes at a raw pointer, implementing [`io::Write`] and
+/// [`fmt::Write`].
+///
+/// Each write copies to the current position and advances it. Once the capacity is
+/// reached, writes are short, and a write with no room left returns `Ok(0)`, which
+/// [`write_all`](io::Write::write_all) and [`write!`] report as
+/// [`ErrorKind::WriteZero`](io::ErrorKind::WriteZero).
+///
+/// Through [`fmt::Write`], a string that does not fit is not written at all, so the
+/// buffer never ends in a partial UTF-8 sequence; [`write!`] then returns [`fmt::Error`].
+/// With both traits implemented, only one may be in scope where [`write!`] is used.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Write;
+/// use ptr_utils::PtrWriter;
+///
+/// let mut buffer = [0u8; 16];
+/// let mut writer = unsafe { PtrWriter::new(buffer.as_mut_ptr(), buffer.len()) };
+///
+/// write!(writer, "id={}", 42).unwrap();
+/// assert_eq!(&buffer[..writer.position()], b"id=42");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct PtrWriter {
+    ptr: *mut u8,
+    capacity: usize,
+    position: usize,
+}
+
+impl PtrWriter {
+    /// Creates a writer into the `capacity` bytes at `ptr`, positioned at the start.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for writing `capacity` bytes for as long as the
+    ///   writer is used
+    /// - The memory location must be mutable
+    #[inline(always)]
+    pub const unsafe fn new(ptr: *mut u8, capacity: usize) -> Self {
+        Self {
+            ptr,
+            capacity,
+            position: 0,
+        }
+    }
+
+    /// Returns the capacity of the region in bytes.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the current byte position from the start of the region, i.e. the number
+    /// of bytes written so far.
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bytes that can still be written.
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize {
+        self.capacity - self.position
+    }
+}
+
+impl io::Write for PtrWriter {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let count = buf.len().min(self.remaining());
+        // SAFETY: `position + count <= capacity`, and the region was promised valid to `new`.
+        unsafe { self.ptr.write_bytes_at(self.position, &buf[..count]) };
+        self.position += count;
+        Ok(count)
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl fmt::Write for PtrWriter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.len() > self.remaining() {
+            return Err(fmt::Error);
+        }
+        // SAFETY: `position + s.len() <= capacity`, and the region was promised valid to `new`.
+        unsafe { self.ptr.write_bytes_at(self.position, s.as_bytes()) };
+        self.position += s.len();
+        Ok(())
+    }
+}
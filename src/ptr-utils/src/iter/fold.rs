@@ -0,0 +1,312 @@
+//! Reductions over consecutive unaligned values.
+
+use core::mem::size_of;
+
+use crate::UnalignedRead;
+
+/// Trait providing folds over consecutive unaligned values, for computing sums, maxima or
+/// checksums over a region in one pass.
+///
+/// Unlike [`IterRead`](crate::IterRead), no iterator is created, and the memory only has
+/// to remain valid for the duration of the call.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{FoldRead, UnalignedWrite};
+///
+/// let mut buffer = [0u8; 13];
+/// let ptr = buffer.as_mut_ptr();
+/// unsafe {
+///     for (x, value) in [7u32, 42, 5].into_iter().enumerate() {
+///         ptr.write_u32_at(1 + x * 4, value);
+///     }
+///
+///     assert_eq!(ptr.fold_u32_at(1, 3, 0, |sum, v| sum + v), 54);
+///     assert_eq!(ptr.fold_u32_at(1, 3, 0, u32::max), 42);
+/// }
+/// ```
+pub trait FoldRead: UnalignedRead + Copy {
+    /// Reads `count` consecutive `T` values starting at the given byte offset, folding
+    /// each into the accumulator with `f`, starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<T>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - Every value read must be a valid `T`
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn fold_at<T: Copy, A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        mut f: impl FnMut(A, T) -> A,
+    ) -> A {
+        let mut acc = init;
+        for x in 0..count {
+            acc = f(acc, self.read_at::<T>(byte_offset + x * size_of::<T>()));
+        }
+        acc
+    }
+
+    // Unsigned integer types
+
+    /// Folds `count` consecutive [`u8`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u8>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_u8_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, u8) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`u16`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u16>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_u16_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, u16) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`u32`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_u32_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, u32) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`u64`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_u64_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, u64) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`u128`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u128>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_u128_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, u128) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`usize`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<usize>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_usize_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, usize) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    // Signed integer types
+
+    /// Folds `count` consecutive [`i8`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i8>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_i8_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, i8) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`i16`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i16>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_i16_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, i16) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`i32`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_i32_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, i32) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`i64`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_i64_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, i64) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`i128`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i128>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_i128_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, i128) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`isize`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<isize>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_isize_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, isize) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    // Floating point types
+
+    /// Folds `count` consecutive [`f32`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<f32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_f32_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, f32) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+
+    /// Folds `count` consecutive [`f64`] values starting at the given byte offset with `f`,
+    /// starting from `init`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<f64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn fold_f64_at<A>(
+        self,
+        byte_offset: usize,
+        count: usize,
+        init: A,
+        f: impl FnMut(A, f64) -> A,
+    ) -> A {
+        self.fold_at(byte_offset, count, init, f)
+    }
+}
+
+impl<P: UnalignedRead + Copy> FoldRead for P {}
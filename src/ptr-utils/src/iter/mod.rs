@@ -0,0 +1,17 @@
+//! Lazy iteration over, and serialization from iterators into, consecutive or strided
+//! values in memory.
+
+pub mod fold;
+pub mod prefixed;
+pub mod read;
+pub mod stride;
+pub mod write;
+
+#[cfg(test)]
+mod tests;
+
+pub use fold::FoldRead;
+pub use prefixed::PrefixedListRead;
+pub use read::{IterRead, ReadIter};
+pub use stride::{StrideIter, StrideRead};
+pub use write::IterWrite;
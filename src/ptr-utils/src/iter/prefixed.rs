@@ -0,0 +1,60 @@
+//! Callback iteration over count-prefixed lists.
+
+use crate::UnalignedRead;
+
+/// Trait providing callback iteration over lists stored as a count followed by that many
+/// items, without allocating.
+///
+/// Counts and items are read in native endianness, like the rest of [`UnalignedRead`].
+/// For a bounds-checked variant, see
+/// [`Span::read_prefixed_list_u32_at`](crate::Span::read_prefixed_list_u32_at).
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{PrefixedListRead, UnalignedWrite};
+///
+/// let mut buffer = [0u8; 16];
+/// let ptr = buffer.as_mut_ptr();
+///
+/// unsafe {
+///     ptr.write_u32_at(0, 3);
+///     for x in 0..3 {
+///         ptr.write_u32_at(4 + x * 4, 10 * (x as u32 + 1));
+///     }
+///
+///     let mut total = 0;
+///     let consumed = ptr.read_prefixed_list_u32_at(0, 4, |item| total += item);
+///     assert_eq!((total, consumed), (60, 16));
+/// }
+/// ```
+pub trait PrefixedListRead: UnalignedRead + Copy {
+    /// Reads a [`u32`] count at `count_offset`, then calls `f` with the [`u32`] at
+    /// `count_offset + 4 + i * item_stride` for each `i` in `0..count`.
+    ///
+    /// Returns the number of bytes the list occupies, `4 + count * item_stride`. Items
+    /// wider than 4 bytes are visited through their first 4 bytes.
+    ///
+    /// # Safety
+    /// - The pointer plus count offset must be valid for reading 4 bytes
+    /// - For every `i` in `0..count`, the pointer plus `count_offset + 4 + i * item_stride`
+    ///   must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the reads
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_prefixed_list_u32_at<F: FnMut(u32)>(
+        self,
+        count_offset: usize,
+        item_stride: usize,
+        mut f: F,
+    ) -> usize {
+        let count = self.read_u32_at(count_offset) as usize;
+        let items_offset = count_offset + 4;
+        for x in 0..count {
+            f(self.read_u32_at(items_offset + x * item_stride));
+        }
+        4 + count * item_stride
+    }
+}
+
+impl<P: UnalignedRead + Copy> PrefixedListRead for P {}
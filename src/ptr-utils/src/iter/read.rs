@@ -0,0 +1,254 @@
+//! Iterators reading consecutive unaligned values.
+
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+use crate::unaligned::raw::read_raw;
+use crate::{AsBytePtr, UnalignedRead};
+
+/// An iterator yielding consecutive unaligned `T` values read from a raw pointer.
+///
+/// Created by the methods of [`IterRead`]. The validity of the memory is promised once,
+/// when the iterator is constructed (which is `unsafe`); [`Iterator::next`] itself is safe.
+/// The iterator borrows the memory for `'a`, so safe constructors such as
+/// [`Span::iter_copied`](crate::Span::iter_copied) cannot outlive the buffer.
+#[derive(Debug, Clone)]
+pub struct ReadIter<'a, T> {
+    ptr: *const u8,
+    remaining: usize,
+    _marker: PhantomData<(&'a [u8], T)>,
+}
+
+impl<'a, T: Copy> ReadIter<'a, T> {
+    /// Creates an iterator over `count` consecutive `T` values starting at `ptr`.
+    ///
+    /// # Safety
+    /// - `ptr` must be valid for reading `count * size_of::<T>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`
+    /// - Every value read must be a valid `T`
+    #[inline(always)]
+    pub unsafe fn new(ptr: *const u8, count: usize) -> Self {
+        Self {
+            ptr,
+            remaining: count,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Copy> Iterator for ReadIter<'_, T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: the constructor's contract covers `remaining` values from `ptr`.
+        let value = unsafe { read_raw::<T>(self.ptr, 0) };
+        self.ptr = self.ptr.wrapping_add(size_of::<T>());
+        self.remaining -= 1;
+        Some(value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Copy> DoubleEndedIterator for ReadIter<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        // SAFETY: the constructor's contract covers `remaining` values from `ptr`.
+        Some(unsafe { read_raw::<T>(self.ptr, self.remaining * size_of::<T>()) })
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for ReadIter<'_, T> {}
+
+impl<T: Copy> FusedIterator for ReadIter<'_, T> {}
+
+/// Trait providing lazy iterators over consecutive unaligned values.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{IterRead, UnalignedWrite};
+///
+/// let mut buffer = [0u8; 13];
+/// let ptr = buffer.as_mut_ptr();
+/// unsafe {
+///     for x in 0..3 {
+///         ptr.write_u32_at(1 + x * 4, x as u32 * 10);
+///     }
+///
+///     let large: u32 = ptr.iter_u32_at(1, 3).filter(|&v| v >= 10).sum();
+///     assert_eq!(large, 30);
+/// }
+/// ```
+pub trait IterRead: UnalignedRead + AsBytePtr + Copy {
+    /// Returns an iterator over `count` consecutive `T` values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<T>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    /// - Every value read must be a valid `T`
+    #[inline(always)]
+    unsafe fn iter_at<'a, T: Copy>(self, byte_offset: usize, count: usize) -> ReadIter<'a, T> {
+        ReadIter::new(self.as_byte_ptr().add(byte_offset), count)
+    }
+
+    // Unsigned integer types
+
+    /// Returns an iterator over `count` consecutive [`u8`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u8>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_u8_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, u8> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`u16`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u16>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_u16_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, u16> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`u32`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u32>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_u32_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, u32> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`u64`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u64>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_u64_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, u64> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`u128`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<u128>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_u128_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, u128> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`usize`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<usize>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_usize_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, usize> {
+        self.iter_at(byte_offset, count)
+    }
+
+    // Signed integer types
+
+    /// Returns an iterator over `count` consecutive [`i8`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i8>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_i8_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, i8> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`i16`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i16>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_i16_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, i16> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`i32`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i32>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_i32_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, i32> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`i64`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i64>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_i64_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, i64> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`i128`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<i128>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_i128_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, i128> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`isize`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<isize>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_isize_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, isize> {
+        self.iter_at(byte_offset, count)
+    }
+
+    // Floating point types
+
+    /// Returns an iterator over `count` consecutive [`f32`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<f32>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_f32_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, f32> {
+        self.iter_at(byte_offset, count)
+    }
+
+    /// Returns an iterator over `count` consecutive [`f64`] values starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `count * size_of::<f64>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_f64_at<'a>(self, byte_offset: usize, count: usize) -> ReadIter<'a, f64> {
+        self.iter_at(byte_offset, count)
+    }
+}
+
+impl<P: UnalignedRead + AsBytePtr + Copy> IterRead for P {}
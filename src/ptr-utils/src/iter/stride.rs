@@ -0,0 +1,384 @@
+//! Strided iteration over one field of an array of structs.
+
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::unaligned::raw::read_raw;
+use crate::{AsBytePtr, UnalignedRead};
+
+/// An iterator yielding unaligned `T` values spaced `stride` bytes apart.
+///
+/// Created by the methods of [`StrideRead`]. Like [`ReadIter`](crate::ReadIter), the
+/// iterator borrows the memory for `'a`.
+#[derive(Debug, Clone)]
+pub struct StrideIter<'a, T> {
+    ptr: *const u8,
+    stride: usize,
+    remaining: usize,
+    _marker: PhantomData<(&'a [u8], T)>,
+}
+
+impl<'a, T: Copy> StrideIter<'a, T> {
+    /// Creates an iterator over `count` values of type `T`, the first at `ptr` and each
+    /// subsequent one `stride` bytes after the previous.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, `ptr + i * stride` must be valid for reading
+    ///   `size_of::<T>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`
+    /// - Every value read must be a valid `T`
+    #[inline(always)]
+    pub unsafe fn new(ptr: *const u8, stride: usize, count: usize) -> Self {
+        Self {
+            ptr,
+            stride,
+            remaining: count,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Copy> Iterator for StrideIter<'_, T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: the constructor's contract covers `remaining` values from `ptr`.
+        let value = unsafe { read_raw::<T>(self.ptr, 0) };
+        self.ptr = self.ptr.wrapping_add(self.stride);
+        self.remaining -= 1;
+        Some(value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Copy> DoubleEndedIterator for StrideIter<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        // SAFETY: the constructor's contract covers `remaining` values from `ptr`.
+        Some(unsafe { read_raw::<T>(self.ptr, self.remaining * self.stride) })
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for StrideIter<'_, T> {}
+
+impl<T: Copy> FusedIterator for StrideIter<'_, T> {}
+
+/// Trait providing iterators that gather one field across an array of structs.
+///
+/// Element `i` is read from `base_offset + i * stride + field_offset`, i.e. one column
+/// of a row-major table.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{StrideRead, UnalignedWrite};
+///
+/// // struct Entry { id: u16, value: u32 } (packed, 6 bytes) x 3
+/// let mut table = [0u8; 18];
+/// let ptr = table.as_mut_ptr();
+/// unsafe {
+///     for x in 0..3 {
+///         ptr.write_u16_at(x * 6, x as u16);
+///         ptr.write_u32_at(x * 6 + 2, x as u32 * 100);
+///     }
+///
+///     let total: u32 = ptr.iter_field_u32(0, 6, 3, 2).sum();
+///     assert_eq!(total, 300);
+/// }
+/// ```
+pub trait StrideRead: UnalignedRead + AsBytePtr + Copy {
+    /// Returns an iterator over the `T` field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<T>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    /// - Every value read must be a valid `T`
+    #[inline(always)]
+    unsafe fn iter_field<'a, T: Copy>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, T> {
+        StrideIter::new(
+            self.as_byte_ptr().add(base_offset + field_offset),
+            stride,
+            count,
+        )
+    }
+
+    // Unsigned integer types
+
+    /// Returns an iterator over the [`u8`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<u8>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_u8<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, u8> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`u16`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<u16>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_u16<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, u16> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`u32`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<u32>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_u32<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, u32> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`u64`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<u64>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_u64<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, u64> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`u128`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<u128>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_u128<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, u128> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`usize`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<usize>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_usize<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, usize> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    // Signed integer types
+
+    /// Returns an iterator over the [`i8`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<i8>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_i8<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, i8> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`i16`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<i16>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_i16<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, i16> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`i32`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<i32>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_i32<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, i32> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`i64`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<i64>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_i64<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, i64> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`i128`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<i128>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_i128<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, i128> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`isize`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<isize>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_isize<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, isize> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    // Floating point types
+
+    /// Returns an iterator over the [`f32`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<f32>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_f32<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, f32> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+
+    /// Returns an iterator over the [`f64`] field at `field_offset` within each of `count`
+    /// records of `stride` bytes, starting at `base_offset`.
+    ///
+    /// # Safety
+    /// - For every `i` in `0..count`, the pointer plus `base_offset + i * stride + field_offset`
+    ///   must be valid for reading `size_of::<f64>()` bytes
+    /// - The memory must remain valid and unmodified for `'a`, the lifetime of the iterator
+    #[inline(always)]
+    unsafe fn iter_field_f64<'a>(
+        self,
+        base_offset: usize,
+        stride: usize,
+        count: usize,
+        field_offset: usize,
+    ) -> StrideIter<'a, f64> {
+        self.iter_field(base_offset, stride, count, field_offset)
+    }
+}
+
+impl<P: UnalignedRead + AsBytePtr + Copy> StrideRead for P {}
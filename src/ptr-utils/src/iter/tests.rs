@@ -0,0 +1,139 @@
+use super::*;
+use crate::UnalignedWrite;
+
+#[test]
+fn test_iter_u32_at() {
+    unsafe {
+        let mut buffer = [0u8; 33];
+        let ptr = buffer.as_mut_ptr();
+        for x in 0..8 {
+            ptr.write_u32_at(1 + x * 4, (x as u32 + 1) * 0x0101_0101);
+        }
+
+        let iter = ptr.iter_u32_at(1, 8);
+        assert_eq!(iter.len(), 8);
+        let values: [u32; 8] = {
+            let mut out = [0u32; 8];
+            for (slot, v) in out.iter_mut().zip(iter) {
+                *slot = v;
+            }
+            out
+        };
+        assert_eq!(values[0], 0x0101_0101);
+        assert_eq!(values[7], 0x0808_0808);
+
+        let evens = ptr.iter_u32_at(1, 8).filter(|v| v & 1 == 0).count();
+        assert_eq!(evens, 4);
+        assert_eq!(ptr.iter_u32_at(1, 8).next_back(), Some(0x0808_0808));
+        assert_eq!(ptr.iter_u32_at(1, 0).next(), None);
+    }
+}
+
+#[test]
+fn test_iter_other_types() {
+    unsafe {
+        let mut buffer = [0u8; 32];
+        let ptr = buffer.as_mut_ptr();
+        ptr.write_f64_at(3, 1.5);
+        ptr.write_f64_at(11, -2.0);
+        ptr.write_i16_at(19, -7);
+        ptr.write_i16_at(21, 9);
+
+        assert_eq!(ptr.iter_f64_at(3, 2).sum::<f64>(), -0.5);
+        assert_eq!(ptr.iter_i16_at(19, 2).max(), Some(9));
+        assert_eq!(ptr.iter_at::<i16>(19, 2).min(), Some(-7));
+    }
+}
+
+#[test]
+fn test_write_iter_round_trip() {
+    unsafe {
+        let mut buffer = [0u8; 40];
+        let ptr = buffer.as_mut_ptr();
+
+        let written = ptr.write_iter_u32_at(3, (0..8).map(|x| x * x + 1));
+        assert_eq!(written, 32);
+        assert!(ptr.iter_u32_at(3, 8).eq((0..8).map(|x| x * x + 1)));
+
+        assert_eq!(ptr.write_iter_f32_at(0, [0.5f32, -1.0]), 8);
+        assert!(ptr.iter_f32_at(0, 2).eq([0.5, -1.0]));
+        assert_eq!(ptr.write_iter_u64_at(0, core::iter::empty()), 0);
+    }
+}
+
+#[test]
+fn test_iter_field_aos() {
+    // struct Record { tag: u8, value: u32, flags: u16 } (packed, 7 bytes) x 3, after 1 byte of header
+    const STRIDE: usize = 7;
+    let mut buffer = [0u8; 1 + STRIDE * 3];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        for x in 0..3 {
+            let record = 1 + x * STRIDE;
+            ptr.write_u8_at(record, 0xEE);
+            ptr.write_u32_at(record + 1, 1000 + x as u32);
+            ptr.write_u16_at(record + 5, x as u16);
+        }
+
+        let mut values = ptr.iter_field_u32(1, STRIDE, 3, 1);
+        assert_eq!(values.len(), 3);
+        assert_eq!(values.next(), Some(1000));
+        assert_eq!(values.next_back(), Some(1002));
+        assert_eq!(values.next(), Some(1001));
+        assert_eq!(values.next(), None);
+
+        assert_eq!(ptr.iter_field_u16(1, STRIDE, 3, 5).max(), Some(2));
+        assert!(ptr.iter_field_u8(1, STRIDE, 3, 0).all(|tag| tag == 0xEE));
+    }
+}
+
+#[test]
+fn test_read_prefixed_list_u32_at() {
+    unsafe {
+        // 3 items of 6 bytes each (a u32 plus 2 bytes of padding), after a byte of header.
+        let mut buffer = [0xEEu8; 23];
+        let ptr = buffer.as_mut_ptr();
+        ptr.write_u32_at(1, 3);
+        for x in 0..3 {
+            ptr.write_u32_at(5 + x * 6, 0x1000 + x as u32);
+        }
+
+        let mut items = [0u32; 3];
+        let mut x = 0;
+        let consumed = ptr.read_prefixed_list_u32_at(1, 6, |item| {
+            items[x] = item;
+            x += 1;
+        });
+        assert_eq!(consumed, 22);
+        assert_eq!(items, [0x1000, 0x1001, 0x1002]);
+
+        // An empty list is just its count.
+        ptr.write_u32_at(1, 0);
+        assert_eq!(ptr.read_prefixed_list_u32_at(1, 6, |_| unreachable!()), 4);
+    }
+}
+
+#[test]
+fn test_fold_at() {
+    unsafe {
+        let mut buffer = [0u8; 33];
+        let ptr = buffer.as_mut_ptr();
+        for (x, value) in [3u32, 9, 1, 7].into_iter().enumerate() {
+            ptr.write_u32_at(1 + x * 4, value);
+        }
+        for (x, value) in [-1.5f64, 2.0].into_iter().enumerate() {
+            ptr.write_f64_at(17 + x * 8, value);
+        }
+
+        assert_eq!(ptr.fold_u32_at(1, 4, 0, |sum, v| sum + v), 20);
+        assert_eq!(ptr.fold_u32_at(1, 4, u32::MAX, u32::min), 1);
+        assert_eq!(ptr.fold_u32_at(1, 0, 5, |_, _| unreachable!()), 5);
+        assert_eq!(ptr.fold_f64_at(17, 2, 0.0, |sum, v| sum + v), 0.5);
+
+        // A byte checksum, accumulating into a wider type.
+        let sum = ptr.fold_u8_at(1, 16, 0u64, |sum, v| sum + v as u64);
+        assert_eq!(sum, 20);
+        assert_eq!(ptr.fold_at::<u16, _>(1, 2, 0usize, |n, _| n + 1), 2);
+    }
+}
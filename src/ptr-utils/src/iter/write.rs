@@ -0,0 +1,293 @@
+//! Serialization of iterators into consecutive unaligned values.
+
+use core::mem::size_of;
+
+use crate::UnalignedWrite;
+
+/// Trait providing writes of every value yielded by an iterator, consecutively.
+///
+/// Each method returns the number of bytes written, so a cursor or offset can be
+/// advanced past the written values.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{IterRead, IterWrite};
+///
+/// let mut buffer = [0u8; 16];
+/// let ptr = buffer.as_mut_ptr();
+/// unsafe {
+///     let written = ptr.write_iter_u16_at(0, (1..=4).map(|x| x * 100));
+///     assert_eq!(written, 8);
+///     assert_eq!(ptr.iter_u16_at(0, 4).sum::<u16>(), 1000);
+/// }
+/// ```
+pub trait IterWrite: UnalignedWrite + Copy {
+    /// Writes every `T` yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<T>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_at<T: Copy>(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = T>,
+    ) -> usize {
+        let mut offset = byte_offset;
+        for value in values {
+            self.write_at(offset, value);
+            offset += size_of::<T>();
+        }
+        offset - byte_offset
+    }
+
+    // Unsigned integer types
+
+    /// Writes every [`u8`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<u8>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_u8_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = u8>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`u16`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<u16>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_u16_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = u16>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`u32`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<u32>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_u32_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = u32>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`u64`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<u64>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_u64_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = u64>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`u128`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<u128>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_u128_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = u128>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`usize`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<usize>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_usize_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = usize>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    // Signed integer types
+
+    /// Writes every [`i8`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<i8>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_i8_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = i8>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`i16`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<i16>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_i16_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = i16>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`i32`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<i32>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_i32_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = i32>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`i64`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<i64>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_i64_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = i64>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`i128`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<i128>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_i128_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = i128>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`isize`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<isize>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_isize_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = isize>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    // Floating point types
+
+    /// Writes every [`f32`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<f32>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_f32_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = f32>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+
+    /// Writes every [`f64`] yielded by `values` consecutively, starting at the given byte offset.
+    /// Returns the number of bytes written.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `n * size_of::<f64>()` bytes,
+    ///   where `n` is the number of values yielded
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline]
+    unsafe fn write_iter_f64_at(
+        self,
+        byte_offset: usize,
+        values: impl IntoIterator<Item = f64>,
+    ) -> usize {
+        self.write_iter_at(byte_offset, values)
+    }
+}
+
+impl<P: UnalignedWrite + Copy> IterWrite for P {}
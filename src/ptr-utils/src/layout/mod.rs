@@ -0,0 +1,109 @@
+//! Helpers tying struct layouts to the byte offsets used by the reads.
+//!
+//! Code reading `#[repr(C)]` structures with hardcoded offsets silently breaks when
+//! the struct layout drifts; the macros here catch that at compile time.
+
+#[cfg(test)]
+mod tests;
+
+/// Asserts at compile time that a struct field lies at the expected byte offset.
+///
+/// Uses [`core::mem::offset_of!`], so the assertion fails the build (rather than a test)
+/// if the layout of `Struct` changes. It can be used at item level or inside functions.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{assert_field_offset, UnalignedRead};
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     version: u16,
+///     flags: u16,
+/// }
+///
+/// assert_field_offset!(Header, magic, 0);
+/// assert_field_offset!(Header, version, 4);
+/// assert_field_offset!(Header, flags, 6);
+///
+/// let bytes = [0x50u8, 0x54, 0x52, 0x55, 0x02, 0x00, 0x01, 0x00];
+/// let ptr = bytes.as_ptr();
+///
+/// unsafe {
+///     // The hardcoded offsets used here are locked in by the assertions above.
+///     let header = ptr.read_at::<Header>(0);
+///     assert_eq!(ptr.read_u16_at(4), header.version);
+///     assert_eq!(ptr.read_u16_at(6), header.flags);
+/// }
+/// ```
+///
+/// A mismatched offset fails to compile:
+///
+/// ```compile_fail
+/// use ptr_utils::assert_field_offset;
+///
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     version: u16,
+/// }
+///
+/// assert_field_offset!(Header, version, 2);
+/// ```
+#[macro_export]
+macro_rules! assert_field_offset {
+    ($struct:ty, $field:ident, $expected:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            ::core::mem::offset_of!($struct, $field) == $expected,
+            ::core::concat!(
+                "offset of `",
+                ::core::stringify!($struct),
+                "::",
+                ::core::stringify!($field),
+                "` does not match expected offset `",
+                ::core::stringify!($expected),
+                "`"
+            )
+        );
+    };
+}
+
+/// Reads a field of a struct from a pointer to the start of that struct, at the offset
+/// given by [`core::mem::offset_of!`].
+///
+/// Expands to an `unsafe` [`UnalignedRead::read_at`](crate::UnalignedRead::read_at) call,
+/// so it must be used inside an `unsafe` block, with the same safety requirements. As the
+/// offset comes from the struct definition, reads can't drift out of sync with the layout;
+/// the pointer need not be aligned for the struct.
+///
+/// # Example
+///
+/// ```
+/// use core::mem::{offset_of, size_of};
+/// use ptr_utils::{read_field, UnalignedWrite};
+///
+/// #[repr(C)]
+/// struct Entry {
+///     id: u32,
+///     // Adding a field here shifts `size`, and every `read_field!` of it, automatically.
+///     flags: u16,
+///     size: u64,
+/// }
+///
+/// let mut bytes = [0u8; size_of::<Entry>() + 1];
+/// let ptr = bytes.as_mut_ptr();
+///
+/// unsafe {
+///     // An unaligned `Entry` at offset 1.
+///     ptr.add(1).write_u64_at(offset_of!(Entry, size), 4096);
+///     assert_eq!(read_field!(ptr.add(1), Entry, size, u64), 4096);
+/// }
+/// ```
+#[macro_export]
+macro_rules! read_field {
+    ($ptr:expr, $struct:ty, $field:ident, $field_ty:ty $(,)?) => {
+        $crate::UnalignedRead::read_at::<$field_ty>($ptr, ::core::mem::offset_of!($struct, $field))
+    };
+}
@@ -0,0 +1,71 @@
+use crate::{assert_field_offset, read_field};
+use crate::{UnalignedRead, UnalignedWrite};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+struct Record {
+    id: u32,
+    kind: u8,
+    value: u64,
+}
+
+assert_field_offset!(Record, id, 0);
+assert_field_offset!(Record, kind, 4);
+assert_field_offset!(Record, value, 8);
+
+#[test]
+fn test_assert_field_offset_matches_reads() {
+    assert_field_offset!(Record, value, 8);
+
+    unsafe {
+        let mut buffer = [0u8; 32];
+        let ptr = buffer.as_mut_ptr();
+        let record = Record {
+            id: 7,
+            kind: 2,
+            value: 0x1122334455667788,
+        };
+
+        ptr.write_at(3, record);
+        assert_eq!(ptr.read_u32_at(3), record.id);
+        assert_eq!(ptr.read_u8_at(3 + 4), record.kind);
+        assert_eq!(ptr.read_u64_at(3 + 8), record.value);
+        assert_eq!(ptr.read_at::<Record>(3), record);
+    }
+}
+
+#[test]
+fn test_read_field_follows_layout() {
+    // `Record` with a field inserted before `value`, which moves it from 8 to 16.
+    #[repr(C)]
+    struct Extended {
+        id: u32,
+        kind: u8,
+        extra: u64,
+        value: u64,
+    }
+    assert_field_offset!(Extended, value, 16);
+
+    unsafe {
+        let mut buffer = [0u8; 40];
+        let ptr = buffer.as_mut_ptr();
+        let record = Record {
+            id: 7,
+            kind: 2,
+            value: 0x1122334455667788,
+        };
+
+        ptr.write_at(1, record);
+        let base = ptr.add(1);
+        assert_eq!(read_field!(base, Record, id, u32), 7);
+        assert_eq!(read_field!(base, Record, kind, u8), 2);
+        assert_eq!(read_field!(base, Record, value, u64), record.value);
+
+        // The same bytes, now laid out as `Extended`: the unchanged read follows `value`
+        // to its new offset, while the old offset now holds `extra`.
+        base.write_u64_at(16, 99);
+        assert_eq!(read_field!(base, Extended, value, u64), 99);
+        assert_eq!(read_field!(base, Extended, extra, u64), record.value);
+        assert_eq!(read_field!(base, Extended, id, u32), 7);
+    }
+}
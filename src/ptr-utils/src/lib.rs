@@ -1,8 +1,106 @@
 #![doc = include_str!(concat!("../", env!("CARGO_PKG_README")))]
 #![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+/// [`debug_assert!`] for the crate's own checks, compiled out entirely under the
+/// `no-panic` feature.
+macro_rules! debug_check {
+    ($($arg:tt)*) => {
+        if cfg!(all(debug_assertions, not(feature = "no-panic"))) {
+            assert!($($arg)*);
+        }
+    };
+}
+
+/// [`assert!`] for the crate's own checks that stay in release builds, compiled out
+/// entirely under the `no-panic` feature.
+macro_rules! release_check {
+    ($($arg:tt)*) => {
+        if cfg!(not(feature = "no-panic")) {
+            assert!($($arg)*);
+        }
+    };
+}
+
+pub mod aligned;
+pub mod angle;
+pub mod bits;
+pub mod bounded;
+pub mod bytes;
+pub mod collect;
+pub mod cursor;
+pub mod dynamic;
+pub mod encoding;
+pub mod endian;
+pub mod field_bytes;
+pub mod float;
+pub mod gather;
+pub mod header;
+pub mod hex;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod iter;
+pub mod layout;
+pub mod load;
+pub mod map;
+pub mod matrix;
+pub mod norm;
+pub mod numeric;
+pub mod page;
+pub mod patch;
+pub mod pixel;
+pub mod pod;
+pub mod region;
+pub mod rva;
+#[cfg(feature = "simd")]
+pub mod simd;
+pub mod span;
+pub mod tagged;
+pub mod time;
 pub mod unaligned;
+pub mod varint;
 
-pub use unaligned::{UnalignedRead, UnalignedWrite};
+pub use aligned::AlignedRead;
+pub use angle::{AngleRead, AngleWrite};
+#[cfg(feature = "bitflags")]
+pub use bits::FlagsRead;
+pub use bits::{BitFieldRead, BitOrder, BitReader, BitWriter, MaskedRead, ValidBitRead};
+pub use bounded::{Bounded, WriteError};
+#[cfg(feature = "smallvec")]
+pub use collect::SmallVecRead;
+#[cfg(feature = "alloc")]
+pub use collect::VecRead;
+pub use cursor::{AdvanceWrite, AdvancingRead, Checkpoint, PtrCursor, WriteCursor};
+pub use dynamic::{DynRead, DynValue, ValueKind};
+pub use encoding::{BcdRead, ComplexRead, ComplexWrite, RleRead, Utf16Read};
+pub use endian::{Endian, EndianRead, EndianWrite, OverflowPolicy, SwappedRead, U24Read, U24Write};
+pub use field_bytes::{FieldBytesRead, FieldBytesWrite};
+pub use float::FiniteRead;
+pub use gather::GatherRead;
+pub use header::{MagicRead, RecordRead};
+pub use hex::HexSlice;
+#[cfg(feature = "std")]
+pub use io::{PtrReader, PtrWriter};
+pub use iter::{FoldRead, IterRead, IterWrite, PrefixedListRead, ReadIter, StrideIter, StrideRead};
+pub use map::MapRead;
+pub use matrix::MatrixRead;
+pub use norm::{NormRead, NormWrite, ScaledRead};
+pub use numeric::{Numeric, NumericRead, NumericWrite};
+pub use page::PageRead;
+pub use patch::{CounterWrite, TempWrite};
+pub use pixel::{PixelRead, PixelWrite};
+pub use pod::Pod;
+#[cfg(feature = "bytemuck")]
+pub use pod::{BytemuckRead, BytemuckWrite};
+pub use region::{RegionRead, RegionWrite};
+pub use rva::{CompressedPtrRead, OffsetTableRead, RvaRead};
+#[cfg(all(feature = "simd", target_arch = "aarch64"))]
+pub use simd::{NeonRead, NeonWrite};
+pub use span::{ReadError, Span};
+pub use tagged::TaggedRead;
+pub use time::{DosTimeRead, FileTimeRead, UnixTimeRead};
+pub use unaligned::{AsBytePtr, AsBytePtrMut, UnalignedRead, UnalignedWrite};
+pub use varint::{DeltaReader, VarintRead};
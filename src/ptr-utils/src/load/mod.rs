@@ -0,0 +1,90 @@
+//! Compile-time description of which unaligned reads are a single load on the target.
+//!
+//! [`UnalignedRead`](crate::UnalignedRead) lowers to `read_unaligned`, which the compiler
+//! turns into one load instruction where the target supports unaligned access of that
+//! width, and into a sequence of narrower loads elsewhere. Callers choosing a data
+//! layout can query this module to keep hot fields at widths that stay cheap.
+//!
+//! The tables reflect the baseline instruction set of each architecture, ignoring
+//! optional target features; confirm the generated code with `cargo asm` or the
+//! `unaligned` benchmark when it matters. With the `byte-assembly` feature, every
+//! access is made of single-byte loads, so only 1 qualifies on any target.
+//!
+//! # Example
+//!
+//! ```
+//! use ptr_utils::load;
+//!
+//! if load::is_single_load::<u64>() {
+//!     // Store the field as one `u64`.
+//! } else {
+//!     // Split it into narrower fields.
+//! }
+//! ```
+
+use core::mem::size_of;
+
+#[cfg(test)]
+mod tests;
+
+/// The byte widths that can be read unaligned with a single load on the current target,
+/// in ascending order.
+///
+/// A [`u128`] is read as two 8-byte loads (two `mov`s on x86_64, two `ldur`s on AArch64),
+/// so 16 is not listed.
+#[cfg(all(
+    not(feature = "byte-assembly"),
+    any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "powerpc64",
+        target_arch = "wasm32",
+        target_arch = "wasm64"
+    )
+))]
+pub const SUPPORTED_SINGLE_LOAD_WIDTHS: &[usize] = &[1, 2, 4, 8];
+
+/// The byte widths that can be read unaligned with a single load on the current target,
+/// in ascending order.
+#[cfg(all(
+    not(feature = "byte-assembly"),
+    any(target_arch = "x86", all(target_arch = "arm", target_feature = "v7"))
+))]
+pub const SUPPORTED_SINGLE_LOAD_WIDTHS: &[usize] = &[1, 2, 4];
+
+/// The byte widths that can be read unaligned with a single load on the current target,
+/// in ascending order.
+///
+/// Either the `byte-assembly` feature is enabled, or this target is not known to support
+/// unaligned loads, so only single bytes qualify.
+#[cfg(any(
+    feature = "byte-assembly",
+    not(any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "powerpc64",
+        target_arch = "wasm32",
+        target_arch = "wasm64",
+        target_arch = "x86",
+        all(target_arch = "arm", target_feature = "v7")
+    ))
+))]
+pub const SUPPORTED_SINGLE_LOAD_WIDTHS: &[usize] = &[1];
+
+/// Returns `true` if an unaligned read of `T` compiles to a single load on the current
+/// target, based on [`SUPPORTED_SINGLE_LOAD_WIDTHS`].
+///
+/// Only the size of `T` is considered; a struct whose size is in the table is read with
+/// one load of that width.
+#[inline(always)]
+pub const fn is_single_load<T>() -> bool {
+    let size = size_of::<T>();
+    let mut i = 0;
+    while i < SUPPORTED_SINGLE_LOAD_WIDTHS.len() {
+        if SUPPORTED_SINGLE_LOAD_WIDTHS[i] == size {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn test_widths_are_sorted_powers_of_two() {
+    assert_eq!(SUPPORTED_SINGLE_LOAD_WIDTHS.first(), Some(&1));
+    for pair in SUPPORTED_SINGLE_LOAD_WIDTHS.windows(2) {
+        assert!(pair[0] < pair[1]);
+    }
+    for &width in SUPPORTED_SINGLE_LOAD_WIDTHS {
+        assert!(width.is_power_of_two());
+    }
+}
+
+#[test]
+fn test_is_single_load() {
+    // Usable in const contexts.
+    const { assert!(is_single_load::<u8>()) };
+
+    assert!(is_single_load::<[u8; 1]>());
+    assert!(!is_single_load::<[u8; 3]>());
+    assert!(!is_single_load::<()>());
+    assert_eq!(
+        is_single_load::<u32>(),
+        SUPPORTED_SINGLE_LOAD_WIDTHS.contains(&4)
+    );
+
+    #[cfg(all(
+        not(feature = "byte-assembly"),
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    {
+        assert!(is_single_load::<u64>());
+        assert!(!is_single_load::<u128>());
+    }
+    #[cfg(feature = "byte-assembly")]
+    assert_eq!(SUPPORTED_SINGLE_LOAD_WIDTHS, &[1]);
+}
@@ -0,0 +1,8 @@
+//! Reads combined with a transform applied at the read site.
+
+pub mod read;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::MapRead;
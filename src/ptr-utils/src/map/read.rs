@@ -0,0 +1,32 @@
+//! The [`MapRead`] combinator.
+
+use crate::UnalignedRead;
+
+/// Trait providing a read that applies a function to the value read.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::MapRead;
+///
+/// // A sensor record storing a voltage in millivolts.
+/// let record = 3300u16.to_ne_bytes();
+///
+/// let volts = unsafe { record.as_ptr().read_map_at(0, |mv: u16| mv as f32 / 1000.0) };
+/// assert_eq!(volts, 3.3);
+/// ```
+pub trait MapRead: UnalignedRead + Copy {
+    /// Reads a `T` from the pointer at the given byte offset and returns `f(value)`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `size_of::<T>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    /// - The bytes read must represent a valid `T`
+    #[inline(always)]
+    unsafe fn read_map_at<T: Copy, R>(self, byte_offset: usize, f: impl FnOnce(T) -> R) -> R {
+        f(self.read_at(byte_offset))
+    }
+}
+
+impl<P: UnalignedRead + Copy> MapRead for P {}
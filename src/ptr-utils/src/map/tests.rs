@@ -0,0 +1,30 @@
+use super::*;
+use crate::UnalignedWrite;
+
+#[test]
+fn test_read_map() {
+    #[derive(Debug, PartialEq)]
+    enum Kind {
+        File,
+        Directory,
+        Unknown(u8),
+    }
+
+    let mut buffer = [0u8; 8];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_u8_at(0, 2);
+        ptr.write_i32_at(1, -250);
+
+        let kind = ptr.read_map_at(0, |raw: u8| match raw {
+            1 => Kind::File,
+            2 => Kind::Directory,
+            other => Kind::Unknown(other),
+        });
+        assert_eq!(kind, Kind::Directory);
+
+        let celsius = ptr.read_map_at(1, |tenths: i32| tenths as f64 / 10.0);
+        assert_eq!(celsius, -25.0);
+    }
+}
@@ -0,0 +1,12 @@
+//! Reads of floating point matrices.
+//!
+//! Graphics APIs disagree on storage order: OpenGL and Vulkan store matrices column-major,
+//! while Direct3D and most C code store them row-major. The transposed reads convert
+//! between the two as part of the read.
+
+pub mod read;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::MatrixRead;
@@ -0,0 +1,94 @@
+//! Matrix reads.
+
+use crate::UnalignedRead;
+
+/// Trait providing reads of `R×C` floating point matrices stored as consecutive rows.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::MatrixRead;
+///
+/// // A 2x3 matrix, stored row by row.
+/// let data = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0].map(f32::to_ne_bytes).concat();
+/// let ptr = data.as_ptr();
+///
+/// unsafe {
+///     let m = ptr.read_f32_matrix_at::<2, 3>(0);
+///     assert_eq!(m, [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+///
+///     let t = ptr.read_f32_matrix_transposed_at::<2, 3>(0);
+///     assert_eq!(t, [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+/// }
+/// ```
+pub trait MatrixRead: UnalignedRead + Copy {
+    /// Reads an `R×C` matrix of [`f32`] values stored row-major (each of the `R` rows is
+    /// `C` consecutive values) at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `R * C * 4` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f32_matrix_at<const R: usize, const C: usize>(
+        self,
+        byte_offset: usize,
+    ) -> [[f32; C]; R] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads a row-major `R×C` matrix of [`f32`] values at the given byte offset and
+    /// returns it transposed, as a `C×R` matrix.
+    ///
+    /// Converts between row-major and column-major conventions in one read: reading a
+    /// column-major matrix this way yields it row-major, and vice versa.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `R * C * 4` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f32_matrix_transposed_at<const R: usize, const C: usize>(
+        self,
+        byte_offset: usize,
+    ) -> [[f32; R]; C] {
+        let matrix = self.read_f32_matrix_at::<R, C>(byte_offset);
+        core::array::from_fn(|col| core::array::from_fn(|row| matrix[row][col]))
+    }
+
+    /// Reads an `R×C` matrix of [`f64`] values stored row-major (each of the `R` rows is
+    /// `C` consecutive values) at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `R * C * 8` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f64_matrix_at<const R: usize, const C: usize>(
+        self,
+        byte_offset: usize,
+    ) -> [[f64; C]; R] {
+        self.read_at(byte_offset)
+    }
+
+    /// Reads a row-major `R×C` matrix of [`f64`] values at the given byte offset and
+    /// returns it transposed, as a `C×R` matrix.
+    ///
+    /// Converts between row-major and column-major conventions in one read: reading a
+    /// column-major matrix this way yields it row-major, and vice versa.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `R * C * 8` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f64_matrix_transposed_at<const R: usize, const C: usize>(
+        self,
+        byte_offset: usize,
+    ) -> [[f64; R]; C] {
+        let matrix = self.read_f64_matrix_at::<R, C>(byte_offset);
+        core::array::from_fn(|col| core::array::from_fn(|row| matrix[row][col]))
+    }
+}
+
+impl<P: UnalignedRead + Copy> MatrixRead for P {}
@@ -0,0 +1,36 @@
+use super::*;
+use crate::UnalignedWrite;
+
+#[test]
+fn test_read_matrix_transposed() {
+    let mut buffer = [0u8; 1 + 6 * 8];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        // A 2x3 matrix, row-major at an odd offset.
+        for (x, value) in [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0].into_iter().enumerate() {
+            ptr.write_f32_at(1 + x * 4, value);
+        }
+        assert_eq!(
+            ptr.read_f32_matrix_at::<2, 3>(1),
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]
+        );
+        assert_eq!(
+            ptr.read_f32_matrix_transposed_at::<2, 3>(1),
+            [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]
+        );
+        // The same bytes read as 3x2 transpose to a different 2x3.
+        assert_eq!(
+            ptr.read_f32_matrix_transposed_at::<3, 2>(1),
+            [[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]
+        );
+
+        for (x, value) in [1.0f64, 2.0, 3.0, 4.0].into_iter().enumerate() {
+            ptr.write_f64_at(1 + x * 8, value);
+        }
+        assert_eq!(
+            ptr.read_f64_matrix_transposed_at::<2, 2>(1),
+            [[1.0, 3.0], [2.0, 4.0]]
+        );
+    }
+}
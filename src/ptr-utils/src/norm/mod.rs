@@ -0,0 +1,18 @@
+//! Reads and writes of normalized integers.
+//!
+//! Vertex and texture formats commonly store normals and colours as integers scaled to a
+//! unit range: unsigned normalized (`unorm`) values map `0..=MAX` onto `0.0..=1.0`, and
+//! signed normalized (`snorm`) values map `-MAX..=MAX` onto `-1.0..=1.0`.
+//!
+//! Sensor and bus signals generalize this to an arbitrary scale and offset per signal.
+
+pub mod read;
+pub mod scaled;
+pub mod write;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::NormRead;
+pub use scaled::ScaledRead;
+pub use write::NormWrite;
@@ -0,0 +1,60 @@
+//! Normalized integer reads.
+
+use crate::UnalignedRead;
+
+/// Trait providing reads of normalized integers, converted to floating point.
+pub trait NormRead: UnalignedRead + Copy {
+    /// Reads a [`u8`] as an unsigned normalized value in the range `0.0..=1.0`.
+    ///
+    /// The value is divided by `255.0`, so `0` maps to `0.0` and `u8::MAX` to `1.0`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_unorm8_at(self, byte_offset: usize) -> f32 {
+        self.read_u8_at(byte_offset) as f32 / 255.0
+    }
+
+    /// Reads a [`u16`] as an unsigned normalized value in the range `0.0..=1.0`.
+    ///
+    /// The value is divided by `65535.0`, so `0` maps to `0.0` and `u16::MAX` to `1.0`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_unorm16_at(self, byte_offset: usize) -> f32 {
+        self.read_u16_at(byte_offset) as f32 / 65535.0
+    }
+
+    /// Reads an [`i8`] as a signed normalized value in the range `-1.0..=1.0`.
+    ///
+    /// The value is divided by `127.0`; the extra negative value `i8::MIN` is clamped to `-1.0`
+    /// so that both ends of the range are symmetric.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_snorm8_at(self, byte_offset: usize) -> f32 {
+        (self.read_i8_at(byte_offset) as f32 / 127.0).max(-1.0)
+    }
+
+    /// Reads an [`i16`] as a signed normalized value in the range `-1.0..=1.0`.
+    ///
+    /// The value is divided by `32767.0`; the extra negative value `i16::MIN` is clamped to `-1.0`
+    /// so that both ends of the range are symmetric.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_snorm16_at(self, byte_offset: usize) -> f32 {
+        (self.read_i16_at(byte_offset) as f32 / 32767.0).max(-1.0)
+    }
+}
+
+impl<P: UnalignedRead + Copy> NormRead for P {}
@@ -0,0 +1,100 @@
+//! Scaled integer reads: a raw integer converted with a per-signal scale and offset.
+
+use crate::UnalignedRead;
+
+/// Trait providing reads of raw integers converted to physical values as
+/// `raw as f32 * scale + offset`.
+///
+/// This is how CAN (DBC), OBD-II and most sensor registers encode their signals, each
+/// with its own scale ("factor") and offset. Values are read in native endianness, like
+/// the rest of [`UnalignedRead`].
+///
+/// 32-bit raw values above `2^24` in magnitude are rounded when converted to [`f32`].
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::ScaledRead;
+///
+/// // An engine coolant temperature signal: factor 0.5, offset -40 (degrees Celsius).
+/// let frame = [0xA0u8];
+/// let celsius = unsafe { frame.as_ptr().read_u8_scaled_at(0, 0.5, -40.0) };
+/// assert_eq!(celsius, 40.0);
+/// ```
+pub trait ScaledRead: UnalignedRead + Copy {
+    // Unsigned integer types
+
+    /// Reads a [`u8`] from the pointer at the given byte offset and returns
+    /// `raw as f32 * scale + offset`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u8_scaled_at(self, byte_offset: usize, scale: f32, offset: f32) -> f32 {
+        self.read_u8_at(byte_offset) as f32 * scale + offset
+    }
+
+    /// Reads a [`u16`] from the pointer at the given byte offset and returns
+    /// `raw as f32 * scale + offset`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u16_scaled_at(self, byte_offset: usize, scale: f32, offset: f32) -> f32 {
+        self.read_u16_at(byte_offset) as f32 * scale + offset
+    }
+
+    /// Reads a [`u32`] from the pointer at the given byte offset and returns
+    /// `raw as f32 * scale + offset`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u32_scaled_at(self, byte_offset: usize, scale: f32, offset: f32) -> f32 {
+        self.read_u32_at(byte_offset) as f32 * scale + offset
+    }
+
+    // Signed integer types
+
+    /// Reads an [`i8`] from the pointer at the given byte offset and returns
+    /// `raw as f32 * scale + offset`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_i8_scaled_at(self, byte_offset: usize, scale: f32, offset: f32) -> f32 {
+        self.read_i8_at(byte_offset) as f32 * scale + offset
+    }
+
+    /// Reads an [`i16`] from the pointer at the given byte offset and returns
+    /// `raw as f32 * scale + offset`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i16_scaled_at(self, byte_offset: usize, scale: f32, offset: f32) -> f32 {
+        self.read_i16_at(byte_offset) as f32 * scale + offset
+    }
+
+    /// Reads an [`i32`] from the pointer at the given byte offset and returns
+    /// `raw as f32 * scale + offset`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i32_scaled_at(self, byte_offset: usize, scale: f32, offset: f32) -> f32 {
+        self.read_i32_at(byte_offset) as f32 * scale + offset
+    }
+}
+
+impl<P: UnalignedRead + Copy> ScaledRead for P {}
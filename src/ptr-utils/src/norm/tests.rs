@@ -0,0 +1,117 @@
+use super::*;
+use crate::{UnalignedRead, UnalignedWrite};
+
+#[test]
+fn test_read_unorm_endpoints() {
+    let mut buffer = [0u8; 6];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_u8_at(0, 0);
+        ptr.write_u8_at(1, u8::MAX);
+        ptr.write_u16_at(2, 0);
+        ptr.write_u16_at(4, u16::MAX);
+        assert_eq!(ptr.read_unorm8_at(0), 0.0);
+        assert_eq!(ptr.read_unorm8_at(1), 1.0);
+        assert_eq!(ptr.read_unorm16_at(2), 0.0);
+        assert_eq!(ptr.read_unorm16_at(4), 1.0);
+    }
+}
+
+#[test]
+fn test_read_snorm_endpoints() {
+    let mut buffer = [0u8; 9];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_i8_at(0, i8::MAX);
+        ptr.write_i8_at(1, -i8::MAX);
+        ptr.write_i8_at(2, i8::MIN);
+        ptr.write_i16_at(3, i16::MAX);
+        ptr.write_i16_at(5, -i16::MAX);
+        ptr.write_i16_at(7, i16::MIN);
+        assert_eq!(ptr.read_snorm8_at(0), 1.0);
+        assert_eq!(ptr.read_snorm8_at(1), -1.0);
+        assert_eq!(ptr.read_snorm8_at(2), -1.0);
+        assert_eq!(ptr.read_snorm16_at(3), 1.0);
+        assert_eq!(ptr.read_snorm16_at(5), -1.0);
+        assert_eq!(ptr.read_snorm16_at(7), -1.0);
+    }
+}
+
+#[test]
+fn test_write_unorm() {
+    let mut buffer = [0u8; 4];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_unorm8_at(0, 1.0);
+        assert_eq!(ptr.read_u8_at(0), u8::MAX);
+        ptr.write_unorm8_at(0, 0.5);
+        assert_eq!(ptr.read_u8_at(0), 128);
+
+        // Out of range values clamp; NaN is stored as zero.
+        ptr.write_unorm8_at(1, -0.5);
+        assert_eq!(ptr.read_u8_at(1), 0);
+        ptr.write_unorm16_at(2, 2.0);
+        assert_eq!(ptr.read_u16_at(2), u16::MAX);
+        ptr.write_unorm16_at(2, f32::NAN);
+        assert_eq!(ptr.read_u16_at(2), 0);
+    }
+}
+
+#[test]
+fn test_write_snorm() {
+    let mut buffer = [0u8; 3];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_snorm8_at(0, -1.0);
+        assert_eq!(ptr.read_i8_at(0), -i8::MAX);
+        ptr.write_snorm16_at(1, -2.0);
+        assert_eq!(ptr.read_i16_at(1), -i16::MAX);
+        ptr.write_snorm16_at(1, 1.0);
+        assert_eq!(ptr.read_i16_at(1), i16::MAX);
+        ptr.write_snorm16_at(1, -0.5);
+        assert_eq!(ptr.read_i16_at(1), -16384);
+    }
+}
+
+#[test]
+fn test_norm_round_trip() {
+    let mut buffer = [0u8; 2];
+    let ptr = buffer.as_mut_ptr();
+
+    for value in [i16::MIN + 1, -1000, -1, 0, 1, 1000, i16::MAX] {
+        unsafe {
+            ptr.write_i16_at(0, value);
+            let normalized = ptr.read_snorm16_at(0);
+            ptr.write_snorm16_at(0, normalized);
+            assert_eq!(ptr.read_i16_at(0), value);
+        }
+    }
+}
+
+#[test]
+fn test_scaled_reads() {
+    unsafe {
+        let mut buffer = [0u8; 16];
+        let ptr = buffer.as_mut_ptr();
+        ptr.write_u8_at(0, 200);
+        ptr.write_i8_at(1, -8);
+        ptr.write_u16_at(2, 2500);
+        ptr.write_i16_at(5, -1200); // unaligned
+        ptr.write_u32_at(7, 123_456);
+        ptr.write_i32_at(11, -40_000);
+
+        // Coolant temperature: 0.5 degrees per bit, -40 offset.
+        assert_eq!(ptr.read_u8_scaled_at(0, 0.5, -40.0), 60.0);
+        assert_eq!(ptr.read_i8_scaled_at(1, 0.25, 0.0), -2.0);
+        // Engine speed: 0.25 rpm per bit.
+        assert_eq!(ptr.read_u16_scaled_at(2, 0.25, 0.0), 625.0);
+        // Acceleration: 0.01 m/s^2 per bit, offset 1.
+        assert!((ptr.read_i16_scaled_at(5, 0.01, 1.0) - -11.0).abs() < 1e-5);
+        assert!((ptr.read_u32_scaled_at(7, 0.001, 0.0) - 123.456).abs() < 1e-4);
+        assert_eq!(ptr.read_i32_scaled_at(11, 2.0, 100.0), -79_900.0);
+    }
+}
@@ -0,0 +1,78 @@
+//! Normalized integer writes.
+
+use crate::UnalignedWrite;
+
+/// Scales `value`, clamped to `min..=1.0`, by `scale` and offsets it by a half away from
+/// zero, so that the truncating `as` cast which follows rounds to nearest.
+///
+/// NaN passes through unchanged and is stored as zero by the saturating cast.
+#[inline(always)]
+fn quantize(value: f32, min: f32, scale: f32) -> f32 {
+    let scaled = value.clamp(min, 1.0) * scale;
+    if scaled >= 0.0 {
+        scaled + 0.5
+    } else {
+        scaled - 0.5
+    }
+}
+
+/// Trait providing writes of floating point values as normalized integers.
+pub trait NormWrite: UnalignedWrite + Copy {
+    /// Writes a value in the range `0.0..=1.0` as an unsigned normalized [`u8`].
+    ///
+    /// The value is clamped to the range and rounded to the nearest step. NaN is stored as `0`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn write_unorm8_at(self, byte_offset: usize, value: f32) {
+        self.write_u8_at(byte_offset, quantize(value, 0.0, 255.0) as u8);
+    }
+
+    /// Writes a value in the range `0.0..=1.0` as an unsigned normalized [`u16`].
+    ///
+    /// The value is clamped to the range and rounded to the nearest step. NaN is stored as `0`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_unorm16_at(self, byte_offset: usize, value: f32) {
+        self.write_u16_at(byte_offset, quantize(value, 0.0, 65535.0) as u16);
+    }
+
+    /// Writes a value in the range `-1.0..=1.0` as a signed normalized [`i8`].
+    ///
+    /// The value is clamped to the range and rounded to the nearest step; `-1.0` is stored
+    /// as `-127`, so `i8::MIN` is never written. NaN is stored as `0`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn write_snorm8_at(self, byte_offset: usize, value: f32) {
+        self.write_i8_at(byte_offset, quantize(value, -1.0, 127.0) as i8);
+    }
+
+    /// Writes a value in the range `-1.0..=1.0` as a signed normalized [`i16`].
+    ///
+    /// The value is clamped to the range and rounded to the nearest step; `-1.0` is stored
+    /// as `-32767`, so `i16::MIN` is never written. NaN is stored as `0`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_snorm16_at(self, byte_offset: usize, value: f32) {
+        self.write_i16_at(byte_offset, quantize(value, -1.0, 32767.0) as i16);
+    }
+}
+
+impl<P: UnalignedWrite + Copy> NormWrite for P {}
@@ -0,0 +1,132 @@
+//! The sealed [`Numeric`] trait, for code generic over the primitive number types.
+//!
+//! The concrete `read_u32_at`-style methods cover each type by name; [`Numeric`] lets
+//! serialization code be written once for any width, with
+//! [`NumericRead::read_numeric_at`] and [`NumericWrite::write_numeric_at`] handling
+//! the byte order.
+//!
+//! # Example
+//!
+//! ```
+//! use ptr_utils::{Endian, Numeric, NumericRead, NumericWrite};
+//!
+//! /// Writes `values` consecutively in big-endian order, returning the bytes written.
+//! unsafe fn write_all_be<T: Numeric>(ptr: *mut u8, values: &[T]) -> usize {
+//!     for (i, &value) in values.iter().enumerate() {
+//!         ptr.write_numeric_at(i * T::SIZE, value, Endian::Big);
+//!     }
+//!     values.len() * T::SIZE
+//! }
+//!
+//! let mut buffer = [0u8; 8];
+//! let written = unsafe { write_all_be(buffer.as_mut_ptr(), &[1u16, 2, 3]) };
+//!
+//! assert_eq!(written, 6);
+//! assert_eq!(&buffer[..6], &[0, 1, 0, 2, 0, 3]);
+//! assert_eq!(unsafe { buffer.as_ptr().read_numeric_at::<u16>(4, Endian::Big) }, 3);
+//! ```
+
+pub mod read;
+pub mod write;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::NumericRead;
+pub use write::NumericWrite;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive integer or floating point type, with its size and byte conversions.
+///
+/// This trait is sealed and implemented for all the integer types (including [`usize`]
+/// and [`isize`]) and for [`f32`] and [`f64`].
+pub trait Numeric: sealed::Sealed + Copy + 'static {
+    /// The size of the type in bytes.
+    const SIZE: usize;
+
+    /// The byte array representation of the type, `[u8; SIZE]`.
+    type Bytes: Copy + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// Returns the little-endian byte representation of the value.
+    fn to_le_bytes_arr(self) -> Self::Bytes;
+
+    /// Returns the big-endian byte representation of the value.
+    fn to_be_bytes_arr(self) -> Self::Bytes;
+
+    /// Creates a value from its little-endian byte representation.
+    fn from_le_bytes_arr(bytes: Self::Bytes) -> Self;
+
+    /// Creates a value from its big-endian byte representation.
+    fn from_be_bytes_arr(bytes: Self::Bytes) -> Self;
+
+    /// Creates a value from a little-endian byte slice.
+    ///
+    /// # Panics
+    /// Panics if `bytes.len()` is not [`SIZE`](Numeric::SIZE).
+    fn from_le_bytes_slice(bytes: &[u8]) -> Self;
+
+    /// Creates a value from a big-endian byte slice.
+    ///
+    /// # Panics
+    /// Panics if `bytes.len()` is not [`SIZE`](Numeric::SIZE).
+    fn from_be_bytes_slice(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_numeric {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl Numeric for $ty {
+                const SIZE: usize = core::mem::size_of::<$ty>();
+
+                type Bytes = [u8; core::mem::size_of::<$ty>()];
+
+                #[inline(always)]
+                fn to_le_bytes_arr(self) -> Self::Bytes {
+                    self.to_le_bytes()
+                }
+
+                #[inline(always)]
+                fn to_be_bytes_arr(self) -> Self::Bytes {
+                    self.to_be_bytes()
+                }
+
+                #[inline(always)]
+                fn from_le_bytes_arr(bytes: Self::Bytes) -> Self {
+                    <$ty>::from_le_bytes(bytes)
+                }
+
+                #[inline(always)]
+                fn from_be_bytes_arr(bytes: Self::Bytes) -> Self {
+                    <$ty>::from_be_bytes(bytes)
+                }
+
+                #[inline(always)]
+                fn from_le_bytes_slice(bytes: &[u8]) -> Self {
+                    <$ty>::from_le_bytes(slice_to_array(bytes))
+                }
+
+                #[inline(always)]
+                fn from_be_bytes_slice(bytes: &[u8]) -> Self {
+                    <$ty>::from_be_bytes(slice_to_array(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Converts a slice to an array of the same length, panicking with a readable message
+/// if the lengths differ.
+#[inline(always)]
+fn slice_to_array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    match bytes.try_into() {
+        Ok(array) => array,
+        Err(_) => panic!("expected {} bytes, got {}", N, bytes.len()),
+    }
+}
@@ -0,0 +1,24 @@
+//! Generic numeric reads.
+
+use super::Numeric;
+use crate::{Endian, UnalignedRead};
+
+/// Trait providing reads of any [`Numeric`] type in a given byte order.
+pub trait NumericRead: UnalignedRead + Copy {
+    /// Reads a `T` in the given byte order from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading [`T::SIZE`](Numeric::SIZE) bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_numeric_at<T: Numeric>(self, byte_offset: usize, endian: Endian) -> T {
+        let bytes = self.read_at::<T::Bytes>(byte_offset);
+        match endian {
+            Endian::Little => T::from_le_bytes_arr(bytes),
+            Endian::Big => T::from_be_bytes_arr(bytes),
+        }
+    }
+}
+
+impl<P: UnalignedRead + Copy> NumericRead for P {}
@@ -0,0 +1,63 @@
+use super::*;
+use crate::Endian;
+
+/// Round-trips `value` through both byte orders and checks the stored bytes.
+fn check_round_trip<T: Numeric + PartialEq + core::fmt::Debug>(value: T) {
+    let mut buffer = [0u8; 20];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_numeric_at(3, value, Endian::Little);
+        assert_eq!(&buffer[3..3 + T::SIZE], value.to_le_bytes_arr().as_ref());
+        assert_eq!(
+            buffer.as_ptr().read_numeric_at::<T>(3, Endian::Little),
+            value
+        );
+
+        let ptr = buffer.as_mut_ptr();
+        ptr.write_numeric_at(3, value, Endian::Big);
+        assert_eq!(&buffer[3..3 + T::SIZE], value.to_be_bytes_arr().as_ref());
+        assert_eq!(buffer.as_ptr().read_numeric_at::<T>(3, Endian::Big), value);
+    }
+
+    assert_eq!(
+        T::from_le_bytes_slice(value.to_le_bytes_arr().as_ref()),
+        value
+    );
+    assert_eq!(
+        T::from_be_bytes_slice(value.to_be_bytes_arr().as_ref()),
+        value
+    );
+}
+
+#[test]
+fn test_numeric_round_trip_all_types() {
+    check_round_trip(0xABu8);
+    check_round_trip(0xABCDu16);
+    check_round_trip(0x12345678u32);
+    check_round_trip(0x123456789ABCDEF0u64);
+    check_round_trip(0x0123456789ABCDEF0123456789ABCDEFu128);
+    check_round_trip(usize::MAX - 1);
+    check_round_trip(-2i8);
+    check_round_trip(-2i16);
+    check_round_trip(-2i32);
+    check_round_trip(-2i64);
+    check_round_trip(-2i128);
+    check_round_trip(isize::MIN);
+    check_round_trip(1.5f32);
+    check_round_trip(-1.5f64);
+}
+
+#[test]
+fn test_numeric_size() {
+    assert_eq!(u8::SIZE, 1);
+    assert_eq!(u128::SIZE, 16);
+    assert_eq!(f32::SIZE, 4);
+    assert_eq!(isize::SIZE, core::mem::size_of::<isize>());
+}
+
+#[test]
+#[should_panic(expected = "expected 4 bytes, got 3")]
+fn test_from_bytes_slice_wrong_length_panics() {
+    u32::from_le_bytes_slice(&[1, 2, 3]);
+}
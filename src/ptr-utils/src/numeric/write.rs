@@ -0,0 +1,25 @@
+//! Generic numeric writes.
+
+use super::Numeric;
+use crate::{Endian, UnalignedWrite};
+
+/// Trait providing writes of any [`Numeric`] type in a given byte order.
+pub trait NumericWrite: UnalignedWrite + Copy {
+    /// Writes a `T` in the given byte order to the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing [`T::SIZE`](Numeric::SIZE) bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_numeric_at<T: Numeric>(self, byte_offset: usize, value: T, endian: Endian) {
+        let bytes = match endian {
+            Endian::Little => value.to_le_bytes_arr(),
+            Endian::Big => value.to_be_bytes_arr(),
+        };
+        self.write_at(byte_offset, bytes);
+    }
+}
+
+impl<P: UnalignedWrite + Copy> NumericWrite for P {}
@@ -0,0 +1,8 @@
+//! Reads guarded against running past the end of a mapped region.
+
+pub mod read;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::PageRead;
@@ -0,0 +1,230 @@
+//! Reads guarded by a caller-supplied extent.
+
+use core::mem::size_of;
+
+use crate::UnalignedRead;
+
+/// Trait providing reads that return [`None`] rather than touching memory at or beyond a
+/// caller-supplied extent.
+///
+/// `page_end` is the number of valid bytes from the base pointer, e.g. the size of a
+/// memory-mapped region, past which a read would fault. This guards pointer-based code
+/// without first wrapping the mapping in a slice.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::PageRead;
+///
+/// let mapping = [0u8; 4096];
+/// let ptr = mapping.as_ptr();
+///
+/// unsafe {
+///     assert_eq!(ptr.read_u32_checked_page_at(4092, 4096), Some(0));
+///     assert_eq!(ptr.read_u32_checked_page_at(4093, 4096), None);
+/// }
+/// ```
+pub trait PageRead: UnalignedRead + Copy {
+    /// Reads a `T` at the given byte offset, or returns [`None`] if
+    /// `byte_offset + size_of::<T>() > page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    /// - The bytes read must represent a valid `T`
+    #[inline(always)]
+    unsafe fn read_checked_page_at<T: Copy>(
+        self,
+        byte_offset: usize,
+        page_end: usize,
+    ) -> Option<T> {
+        let end = byte_offset.checked_add(size_of::<T>())?;
+        if end > page_end {
+            return None;
+        }
+        Some(self.read_at(byte_offset))
+    }
+
+    // Unsigned integer types
+
+    /// Reads a [`u8`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u8_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<u8> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads a [`u16`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u16_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<u16> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads a [`u32`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u32_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<u32> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads a [`u64`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u64_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<u64> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads a [`u128`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u128_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<u128> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads a [`usize`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_usize_checked_page_at(
+        self,
+        byte_offset: usize,
+        page_end: usize,
+    ) -> Option<usize> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    // Signed integer types
+
+    /// Reads an [`i8`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_i8_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<i8> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads an [`i16`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i16_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<i16> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads an [`i32`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i32_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<i32> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads an [`i64`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i64_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<i64> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads an [`i128`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i128_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<i128> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads an [`isize`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_isize_checked_page_at(
+        self,
+        byte_offset: usize,
+        page_end: usize,
+    ) -> Option<isize> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    // Floating point types
+
+    /// Reads an [`f32`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f32_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<f32> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+
+    /// Reads an [`f64`] value at the given byte offset, or returns [`None`] if the read would
+    /// extend past `page_end`.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `page_end` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f64_checked_page_at(self, byte_offset: usize, page_end: usize) -> Option<f64> {
+        self.read_checked_page_at(byte_offset, page_end)
+    }
+}
+
+impl<P: UnalignedRead + Copy> PageRead for P {}
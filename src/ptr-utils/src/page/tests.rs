@@ -0,0 +1,43 @@
+use super::*;
+use crate::UnalignedWrite;
+
+const PAGE: usize = 4096;
+
+#[test]
+fn test_checked_page_reads_at_boundary() {
+    let mut mapping = [0u8; PAGE + 8];
+    let ptr = mapping.as_mut_ptr();
+
+    unsafe {
+        ptr.write_u32_at(PAGE - 4, 0xCAFEBABE);
+        ptr.write_u64_at(PAGE - 8, 0x0102030405060708);
+
+        // Reads ending exactly at the boundary succeed.
+        assert_eq!(
+            ptr.read_u64_checked_page_at(PAGE - 8, PAGE),
+            Some(0x0102030405060708)
+        );
+        assert_eq!(
+            ptr.read_u8_checked_page_at(PAGE - 1, PAGE),
+            Some(mapping[PAGE - 1])
+        );
+
+        // Reads crossing or starting past it do not.
+        assert_eq!(ptr.read_u32_checked_page_at(PAGE - 3, PAGE), None);
+        assert_eq!(ptr.read_u16_checked_page_at(PAGE, PAGE), None);
+        assert_eq!(ptr.read_u8_checked_page_at(PAGE, PAGE), None);
+    }
+}
+
+#[test]
+fn test_checked_page_read_offset_overflow() {
+    let data = [0u8; 4];
+    unsafe {
+        assert_eq!(
+            data.as_ptr()
+                .read_u32_checked_page_at(usize::MAX - 1, usize::MAX),
+            None
+        );
+        assert_eq!(data.as_ptr().read_f32_checked_page_at(0, 4), Some(0.0));
+    }
+}
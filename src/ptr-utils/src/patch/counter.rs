@@ -0,0 +1,419 @@
+//! Non-atomic read-modify-write counters.
+
+use crate::{UnalignedRead, UnalignedWrite};
+
+/// Trait providing in-place counter updates: read a value, add or subtract a delta,
+/// write it back, and return the old value.
+///
+/// Useful for reference counts and free-list heads stored in serialized structures.
+/// Arithmetic wraps on overflow, like [`wrapping_add`](u32::wrapping_add).
+///
+/// These are **not** atomic: the read and the write are separate, unaligned accesses, so
+/// concurrent updates from other threads can be lost. For counters shared between threads,
+/// use the types in [`core::sync::atomic`] on aligned memory instead.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{CounterWrite, UnalignedRead};
+///
+/// let mut refcount = 1u32.to_ne_bytes();
+/// let ptr = refcount.as_mut_ptr();
+///
+/// unsafe {
+///     assert_eq!(ptr.fetch_add_u32_at(0, 1), 1);
+///     assert_eq!(ptr.fetch_sub_u32_at(0, 2), 2);
+///     assert_eq!(ptr.read_u32_at(0), 0);
+/// }
+/// ```
+pub trait CounterWrite: UnalignedRead + UnalignedWrite + Copy {
+    // Unsigned integer types
+
+    /// Reads a [`u8`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_u8_at(self, byte_offset: usize, delta: u8) -> u8 {
+        let old = self.read_u8_at(byte_offset);
+        self.write_u8_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads a [`u8`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_u8_at(self, byte_offset: usize, delta: u8) -> u8 {
+        let old = self.read_u8_at(byte_offset);
+        self.write_u8_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    /// Reads a [`u16`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_u16_at(self, byte_offset: usize, delta: u16) -> u16 {
+        let old = self.read_u16_at(byte_offset);
+        self.write_u16_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads a [`u16`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_u16_at(self, byte_offset: usize, delta: u16) -> u16 {
+        let old = self.read_u16_at(byte_offset);
+        self.write_u16_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    /// Reads a [`u32`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_u32_at(self, byte_offset: usize, delta: u32) -> u32 {
+        let old = self.read_u32_at(byte_offset);
+        self.write_u32_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads a [`u32`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_u32_at(self, byte_offset: usize, delta: u32) -> u32 {
+        let old = self.read_u32_at(byte_offset);
+        self.write_u32_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    /// Reads a [`u64`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_u64_at(self, byte_offset: usize, delta: u64) -> u64 {
+        let old = self.read_u64_at(byte_offset);
+        self.write_u64_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads a [`u64`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_u64_at(self, byte_offset: usize, delta: u64) -> u64 {
+        let old = self.read_u64_at(byte_offset);
+        self.write_u64_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    /// Reads a [`u128`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_u128_at(self, byte_offset: usize, delta: u128) -> u128 {
+        let old = self.read_u128_at(byte_offset);
+        self.write_u128_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads a [`u128`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_u128_at(self, byte_offset: usize, delta: u128) -> u128 {
+        let old = self.read_u128_at(byte_offset);
+        self.write_u128_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    /// Reads a [`usize`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing [`size_of::<usize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_usize_at(self, byte_offset: usize, delta: usize) -> usize {
+        let old = self.read_usize_at(byte_offset);
+        self.write_usize_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads a [`usize`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing [`size_of::<usize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_usize_at(self, byte_offset: usize, delta: usize) -> usize {
+        let old = self.read_usize_at(byte_offset);
+        self.write_usize_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    // Signed integer types
+
+    /// Reads an [`i8`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_i8_at(self, byte_offset: usize, delta: i8) -> i8 {
+        let old = self.read_i8_at(byte_offset);
+        self.write_i8_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads an [`i8`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_i8_at(self, byte_offset: usize, delta: i8) -> i8 {
+        let old = self.read_i8_at(byte_offset);
+        self.write_i8_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    /// Reads an [`i16`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_i16_at(self, byte_offset: usize, delta: i16) -> i16 {
+        let old = self.read_i16_at(byte_offset);
+        self.write_i16_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads an [`i16`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_i16_at(self, byte_offset: usize, delta: i16) -> i16 {
+        let old = self.read_i16_at(byte_offset);
+        self.write_i16_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    /// Reads an [`i32`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_i32_at(self, byte_offset: usize, delta: i32) -> i32 {
+        let old = self.read_i32_at(byte_offset);
+        self.write_i32_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads an [`i32`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_i32_at(self, byte_offset: usize, delta: i32) -> i32 {
+        let old = self.read_i32_at(byte_offset);
+        self.write_i32_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    /// Reads an [`i64`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_i64_at(self, byte_offset: usize, delta: i64) -> i64 {
+        let old = self.read_i64_at(byte_offset);
+        self.write_i64_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads an [`i64`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_i64_at(self, byte_offset: usize, delta: i64) -> i64 {
+        let old = self.read_i64_at(byte_offset);
+        self.write_i64_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    /// Reads an [`i128`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_i128_at(self, byte_offset: usize, delta: i128) -> i128 {
+        let old = self.read_i128_at(byte_offset);
+        self.write_i128_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads an [`i128`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_i128_at(self, byte_offset: usize, delta: i128) -> i128 {
+        let old = self.read_i128_at(byte_offset);
+        self.write_i128_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+
+    /// Reads an [`isize`] at the given byte offset, adds `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing [`size_of::<isize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_add_isize_at(self, byte_offset: usize, delta: isize) -> isize {
+        let old = self.read_isize_at(byte_offset);
+        self.write_isize_at(byte_offset, old.wrapping_add(delta));
+        old
+    }
+
+    /// Reads an [`isize`] at the given byte offset, subtracts `delta` (wrapping), writes the
+    /// result back, and returns the old value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing [`size_of::<isize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the update
+    /// - The memory location must be mutable
+    /// - Must not race with other accesses to the same bytes; this is not atomic
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn fetch_sub_isize_at(self, byte_offset: usize, delta: isize) -> isize {
+        let old = self.read_isize_at(byte_offset);
+        self.write_isize_at(byte_offset, old.wrapping_sub(delta));
+        old
+    }
+}
+
+impl<P: UnalignedRead + UnalignedWrite + Copy> CounterWrite for P {}
@@ -0,0 +1,10 @@
+//! In-place modification of values already in memory.
+
+pub mod counter;
+pub mod temp;
+
+#[cfg(test)]
+mod tests;
+
+pub use counter::CounterWrite;
+pub use temp::TempWrite;
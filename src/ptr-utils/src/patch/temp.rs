@@ -0,0 +1,217 @@
+//! Temporarily overwriting a value for the duration of a closure.
+
+use crate::{UnalignedRead, UnalignedWrite};
+
+/// Trait providing scoped temporary writes, restoring the original value afterwards.
+///
+/// Useful for patch-and-test workflows: write a value, observe its effect, then put the
+/// original back.
+///
+/// The original value is **not** restored if `f` panics. Wrap the call in
+/// `std::panic::catch_unwind` if the buffer must be left untouched on unwind.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{TempWrite, UnalignedRead};
+///
+/// let mut buffer = 1u32.to_ne_bytes();
+/// let ptr = buffer.as_mut_ptr();
+///
+/// unsafe {
+///     let seen = ptr.with_temp_u32_at(0, 42, || ptr.read_u32_at(0));
+///     assert_eq!(seen, 42);
+///     assert_eq!(ptr.read_u32_at(0), 1);
+/// }
+/// ```
+pub trait TempWrite: UnalignedRead + UnalignedWrite + Copy {
+    /// Writes `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing `size_of::<T>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - The bytes at the offset must represent a valid `T`
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline]
+    unsafe fn with_temp_at<T: Copy, R>(
+        self,
+        byte_offset: usize,
+        temp: T,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        let original = self.read_at::<T>(byte_offset);
+        self.write_at(byte_offset, temp);
+        let result = f();
+        self.write_at(byte_offset, original);
+        result
+    }
+
+    // Unsigned integer types
+
+    /// Writes a [`u8`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn with_temp_u8_at<R>(self, byte_offset: usize, temp: u8, f: impl FnOnce() -> R) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    /// Writes a [`u16`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn with_temp_u16_at<R>(self, byte_offset: usize, temp: u16, f: impl FnOnce() -> R) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    /// Writes a [`u32`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn with_temp_u32_at<R>(self, byte_offset: usize, temp: u32, f: impl FnOnce() -> R) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    /// Writes a [`u64`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn with_temp_u64_at<R>(self, byte_offset: usize, temp: u64, f: impl FnOnce() -> R) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    /// Writes a [`u128`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn with_temp_u128_at<R>(
+        self,
+        byte_offset: usize,
+        temp: u128,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    /// Writes a [`usize`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing [`size_of::<usize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn with_temp_usize_at<R>(
+        self,
+        byte_offset: usize,
+        temp: usize,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    // Signed integer types
+
+    /// Writes an [`i8`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 1 byte
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn with_temp_i8_at<R>(self, byte_offset: usize, temp: i8, f: impl FnOnce() -> R) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    /// Writes an [`i16`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn with_temp_i16_at<R>(self, byte_offset: usize, temp: i16, f: impl FnOnce() -> R) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    /// Writes an [`i32`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn with_temp_i32_at<R>(self, byte_offset: usize, temp: i32, f: impl FnOnce() -> R) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    /// Writes an [`i64`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn with_temp_i64_at<R>(self, byte_offset: usize, temp: i64, f: impl FnOnce() -> R) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    /// Writes an [`i128`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn with_temp_i128_at<R>(
+        self,
+        byte_offset: usize,
+        temp: i128,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+
+    /// Writes an [`isize`] `temp` at the given byte offset, runs `f`, then restores the original value.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading and writing [`size_of::<isize>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the call
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned reads and writes
+    #[inline(always)]
+    unsafe fn with_temp_isize_at<R>(
+        self,
+        byte_offset: usize,
+        temp: isize,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        self.with_temp_at(byte_offset, temp, f)
+    }
+}
+
+impl<P: UnalignedRead + UnalignedWrite + Copy> TempWrite for P {}
@@ -0,0 +1,72 @@
+use super::*;
+use crate::{UnalignedRead, UnalignedWrite};
+
+#[test]
+fn test_with_temp_restores_original() {
+    let mut buffer = [0u8; 16];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_u32_at(3, 0xDEADBEEF);
+        ptr.write_i16_at(8, -5);
+
+        let seen = ptr.with_temp_u32_at(3, 7, || ptr.read_u32_at(3));
+        assert_eq!(seen, 7);
+        assert_eq!(ptr.read_u32_at(3), 0xDEADBEEF);
+
+        let seen = ptr.with_temp_i16_at(8, 100, || ptr.read_i16_at(8));
+        assert_eq!(seen, 100);
+        assert_eq!(ptr.read_i16_at(8), -5);
+    }
+
+    // Neighbouring bytes are untouched.
+    assert_eq!(buffer[..3], [0, 0, 0]);
+    assert_eq!(buffer[10..], [0; 6]);
+}
+
+#[test]
+fn test_fetch_add_and_sub() {
+    unsafe {
+        let mut buffer = [0u8; 32];
+        let ptr = buffer.as_mut_ptr();
+        ptr.write_u32_at(1, 10);
+        ptr.write_i16_at(5, -3);
+        ptr.write_u64_at(7, 100);
+        ptr.write_u8_at(15, 0);
+
+        assert_eq!(ptr.fetch_add_u32_at(1, 5), 10);
+        assert_eq!(ptr.fetch_sub_u32_at(1, 3), 15);
+        assert_eq!(ptr.read_u32_at(1), 12);
+
+        assert_eq!(ptr.fetch_add_i16_at(5, 4), -3);
+        assert_eq!(ptr.read_i16_at(5), 1);
+        assert_eq!(ptr.fetch_sub_u64_at(7, 1), 100);
+        assert_eq!(ptr.read_u64_at(7), 99);
+
+        // Neighbouring bytes are untouched.
+        assert_eq!(buffer[0], 0);
+        assert_eq!(buffer[15], 0);
+    }
+}
+
+#[test]
+fn test_fetch_add_wraps() {
+    unsafe {
+        let mut buffer = [0u8; 16];
+        let ptr = buffer.as_mut_ptr();
+
+        ptr.write_u32_at(0, u32::MAX);
+        assert_eq!(ptr.fetch_add_u32_at(0, 1), u32::MAX);
+        assert_eq!(ptr.read_u32_at(0), 0);
+        assert_eq!(ptr.fetch_sub_u32_at(0, 1), 0);
+        assert_eq!(ptr.read_u32_at(0), u32::MAX);
+
+        ptr.write_i8_at(4, i8::MAX);
+        assert_eq!(ptr.fetch_add_i8_at(4, 1), i8::MAX);
+        assert_eq!(ptr.read_i8_at(4), i8::MIN);
+
+        ptr.write_usize_at(5, 0);
+        assert_eq!(ptr.fetch_sub_usize_at(5, 2), 0);
+        assert_eq!(ptr.read_usize_at(5), usize::MAX - 1);
+    }
+}
@@ -0,0 +1,13 @@
+//! Reads and writes of common packed pixel formats.
+//!
+//! All channel arrays are returned and accepted in RGB(A) order, regardless of the
+//! order in memory, so callers never have to reorder channels themselves.
+
+pub mod read;
+pub mod write;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::PixelRead;
+pub use write::PixelWrite;
@@ -0,0 +1,125 @@
+//! Packed pixel reads.
+
+use crate::UnalignedRead;
+
+/// Trait providing reads of packed pixel formats as RGB(A) channel arrays.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::PixelRead;
+///
+/// let pixels = [0x30u8, 0x20, 0x10, 0xFF, 0x00, 0xF8];
+/// let ptr = pixels.as_ptr();
+///
+/// unsafe {
+///     assert_eq!(ptr.read_bgra8_at(0), [0x10, 0x20, 0x30, 0xFF]);
+///     assert_eq!(ptr.read_rgb565_at(4), [0xFF, 0x00, 0x00]); // pure red
+/// }
+/// ```
+pub trait PixelRead: UnalignedRead + Copy {
+    /// Reads an RGBA8 pixel (bytes `R, G, B, A` in memory) from the pointer at the given
+    /// byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_rgba8_at(self, byte_offset: usize) -> [u8; 4] {
+        self.read_at::<[u8; 4]>(byte_offset)
+    }
+
+    /// Reads a BGRA8 pixel (bytes `B, G, R, A` in memory) from the pointer at the given
+    /// byte offset, returning the channels in RGBA order.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_bgra8_at(self, byte_offset: usize) -> [u8; 4] {
+        let [b, g, r, a] = self.read_at::<[u8; 4]>(byte_offset);
+        [r, g, b, a]
+    }
+
+    /// Reads an RGB8 pixel (bytes `R, G, B` in memory) from the pointer at the given
+    /// byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_rgb8_at(self, byte_offset: usize) -> [u8; 3] {
+        self.read_at::<[u8; 3]>(byte_offset)
+    }
+
+    /// Reads a little-endian RGB565 pixel from the pointer at the given byte offset,
+    /// expanding each channel to 8 bits.
+    ///
+    /// Red occupies bits 11..16, green bits 5..11 and blue bits 0..5. Channels are
+    /// expanded by bit replication, so the full range maps exactly (`0x1F` to `0xFF`).
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_rgb565_at(self, byte_offset: usize) -> [u8; 3] {
+        let packed = u16::from_le(self.read_u16_at(byte_offset));
+        let r = (packed >> 11) as u8;
+        let g = ((packed >> 5) & 0x3F) as u8;
+        let b = (packed & 0x1F) as u8;
+        [
+            (r << 3) | (r >> 2),
+            (g << 2) | (g >> 4),
+            (b << 3) | (b >> 2),
+        ]
+    }
+
+    /// Reads an RGB8 pixel (bytes `R, G, B` in memory) from the pointer at the given
+    /// byte offset, converting it to HSV.
+    ///
+    /// Returns `(hue, saturation, value)`, with hue in degrees (`0.0..360.0`) and
+    /// saturation and value in `0.0..=1.0`. Grays (including black and white) have no
+    /// defined hue and report `0.0`; black also reports a saturation of `0.0`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn read_rgb_as_hsv_at(self, byte_offset: usize) -> (f32, f32, f32) {
+        rgb_to_hsv(self.read_rgb8_at(byte_offset))
+    }
+}
+
+impl<P: UnalignedRead + Copy> PixelRead for P {}
+
+/// Converts 8-bit RGB channels to `(hue, saturation, value)`.
+#[inline]
+fn rgb_to_hsv([r, g, b]: [u8; 3]) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let value = max as f32 / 255.0;
+    if max == min {
+        // Gray: hue is undefined, and saturation is zero.
+        return (0.0, 0.0, value);
+    }
+
+    let delta = (max - min) as f32;
+    let saturation = delta / max as f32;
+    let diff = |a: u8, b: u8| (a as f32 - b as f32) / delta;
+    let sector = if max == r {
+        // Between magenta (300) and yellow (60), wrapping through red at 0.
+        let sector = diff(g, b);
+        if sector < 0.0 {
+            sector + 6.0
+        } else {
+            sector
+        }
+    } else if max == g {
+        diff(b, r) + 2.0
+    } else {
+        diff(r, g) + 4.0
+    };
+
+    (sector * 60.0, saturation, value)
+}
@@ -0,0 +1,121 @@
+use super::*;
+
+#[test]
+fn test_rgba_bgra() {
+    unsafe {
+        let mut buffer = [0u8; 12];
+        let ptr = buffer.as_mut_ptr();
+
+        ptr.write_rgba8_at(0, [1, 2, 3, 4]);
+        ptr.write_bgra8_at(4, [1, 2, 3, 4]);
+        ptr.write_rgb8_at(8, [5, 6, 7]);
+
+        assert_eq!(buffer[..8], [1, 2, 3, 4, 3, 2, 1, 4]);
+        let ptr = buffer.as_ptr();
+        assert_eq!(ptr.read_rgba8_at(0), [1, 2, 3, 4]);
+        assert_eq!(ptr.read_bgra8_at(4), [1, 2, 3, 4]);
+        assert_eq!(ptr.read_rgb8_at(8), [5, 6, 7]);
+    }
+}
+
+#[test]
+fn test_rgb565_expansion() {
+    unsafe {
+        let data = [0x00u8, 0xF8, 0xE0, 0x07, 0x1F, 0x00, 0xFF, 0xFF, 0x00, 0x00];
+        let ptr = data.as_ptr();
+
+        assert_eq!(ptr.read_rgb565_at(0), [0xFF, 0x00, 0x00]);
+        assert_eq!(ptr.read_rgb565_at(2), [0x00, 0xFF, 0x00]);
+        assert_eq!(ptr.read_rgb565_at(4), [0x00, 0x00, 0xFF]);
+        assert_eq!(ptr.read_rgb565_at(6), [0xFF, 0xFF, 0xFF]);
+        assert_eq!(ptr.read_rgb565_at(8), [0x00, 0x00, 0x00]);
+    }
+}
+
+#[test]
+fn test_rgb565_round_trip() {
+    unsafe {
+        let mut buffer = [0u8; 3];
+        let ptr = buffer.as_mut_ptr();
+
+        // Exactly representable values survive unchanged.
+        for rgb in [[0xFF, 0x00, 0x00], [0x00, 0xFF, 0xFF], [0x84, 0x82, 0x84]] {
+            ptr.write_rgb565_at(1, rgb);
+            assert_eq!(ptr.read_rgb565_at(1), rgb);
+        }
+
+        // Others round to the nearest level: within 4 (red/blue) and 2 (green).
+        let rgb = [0x7B, 0x45, 0xC9];
+        ptr.write_rgb565_at(1, rgb);
+        let back = ptr.read_rgb565_at(1);
+        assert!(rgb[0].abs_diff(back[0]) <= 4);
+        assert!(rgb[1].abs_diff(back[1]) <= 2);
+        assert!(rgb[2].abs_diff(back[2]) <= 4);
+    }
+}
+
+#[test]
+fn test_rgb565_round_trip_all_values() {
+    unsafe {
+        let mut buffer = [0u8; 2];
+        let ptr = buffer.as_mut_ptr();
+
+        for value in 0..=255u8 {
+            ptr.write_rgb565_at(0, [value, value, value]);
+            let back = ptr.read_rgb565_at(0);
+            assert!(
+                value.abs_diff(back[0]) <= 4,
+                "{value} read back as {back:?}"
+            );
+            assert!(
+                value.abs_diff(back[1]) <= 2,
+                "{value} read back as {back:?}"
+            );
+
+            // Whatever the reader produces, the writer packs back to the same pixel.
+            let packed = buffer;
+            ptr.write_rgb565_at(0, back);
+            assert_eq!(buffer, packed);
+        }
+    }
+}
+
+#[test]
+fn test_bgra_write_reads_back() {
+    unsafe {
+        let mut buffer = [0u8; 5];
+        let ptr = buffer.as_mut_ptr();
+
+        ptr.write_bgra8_at(1, [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(buffer[1..], [0x33, 0x22, 0x11, 0x44]);
+        assert_eq!(ptr.read_bgra8_at(1), [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(ptr.read_rgba8_at(1), [0x33, 0x22, 0x11, 0x44]);
+    }
+}
+
+#[test]
+fn test_rgb_as_hsv() {
+    unsafe {
+        let data = [
+            0xFFu8, 0x00, 0x00, // red
+            0xFF, 0xFF, 0xFF, // white
+            0x00, 0x00, 0x00, // black
+            0x80, 0x80, 0x80, // gray
+            0x00, 0xFF, 0x00, // green
+            0x00, 0x00, 0xFF, // blue
+            0xFF, 0x00, 0x80, // rose, just below the red wrap-around
+        ];
+        let ptr = data.as_ptr();
+
+        assert_eq!(ptr.read_rgb_as_hsv_at(0), (0.0, 1.0, 1.0));
+        assert_eq!(ptr.read_rgb_as_hsv_at(3), (0.0, 0.0, 1.0));
+        assert_eq!(ptr.read_rgb_as_hsv_at(6), (0.0, 0.0, 0.0));
+        assert_eq!(ptr.read_rgb_as_hsv_at(9), (0.0, 0.0, 128.0 / 255.0));
+        assert_eq!(ptr.read_rgb_as_hsv_at(12), (120.0, 1.0, 1.0));
+        assert_eq!(ptr.read_rgb_as_hsv_at(15), (240.0, 1.0, 1.0));
+
+        let (hue, saturation, value) = ptr.read_rgb_as_hsv_at(18);
+        assert!((hue - (360.0 - 60.0 * 128.0 / 255.0)).abs() < 1e-3);
+        assert_eq!((saturation, value), (1.0, 1.0));
+    }
+}
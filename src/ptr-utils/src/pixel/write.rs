@@ -0,0 +1,69 @@
+//! Packed pixel writes.
+
+use crate::UnalignedWrite;
+
+/// Trait providing writes of RGB(A) channel arrays in packed pixel formats.
+pub trait PixelWrite: UnalignedWrite + Copy {
+    /// Writes an RGBA8 pixel (bytes `R, G, B, A` in memory) to the pointer at the given
+    /// byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn write_rgba8_at(self, byte_offset: usize, rgba: [u8; 4]) {
+        self.write_at(byte_offset, rgba);
+    }
+
+    /// Writes an RGBA channel array as a BGRA8 pixel (bytes `B, G, R, A` in memory) to the
+    /// pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn write_bgra8_at(self, byte_offset: usize, rgba: [u8; 4]) {
+        let [r, g, b, a] = rgba;
+        self.write_at(byte_offset, [b, g, r, a]);
+    }
+
+    /// Writes an RGB8 pixel (bytes `R, G, B` in memory) to the pointer at the given
+    /// byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 3 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn write_rgb8_at(self, byte_offset: usize, rgb: [u8; 3]) {
+        self.write_at(byte_offset, rgb);
+    }
+
+    /// Packs an RGB channel array into a little-endian RGB565 pixel at the given byte offset.
+    ///
+    /// Each channel is rounded to the nearest 5/6/5-bit level, so a read back through
+    /// [`PixelRead::read_rgb565_at`](crate::PixelRead::read_rgb565_at) is within 4
+    /// (red/blue) or 2 (green) of the original, and exact for values that read produced.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 2 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_rgb565_at(self, byte_offset: usize, rgb: [u8; 3]) {
+        let [r, g, b] = rgb;
+        let packed = (quantize(r, 31) << 11) | (quantize(g, 63) << 5) | quantize(b, 31);
+        self.write_u16_at(byte_offset, packed.to_le());
+    }
+}
+
+impl<P: UnalignedWrite + Copy> PixelWrite for P {}
+
+/// Rounds an 8-bit channel to the nearest of `max + 1` levels.
+#[inline(always)]
+fn quantize(channel: u8, max: u16) -> u16 {
+    (channel as u16 * max + 127) / 255
+}
@@ -0,0 +1,71 @@
+//! Reads and writes of [`bytemuck::Pod`] types.
+
+use bytemuck::Pod;
+
+use crate::{UnalignedRead, UnalignedWrite};
+
+/// Trait providing reads of any [`bytemuck::Pod`] type, for types that already derive
+/// it rather than implementing this crate's [`Pod`](crate::Pod).
+///
+/// # Example
+///
+/// ```
+/// use bytemuck::{Pod, Zeroable};
+/// use ptr_utils::{BytemuckRead, BytemuckWrite};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// #[repr(C)]
+/// struct Vertex {
+///     position: [f32; 2],
+///     color: u32,
+/// }
+///
+/// // Usually derived, with bytemuck's `derive` feature.
+/// unsafe impl Zeroable for Vertex {}
+/// unsafe impl Pod for Vertex {}
+///
+/// let mut buffer = [0u8; 13];
+/// let ptr = buffer.as_mut_ptr();
+/// let vertex = Vertex { position: [1.0, -1.0], color: 0xFF00FF00 };
+///
+/// unsafe {
+///     ptr.write_pod_bytemuck_at(1, vertex);
+///     assert_eq!(ptr.read_pod_bytemuck_at::<Vertex>(1), vertex);
+/// }
+/// ```
+pub trait BytemuckRead: UnalignedRead + Copy {
+    /// Reads a `T` from the pointer at the given byte offset.
+    ///
+    /// Unlike [`read_at`](UnalignedRead::read_at), the bytes need not be checked for
+    /// validity: [`Pod`] guarantees any bit pattern is a valid `T`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `size_of::<T>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_pod_bytemuck_at<T: Pod>(self, byte_offset: usize) -> T {
+        self.read_at(byte_offset)
+    }
+}
+
+impl<P: UnalignedRead + Copy> BytemuckRead for P {}
+
+/// Trait providing writes of any [`bytemuck::Pod`] type.
+///
+/// [`Pod`] guarantees `T` has no padding, so every byte written is initialized.
+pub trait BytemuckWrite: UnalignedWrite + Copy {
+    /// Writes a `T` to the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `size_of::<T>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_pod_bytemuck_at<T: Pod>(self, byte_offset: usize, value: T) {
+        self.write_at(byte_offset, value);
+    }
+}
+
+impl<P: UnalignedWrite + Copy> BytemuckWrite for P {}
@@ -0,0 +1,39 @@
+//! The [`Pod`] marker trait for types that may be read from arbitrary bytes.
+
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "bytemuck")]
+pub use self::bytemuck::{BytemuckRead, BytemuckWrite};
+
+/// Marker for "plain old data": types valid for every bit pattern, with no padding.
+///
+/// Safe APIs (such as [`Span::iter_refs`](crate::Span::iter_refs)) require this bound to
+/// reinterpret untrusted bytes as `T` without undefined behaviour.
+///
+/// # Safety
+/// Implementors must guarantee that:
+/// - Every bit pattern of `size_of::<Self>()` bytes is a valid value of `Self`
+/// - `Self` contains no padding bytes
+/// - `Self` contains no pointers, references or interior mutability
+///
+/// For a `#[repr(C)]` struct, this holds if all fields are [`Pod`] and there is no
+/// padding between or after them.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(
+            // SAFETY: primitive integers and floats are valid for every bit pattern.
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+// SAFETY: an array of `Pod` values has no padding and is valid for every bit pattern.
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
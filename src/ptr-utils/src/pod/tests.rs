@@ -0,0 +1,30 @@
+use super::*;
+
+fn assert_pod<T: Pod>() {}
+
+#[test]
+fn test_pod_impls() {
+    assert_pod::<u8>();
+    assert_pod::<i128>();
+    assert_pod::<f64>();
+    assert_pod::<[u16; 4]>();
+    assert_pod::<[[f32; 2]; 3]>();
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck_read_write() {
+    let mut buffer = [0u8; 17];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_pod_bytemuck_at(1, [0x1234u16, 0x5678]);
+        ptr.write_pod_bytemuck_at(5, -2.5f64);
+        assert_eq!(ptr.read_pod_bytemuck_at::<[u16; 2]>(1), [0x1234, 0x5678]);
+        assert_eq!(ptr.read_pod_bytemuck_at::<f64>(5), -2.5);
+        assert_eq!(
+            ptr.read_pod_bytemuck_at::<u32>(1),
+            u32::from_ne_bytes(::bytemuck::cast([0x1234u16, 0x5678]))
+        );
+    }
+}
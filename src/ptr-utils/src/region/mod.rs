@@ -0,0 +1,10 @@
+//! Operations over whole byte ranges, rather than single values.
+
+pub mod read;
+pub mod write;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::RegionRead;
+pub use write::RegionWrite;
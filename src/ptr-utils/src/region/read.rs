@@ -0,0 +1,154 @@
+//! Read-only byte range operations.
+
+use core::hash::Hasher;
+use core::{ptr, slice};
+
+use crate::{AsBytePtr, UnalignedRead};
+
+/// Trait providing read-only operations over byte ranges at a byte offset.
+pub trait RegionRead: UnalignedRead + AsBytePtr + Copy {
+    /// Returns `true` if the bytes at the given byte offset are equal to `expected`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `expected.len()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn bytes_eq_at(self, byte_offset: usize, expected: &[u8]) -> bool {
+        slice::from_raw_parts(self.as_byte_ptr().add(byte_offset), expected.len()) == expected
+    }
+
+    /// Returns the wrapping sum of `len` bytes starting at the given byte offset.
+    ///
+    /// This is the simple additive checksum used by many formats and bootloaders.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `len` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn sum_bytes_at(self, byte_offset: usize, len: usize) -> u32 {
+        slice::from_raw_parts(self.as_byte_ptr().add(byte_offset), len)
+            .iter()
+            .fold(0u32, |sum, &byte| sum.wrapping_add(byte as u32))
+    }
+
+    /// Returns the XOR of `len` bytes starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `len` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn xor_bytes_at(self, byte_offset: usize, len: usize) -> u8 {
+        slice::from_raw_parts(self.as_byte_ptr().add(byte_offset), len)
+            .iter()
+            .fold(0u8, |acc, &byte| acc ^ byte)
+    }
+
+    /// Returns the 64-bit FNV-1a hash of `len` bytes starting at the given byte offset.
+    ///
+    /// FNV-1a is deterministic across platforms and runs, so the hash can serve as a
+    /// dedup key or change detector for serialized data. It is not collision-resistant
+    /// against adversarial input.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `len` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn fnv1a_hash_at(self, byte_offset: usize, len: usize) -> u64 {
+        const OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01B3;
+
+        slice::from_raw_parts(self.as_byte_ptr().add(byte_offset), len)
+            .iter()
+            .fold(OFFSET_BASIS, |hash, &byte| {
+                (hash ^ byte as u64).wrapping_mul(PRIME)
+            })
+    }
+
+    /// Feeds `len` bytes starting at the given byte offset into `state`, as a single
+    /// [`Hasher::write`] call.
+    ///
+    /// For hashing a region with any [`Hasher`], e.g. to key a `HashMap` by its contents.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `len` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn hash_bytes_at<H: Hasher>(self, byte_offset: usize, len: usize, state: &mut H) {
+        state.write(slice::from_raw_parts(
+            self.as_byte_ptr().add(byte_offset),
+            len,
+        ));
+    }
+
+    /// Returns `true` if all `len` bytes starting at the given byte offset are zero.
+    ///
+    /// For formats that require padding or reserved bytes to be zero and treat anything
+    /// else as corruption.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `len` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::{RegionRead, UnalignedRead};
+    ///
+    /// // A 3-byte name padded with zeros to 8 bytes.
+    /// let record = *b"abc\0\0\0\0\0";
+    /// let ptr = record.as_ptr();
+    ///
+    /// unsafe {
+    ///     assert_eq!(ptr.read_at::<[u8; 3]>(0), *b"abc");
+    ///     assert!(ptr.padding_is_zero_at(3, 5));
+    /// }
+    /// ```
+    #[inline]
+    unsafe fn padding_is_zero_at(self, byte_offset: usize, len: usize) -> bool {
+        slice::from_raw_parts(self.as_byte_ptr().add(byte_offset), len)
+            .iter()
+            .all(|&byte| byte == 0)
+    }
+
+    /// Compares `len` bytes starting at the given byte offset against `len` bytes at
+    /// `other`, returning the index of the first differing byte within the range (so
+    /// `other.add(index)` is the differing byte), or [`None`] if the ranges are equal.
+    ///
+    /// Stops at the first difference, for locating where two serialized buffers diverge.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `len` bytes
+    /// - `other` must be valid for reading `len` bytes
+    /// - The caller must ensure both pointers remain valid for the duration of the read
+    #[inline]
+    unsafe fn first_diff_at(
+        self,
+        other: *const u8,
+        byte_offset: usize,
+        len: usize,
+    ) -> Option<usize> {
+        let this = slice::from_raw_parts(self.as_byte_ptr().add(byte_offset), len);
+        let other = slice::from_raw_parts(other, len);
+        this.iter().zip(other).position(|(a, b)| a != b)
+    }
+
+    /// Copies `len` bytes starting at the given byte offset to `dst`.
+    ///
+    /// This is the canonical bulk copy: it lowers to [`ptr::copy_nonoverlapping`]
+    /// (i.e. `memcpy`), which already picks the widest loads and stores the addresses
+    /// allow and handles the unaligned head and tail. In the `copy` benchmark it is faster
+    /// than byte-by-byte and hand-rolled 8-byte chunked loops at every length measured,
+    /// for aligned and misaligned addresses; the results are recorded in `benches/copy.rs`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading `len` bytes
+    /// - `dst` must be valid for writing `len` bytes
+    /// - The source and destination ranges must not overlap
+    /// - No alignment requirements
+    #[inline(always)]
+    unsafe fn copy_bytes_fast_at(self, src_offset: usize, dst: *mut u8, len: usize) {
+        ptr::copy_nonoverlapping(self.as_byte_ptr().add(src_offset), dst, len);
+    }
+}
+
+impl<P: UnalignedRead + AsBytePtr + Copy> RegionRead for P {}
@@ -0,0 +1,195 @@
+use core::hash::Hasher;
+
+use super::*;
+use crate::{UnalignedRead, UnalignedWrite};
+
+#[test]
+fn test_bytes_eq_at() {
+    let data = *b"xxHELLOxx";
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert!(ptr.bytes_eq_at(2, b"HELLO"));
+        assert!(!ptr.bytes_eq_at(1, b"HELLO"));
+        assert!(ptr.bytes_eq_at(9, b""));
+    }
+}
+
+#[test]
+fn test_first_diff_at() {
+    let data = *b"xxHELLOxx";
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.first_diff_at(b"HELLO".as_ptr(), 2, 5), None);
+        assert_eq!(ptr.first_diff_at(b"HELPS".as_ptr(), 2, 5), Some(3));
+        assert_eq!(ptr.first_diff_at(b"JELLO".as_ptr(), 2, 5), Some(0));
+        assert_eq!(ptr.first_diff_at(b"HELLX".as_ptr(), 2, 5), Some(4));
+        // Only `len` bytes are compared.
+        assert_eq!(ptr.first_diff_at(b"HELPS".as_ptr(), 2, 3), None);
+        assert_eq!(ptr.first_diff_at(b"".as_ptr(), 9, 0), None);
+    }
+}
+
+#[test]
+fn test_fill_at() {
+    let mut buffer = [0u8; 8];
+    unsafe { buffer.as_mut_ptr().fill_at(2, 4, 0xCC) };
+    assert_eq!(buffer, [0, 0, 0xCC, 0xCC, 0xCC, 0xCC, 0, 0]);
+}
+
+#[test]
+fn test_write_bytes_at() {
+    let mut buffer = [0u8; 8];
+    unsafe {
+        buffer.as_mut_ptr().write_bytes_at(3, b"abc");
+        buffer.as_mut_ptr().write_bytes_at(8, b"");
+    }
+    assert_eq!(&buffer, b"\0\0\0abc\0\0");
+}
+
+#[test]
+fn test_checksums() {
+    let data = [0xFFu8, 0x01, 0x10, 0x80, 0x7F, 0x00];
+    let ptr = data.as_ptr();
+
+    unsafe {
+        // 0x01 + 0x10 + 0x80 + 0x7F = 0x110
+        assert_eq!(ptr.sum_bytes_at(1, 4), 0x110);
+        assert_eq!(ptr.sum_bytes_at(0, 6), 0x20F);
+        assert_eq!(ptr.sum_bytes_at(0, 0), 0);
+        // 0x01 ^ 0x10 ^ 0x80 ^ 0x7F = 0xEE
+        assert_eq!(ptr.xor_bytes_at(1, 4), 0xEE);
+        assert_eq!(ptr.xor_bytes_at(0, 1), 0xFF);
+    }
+
+    // The sum is not truncated to a byte
+    let ones = [0xFFu8; 64];
+    assert_eq!(unsafe { ones.as_ptr().sum_bytes_at(0, 64) }, 64 * 0xFF);
+}
+
+#[test]
+fn test_fnv1a_hash_at() {
+    let data = *b"xfoobarx";
+    let ptr = data.as_ptr();
+
+    unsafe {
+        // Reference values from the FNV specification's test suite.
+        assert_eq!(ptr.fnv1a_hash_at(1, 0), 0xCBF29CE484222325);
+        assert_eq!(ptr.fnv1a_hash_at(1, 6), 0x85944171F73967E8);
+        assert_eq!(b"a".as_ptr().fnv1a_hash_at(0, 1), 0xAF63DC4C8601EC8C);
+    }
+}
+
+#[test]
+fn test_hash_bytes_at() {
+    // Records the bytes it is fed, to check exactly the region is passed.
+    #[derive(Default)]
+    struct Recorder([u8; 8], usize);
+
+    impl Hasher for Recorder {
+        fn finish(&self) -> u64 {
+            self.1 as u64
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0[self.1..self.1 + bytes.len()].copy_from_slice(bytes);
+            self.1 += bytes.len();
+        }
+    }
+
+    let data = *b"xfoobarx";
+    let mut state = Recorder::default();
+    unsafe { data.as_ptr().hash_bytes_at(1, 6, &mut state) };
+    assert_eq!(&state.0[..state.1], b"foobar");
+}
+
+#[test]
+fn test_padding_is_zero() {
+    let mut data = [0u8; 12];
+    data[0] = 0x41;
+    let ptr = data.as_mut_ptr();
+
+    unsafe {
+        assert!(ptr.padding_is_zero_at(1, 11));
+        assert!(ptr.padding_is_zero_at(5, 0));
+        assert!(!ptr.padding_is_zero_at(0, 2));
+
+        // A single nonzero byte anywhere in the range fails the check.
+        ptr.write_u8_at(9, 0x01);
+        assert!(!ptr.padding_is_zero_at(1, 11));
+        assert!(ptr.padding_is_zero_at(1, 8));
+    }
+}
+
+#[test]
+fn test_write_repeated() {
+    let mut buffer = [0u8; 26];
+    let ptr = buffer.as_mut_ptr();
+
+    unsafe {
+        ptr.write_repeated_u32_at(1, 0xDEADBEEF, 6);
+        for x in 0..6 {
+            assert_eq!(ptr.read_u32_at(1 + x * 4), 0xDEADBEEF);
+        }
+
+        ptr.write_repeated_at(1, [0xAAu8, 0xBB], 2);
+        assert_eq!(
+            ptr.read_u32_at(1),
+            u32::from_ne_bytes([0xAA, 0xBB, 0xAA, 0xBB])
+        );
+        assert_eq!(ptr.read_u32_at(5), 0xDEADBEEF);
+    }
+
+    // Bytes outside the region are untouched.
+    assert_eq!(buffer[0], 0);
+    assert_eq!(buffer[25], 0);
+}
+
+#[test]
+fn test_copy_bytes_fast() {
+    let src: [u8; 37] = core::array::from_fn(|x| x as u8);
+    let mut dst = [0u8; 40];
+
+    unsafe {
+        src.as_ptr()
+            .copy_bytes_fast_at(1, dst.as_mut_ptr().add(3), 35);
+        src.as_ptr().copy_bytes_fast_at(0, dst.as_mut_ptr(), 0);
+    }
+
+    assert_eq!(dst[..3], [0, 0, 0]);
+    assert_eq!(dst[3..38], src[1..36]);
+    assert_eq!(dst[38..], [0, 0]);
+}
+
+#[test]
+fn test_swap_regions_at() {
+    unsafe {
+        // Three 8-byte records; swap the first and last.
+        let mut buffer = [0u8; 24];
+        let ptr = buffer.as_mut_ptr();
+        ptr.write_u64_at(0, 0x1111_1111_1111_1111);
+        ptr.write_u64_at(8, 0x2222_2222_2222_2222);
+        ptr.write_u64_at(16, 0x3333_3333_3333_3333);
+
+        ptr.swap_regions_at(0, 16, 8);
+        assert_eq!(ptr.read_u64_at(0), 0x3333_3333_3333_3333);
+        assert_eq!(ptr.read_u64_at(8), 0x2222_2222_2222_2222);
+        assert_eq!(ptr.read_u64_at(16), 0x1111_1111_1111_1111);
+
+        // Adjacent ranges don't overlap; either argument order works.
+        ptr.swap_regions_at(16, 8, 8);
+        assert_eq!(ptr.read_u64_at(8), 0x1111_1111_1111_1111);
+        assert_eq!(ptr.read_u64_at(16), 0x2222_2222_2222_2222);
+
+        ptr.swap_regions_at(0, 0, 0);
+    }
+}
+
+#[cfg(all(debug_assertions, not(feature = "no-panic")))]
+#[test]
+#[should_panic(expected = "regions at 0 and 4 overlap for 8 bytes")]
+fn test_swap_regions_at_overlap_panics() {
+    let mut buffer = [0u8; 12];
+    unsafe { buffer.as_mut_ptr().swap_regions_at(0, 4, 8) };
+}
@@ -0,0 +1,225 @@
+//! Mutating byte range operations.
+
+use core::mem::size_of;
+use core::ptr;
+
+use crate::{AsBytePtrMut, UnalignedWrite};
+
+/// Trait providing mutating operations over byte ranges at a byte offset.
+pub trait RegionWrite: UnalignedWrite + AsBytePtrMut + Copy {
+    /// Sets `len` bytes starting at the given byte offset to `value`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `len` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    #[inline(always)]
+    unsafe fn fill_at(self, byte_offset: usize, len: usize, value: u8) {
+        ptr::write_bytes(self.as_byte_ptr_mut().add(byte_offset), value, len);
+    }
+
+    /// Copies `bytes` to the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `bytes.len()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - The destination must not overlap `bytes`
+    #[inline(always)]
+    unsafe fn write_bytes_at(self, byte_offset: usize, bytes: &[u8]) {
+        ptr::copy_nonoverlapping(
+            bytes.as_ptr(),
+            self.as_byte_ptr_mut().add(byte_offset),
+            bytes.len(),
+        );
+    }
+
+    /// Swaps the `len` bytes at `offset_a` with the `len` bytes at `offset_b`.
+    ///
+    /// The byte-range counterpart of [`slice::swap`], for reordering fixed-size records
+    /// in place.
+    ///
+    /// # Safety
+    /// - The pointer plus each offset must be valid for reading and writing `len` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the swap
+    /// - The memory location must be mutable
+    /// - The two ranges must not overlap (checked in debug builds)
+    #[inline(always)]
+    unsafe fn swap_regions_at(self, offset_a: usize, offset_b: usize, len: usize) {
+        debug_check!(
+            offset_a.abs_diff(offset_b) >= len,
+            "regions at {offset_a} and {offset_b} overlap for {len} bytes"
+        );
+        let ptr = self.as_byte_ptr_mut();
+        ptr::swap_nonoverlapping(ptr.add(offset_a), ptr.add(offset_b), len);
+    }
+
+    /// Writes `value` into `count` consecutive `T` slots starting at the given byte offset.
+    ///
+    /// The typed analogue of [`RegionWrite::fill_at`], for initializing tables of
+    /// multi-byte entries.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<T>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline]
+    unsafe fn write_repeated_at<T: Copy>(self, byte_offset: usize, value: T, count: usize) {
+        for x in 0..count {
+            self.write_at(byte_offset + x * size_of::<T>(), value);
+        }
+    }
+
+    // Unsigned integer types
+
+    /// Writes a [`u16`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<u16>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_u16_at(self, byte_offset: usize, value: u16, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    /// Writes a [`u32`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<u32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_u32_at(self, byte_offset: usize, value: u32, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    /// Writes a [`u64`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<u64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_u64_at(self, byte_offset: usize, value: u64, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    /// Writes a [`u128`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<u128>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_u128_at(self, byte_offset: usize, value: u128, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    /// Writes a [`usize`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<usize>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_usize_at(self, byte_offset: usize, value: usize, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    // Signed integer types
+
+    /// Writes an [`i16`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<i16>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_i16_at(self, byte_offset: usize, value: i16, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    /// Writes an [`i32`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<i32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_i32_at(self, byte_offset: usize, value: i32, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    /// Writes an [`i64`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<i64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_i64_at(self, byte_offset: usize, value: i64, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    /// Writes an [`i128`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<i128>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_i128_at(self, byte_offset: usize, value: i128, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    /// Writes an [`isize`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<isize>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_isize_at(self, byte_offset: usize, value: isize, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    // Floating point types
+
+    /// Writes an [`f32`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<f32>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_f32_at(self, byte_offset: usize, value: f32, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+
+    /// Writes an [`f64`] `value` into `count` consecutive slots starting at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing `count * size_of::<f64>()` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_repeated_f64_at(self, byte_offset: usize, value: f64, count: usize) {
+        self.write_repeated_at(byte_offset, value, count);
+    }
+}
+
+impl<P: UnalignedWrite + AsBytePtrMut + Copy> RegionWrite for P {}
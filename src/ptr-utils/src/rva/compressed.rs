@@ -0,0 +1,51 @@
+//! Compressed pointer reads.
+
+use crate::UnalignedRead;
+
+/// Trait providing reads of compressed pointers: 32-bit offsets scaled by a shift and
+/// added to a base, `base + (stored << shift)`.
+///
+/// This is the encoding of the JVM's compressed ordinary object pointers, where objects
+/// are 8-byte aligned and a shift of 3 addresses 32 GiB with 32-bit references.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{CompressedPtrRead, UnalignedRead};
+///
+/// // A heap whose reference at offset 0 points to the value at offset 16, scaled by 8.
+/// let mut heap = [0u8; 24];
+/// heap[..4].copy_from_slice(&2u32.to_ne_bytes());
+/// heap[16] = 0x5A;
+/// let base = heap.as_ptr();
+///
+/// unsafe {
+///     let target = base.read_compressed_ptr_at(base, 0, 3);
+///     assert_eq!(target.read_u8_at(0), 0x5A);
+/// }
+/// ```
+pub trait CompressedPtrRead: UnalignedRead + Copy {
+    /// Reads a native endian [`u32`] at the given byte offset and returns
+    /// `base + (stored << shift)`.
+    ///
+    /// The pointer arithmetic wraps, and the result derives its provenance from `base`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - `shift` must be less than [`usize::BITS`]; the shifted offset is truncated to the
+    ///   address width
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_compressed_ptr_at(
+        self,
+        base: *const u8,
+        byte_offset: usize,
+        shift: u32,
+    ) -> *const u8 {
+        let stored = self.read_u32_at(byte_offset) as usize;
+        base.wrapping_add(stored << shift)
+    }
+}
+
+impl<P: UnalignedRead + Copy> CompressedPtrRead for P {}
@@ -0,0 +1,20 @@
+//! Resolution of references stored relative to a base pointer.
+//!
+//! PE/COFF images store most references as relative virtual addresses (RVAs): offsets
+//! from the image base rather than from the referencing field. Compressed pointers
+//! likewise store a scaled offset from a heap base. Resolving either needs the base
+//! pointer as well as the field; the returned pointers derive their provenance from
+//! the base.
+//!
+//! Offset tables, arrays of offsets from the start of a file, resolve the same way.
+
+pub mod compressed;
+pub mod read;
+pub mod table;
+
+#[cfg(test)]
+mod tests;
+
+pub use compressed::CompressedPtrRead;
+pub use read::RvaRead;
+pub use table::OffsetTableRead;
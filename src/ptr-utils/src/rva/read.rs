@@ -0,0 +1,98 @@
+//! RVA reads.
+
+use crate::{Endian, EndianRead, UnalignedRead};
+
+/// Trait providing reads of image-base-relative offsets, resolved to pointers.
+///
+/// The offsets are read as little-endian, as in PE/COFF. The pointer arithmetic wraps,
+/// so a corrupt RVA yields a wild pointer rather than undefined behaviour; it must still
+/// be validated against the image size before it is dereferenced.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{RvaRead, UnalignedRead};
+///
+/// // A synthetic image whose field at offset 4 holds the RVA of a value at offset 12.
+/// let mut image = [0u8; 16];
+/// image[4..8].copy_from_slice(&12u32.to_le_bytes());
+/// image[12..16].copy_from_slice(&0xCAFEu32.to_ne_bytes());
+/// let base = image.as_ptr();
+///
+/// unsafe {
+///     let target = base.read_rva_u32_at(base, 4);
+///     assert_eq!(target.read_u32_at(0), 0xCAFE);
+/// }
+/// ```
+pub trait RvaRead: UnalignedRead + Copy {
+    /// Reads a little-endian [`u32`] RVA at the given byte offset and returns
+    /// `image_base + rva`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_rva_u32_at(self, image_base: *const u8, byte_offset: usize) -> *const u8 {
+        let rva = self.read_u32_endian_at(byte_offset, Endian::Little);
+        image_base.wrapping_add(rva as usize)
+    }
+
+    /// Reads a little-endian [`u64`] RVA at the given byte offset and returns
+    /// `image_base + rva`.
+    ///
+    /// For 64-bit relative fields, such as those in some PE32+ structures. On targets
+    /// with a 32-bit `usize`, the RVA is truncated to the address width.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_rva_u64_at(self, image_base: *const u8, byte_offset: usize) -> *const u8 {
+        let rva = self.read_u64_endian_at(byte_offset, Endian::Little);
+        image_base.wrapping_add(rva as usize)
+    }
+
+    /// Reads a little-endian [`u32`] RVA at the given byte offset, returning `None` if it
+    /// is 0 and `Some(image_base + rva)` otherwise.
+    ///
+    /// For nullable references, where an RVA of 0 means "no object" (e.g. an absent
+    /// directory entry) rather than the image base itself.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_optional_rva_at(
+        self,
+        image_base: *const u8,
+        byte_offset: usize,
+    ) -> Option<*const u8> {
+        self.read_optional_rva_sentinel_at(image_base, byte_offset, 0)
+    }
+
+    /// Reads a little-endian [`u32`] RVA at the given byte offset, returning `None` if it
+    /// equals `sentinel` and `Some(image_base + rva)` otherwise.
+    ///
+    /// For formats that mark absent references with a value other than 0, such as
+    /// `u32::MAX`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_optional_rva_sentinel_at(
+        self,
+        image_base: *const u8,
+        byte_offset: usize,
+        sentinel: u32,
+    ) -> Option<*const u8> {
+        let rva = self.read_u32_endian_at(byte_offset, Endian::Little);
+        (rva != sentinel).then(|| image_base.wrapping_add(rva as usize))
+    }
+}
+
+impl<P: UnalignedRead + Copy> RvaRead for P {}
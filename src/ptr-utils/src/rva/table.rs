@@ -0,0 +1,89 @@
+//! Offset table resolution.
+
+use crate::{AsBytePtr, UnalignedRead};
+
+/// Trait resolving tables of offsets, each relative to the pointer the table is read
+/// through, into pointers.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::{OffsetTableRead, UnalignedRead};
+///
+/// // A file starting with a table of 2 offsets, followed by the entries they point to.
+/// let mut file = [0u8; 12];
+/// file[..4].copy_from_slice(&8u32.to_ne_bytes());
+/// file[4..8].copy_from_slice(&10u32.to_ne_bytes());
+/// file[8] = 0xAA;
+/// file[10] = 0xBB;
+/// let base = file.as_ptr();
+///
+/// let mut entries = [core::ptr::null(); 2];
+/// unsafe {
+///     base.resolve_offset_table_u32_at(0, 2, &mut entries);
+///     assert_eq!(entries[0].read_u8_at(0), 0xAA);
+///     assert_eq!(entries[1].read_u8_at(0), 0xBB);
+/// }
+/// ```
+pub trait OffsetTableRead: UnalignedRead + AsBytePtr + Copy {
+    /// Reads `count` native endian [`u32`] offsets starting at `table_offset`, and writes
+    /// `self + offset` for each into `out`.
+    ///
+    /// The pointer arithmetic wraps, and the results derive their provenance from `self`.
+    ///
+    /// # Safety
+    /// - The pointer plus table offset must be valid for reading `count * 4` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - `out` must hold at least `count` pointers (checked in debug builds)
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn resolve_offset_table_u32_at(
+        self,
+        table_offset: usize,
+        count: usize,
+        out: &mut [*const u8],
+    ) {
+        debug_check!(
+            out.len() >= count,
+            "output slice holds {} pointers but the table has {count} entries",
+            out.len()
+        );
+        let base = self.as_byte_ptr();
+        for (x, slot) in out.iter_mut().take(count).enumerate() {
+            let offset = self.read_u32_at(table_offset + x * 4) as usize;
+            *slot = base.wrapping_add(offset);
+        }
+    }
+
+    /// Reads `count` native endian [`u64`] offsets starting at `table_offset`, and writes
+    /// `self + offset` for each into `out`.
+    ///
+    /// The pointer arithmetic wraps, and the results derive their provenance from `self`.
+    ///
+    /// # Safety
+    /// - The pointer plus table offset must be valid for reading `count * 8` bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - `out` must hold at least `count` pointers (checked in debug builds)
+    /// - Offsets are truncated to the address width on 32-bit targets
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn resolve_offset_table_u64_at(
+        self,
+        table_offset: usize,
+        count: usize,
+        out: &mut [*const u8],
+    ) {
+        debug_check!(
+            out.len() >= count,
+            "output slice holds {} pointers but the table has {count} entries",
+            out.len()
+        );
+        let base = self.as_byte_ptr();
+        for (x, slot) in out.iter_mut().take(count).enumerate() {
+            let offset = self.read_u64_at(table_offset + x * 8) as usize;
+            *slot = base.wrapping_add(offset);
+        }
+    }
+}
+
+impl<P: UnalignedRead + AsBytePtr + Copy> OffsetTableRead for P {}
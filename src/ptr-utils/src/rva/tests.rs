@@ -0,0 +1,118 @@
+use super::*;
+use crate::UnalignedRead;
+
+#[test]
+fn test_read_rva() {
+    // Image layout: a 32-bit RVA at 0x02, a 64-bit RVA at 0x06, and their targets.
+    let mut image = [0u8; 0x20];
+    image[0x02..0x06].copy_from_slice(&0x10u32.to_le_bytes());
+    image[0x06..0x0E].copy_from_slice(&0x18u64.to_le_bytes());
+    image[0x10..0x14].copy_from_slice(&0x11223344u32.to_ne_bytes());
+    image[0x18..0x1C].copy_from_slice(&0x55667788u32.to_ne_bytes());
+    let base = image.as_ptr();
+
+    unsafe {
+        let target = base.read_rva_u32_at(base, 0x02);
+        assert_eq!(target, base.add(0x10));
+        assert_eq!(target.read_u32_at(0), 0x11223344);
+
+        let target = base.read_rva_u64_at(base, 0x06);
+        assert_eq!(target, base.add(0x18));
+        assert_eq!(target.read_u32_at(0), 0x55667788);
+    }
+}
+
+#[test]
+fn test_read_rva_from_sub_pointer() {
+    // The field is read through a pointer into the image, but resolved against the base.
+    let mut image = [0u8; 16];
+    image[8..12].copy_from_slice(&4u32.to_le_bytes());
+    image[4] = 0x7F;
+    let base = image.as_ptr();
+
+    unsafe {
+        let header = base.add(8);
+        let target = header.read_rva_u32_at(base, 0);
+        assert_eq!(target.read_u8_at(0), 0x7F);
+    }
+}
+
+#[test]
+fn test_read_optional_rva() {
+    // A null RVA at 0x00, a present one at 0x04, and a `u32::MAX`-terminated one at 0x08.
+    let mut image = [0u8; 16];
+    image[0x04..0x08].copy_from_slice(&0x0Cu32.to_le_bytes());
+    image[0x08..0x0C].copy_from_slice(&u32::MAX.to_le_bytes());
+    image[0x0C] = 0x5A;
+    let base = image.as_ptr();
+
+    unsafe {
+        assert_eq!(base.read_optional_rva_at(base, 0x00), None);
+
+        let target = base.read_optional_rva_at(base, 0x04).unwrap();
+        assert_eq!(target, base.add(0x0C));
+        assert_eq!(target.read_u8_at(0), 0x5A);
+
+        assert_eq!(
+            base.read_optional_rva_sentinel_at(base, 0x08, u32::MAX),
+            None
+        );
+        assert_eq!(
+            base.read_optional_rva_sentinel_at(base, 0x00, u32::MAX),
+            Some(base)
+        );
+        assert_eq!(
+            base.read_optional_rva_sentinel_at(base, 0x04, u32::MAX),
+            Some(base.add(0x0C))
+        );
+    }
+}
+
+#[test]
+fn test_read_compressed_ptr() {
+    let mut heap = [0u8; 64];
+    heap[0..4].copy_from_slice(&5u32.to_ne_bytes());
+    heap[4..8].copy_from_slice(&0u32.to_ne_bytes());
+    heap[40..44].copy_from_slice(&0xFEEDu32.to_ne_bytes());
+    let base = heap.as_ptr();
+
+    unsafe {
+        // 5 << 3 = 40
+        let target = base.read_compressed_ptr_at(base, 0, 3);
+        assert_eq!(target, base.add(40));
+        assert_eq!(target.read_u32_at(0), 0xFEED);
+
+        // A zero shift is a plain offset, and a zero reference resolves to the base.
+        assert_eq!(base.read_compressed_ptr_at(base, 0, 0), base.add(5));
+        assert_eq!(base.read_compressed_ptr_at(base, 4, 3), base);
+    }
+}
+
+#[test]
+fn test_resolve_offset_table() {
+    // A 3-entry u32 table at 0x02 and a 2-entry u64 table at 0x0E, pointing past them.
+    let mut file = [0u8; 0x28];
+    for (x, offset) in [0x1Eu32, 0x20, 0x24].into_iter().enumerate() {
+        file[0x02 + x * 4..0x06 + x * 4].copy_from_slice(&offset.to_ne_bytes());
+    }
+    for (x, offset) in [0x24u64, 0x1E].into_iter().enumerate() {
+        file[0x0E + x * 8..0x16 + x * 8].copy_from_slice(&offset.to_ne_bytes());
+    }
+    file[0x1E] = 0x11;
+    file[0x20] = 0x22;
+    file[0x24] = 0x33;
+    let base = file.as_ptr();
+
+    unsafe {
+        let mut out = [core::ptr::null(); 4];
+        base.resolve_offset_table_u32_at(0x02, 3, &mut out);
+        assert_eq!(out[..3], [base.add(0x1E), base.add(0x20), base.add(0x24)]);
+        assert!(out[3].is_null());
+        assert_eq!(out[2].read_u8_at(0), 0x33);
+
+        let mut out = [core::ptr::null(); 2];
+        base.resolve_offset_table_u64_at(0x0E, 2, &mut out);
+        assert_eq!(out, [base.add(0x24), base.add(0x1E)]);
+        assert_eq!(out[1].read_u8_at(0), 0x11);
+    }
+}
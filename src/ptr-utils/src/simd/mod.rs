@@ -0,0 +1,13 @@
+//! Unaligned SIMD vector loads and stores.
+//!
+//! Each instruction set is gated on its target architecture, in addition to the `simd`
+//! feature.
+
+#[cfg(target_arch = "aarch64")]
+pub mod neon;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(target_arch = "aarch64")]
+pub use neon::{NeonRead, NeonWrite};
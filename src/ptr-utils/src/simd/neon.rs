@@ -0,0 +1,58 @@
+//! AArch64 NEON vector loads and stores.
+
+use core::arch::aarch64::{uint8x16_t, vld1q_u8, vst1q_u8};
+
+use crate::{AsBytePtr, AsBytePtrMut, UnalignedRead, UnalignedWrite};
+
+/// Trait providing NEON vector loads at a byte offset.
+///
+/// NEON is part of the AArch64 baseline, and `vld1q_u8` has no alignment requirement.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(target_arch = "aarch64", feature = "simd"))]
+/// # {
+/// use core::arch::aarch64::vgetq_lane_u8;
+/// use ptr_utils::simd::NeonRead;
+///
+/// let data: [u8; 17] = core::array::from_fn(|i| i as u8);
+///
+/// unsafe {
+///     let vector = data.as_ptr().read_uint8x16_at(1);
+///     assert_eq!(vgetq_lane_u8::<0>(vector), 1);
+///     assert_eq!(vgetq_lane_u8::<15>(vector), 16);
+/// }
+/// # }
+/// ```
+pub trait NeonRead: UnalignedRead + AsBytePtr + Copy {
+    /// Reads a [`uint8x16_t`] vector from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_uint8x16_at(self, byte_offset: usize) -> uint8x16_t {
+        vld1q_u8(self.as_byte_ptr().add(byte_offset))
+    }
+}
+
+impl<P: UnalignedRead + AsBytePtr + Copy> NeonRead for P {}
+
+/// Trait providing NEON vector stores at a byte offset.
+pub trait NeonWrite: UnalignedWrite + AsBytePtrMut + Copy {
+    /// Writes a [`uint8x16_t`] vector to the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing 16 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    #[inline(always)]
+    unsafe fn write_uint8x16_at(self, byte_offset: usize, value: uint8x16_t) {
+        vst1q_u8(self.as_byte_ptr_mut().add(byte_offset), value);
+    }
+}
+
+impl<P: UnalignedWrite + AsBytePtrMut + Copy> NeonWrite for P {}
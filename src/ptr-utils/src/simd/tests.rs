@@ -0,0 +1,23 @@
+#[cfg(target_arch = "aarch64")]
+use super::*;
+
+#[test]
+#[cfg(target_arch = "aarch64")]
+fn test_neon_round_trip() {
+    use core::arch::aarch64::{vaddq_u8, vdupq_n_u8};
+
+    let source: [u8; 20] = core::array::from_fn(|i| i as u8);
+    let mut dest = [0u8; 20];
+
+    unsafe {
+        let vector = source.as_ptr().read_uint8x16_at(3);
+        let incremented = vaddq_u8(vector, vdupq_n_u8(1));
+        dest.as_mut_ptr().write_uint8x16_at(1, incremented);
+    }
+
+    assert_eq!(dest[0], 0);
+    for i in 0..16 {
+        assert_eq!(dest[1 + i], source[3 + i] + 1);
+    }
+    assert_eq!(&dest[17..], &[0, 0, 0]);
+}
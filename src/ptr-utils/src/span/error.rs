@@ -0,0 +1,46 @@
+//! Errors returned by fallible [`Span`](super::Span) reads.
+
+use core::fmt;
+
+/// Why a read from a [`Span`](crate::Span) failed.
+///
+/// Distinguishes running out of data (which may succeed with more input) from data
+/// that is present but structurally invalid (which never will).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReadError {
+    /// The read extends past the end of the data.
+    Eof {
+        /// Byte offset the read started at.
+        offset: usize,
+        /// Number of bytes the read needed.
+        needed: usize,
+        /// Number of bytes available from `offset` to the end of the data.
+        available: usize,
+        /// Length of the whole data in bytes.
+        len: usize,
+    },
+    /// The bytes are in bounds, but do not represent a valid value.
+    Invalid {
+        /// Byte offset of the invalid value.
+        offset: usize,
+    },
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ReadError::Eof {
+                offset,
+                needed,
+                len,
+                ..
+            } => write!(
+                f,
+                "out of bounds: need {needed} bytes at offset {offset}, buffer is {len} bytes"
+            ),
+            ReadError::Invalid { offset } => write!(f, "invalid value at offset {offset}"),
+        }
+    }
+}
+
+impl core::error::Error for ReadError {}
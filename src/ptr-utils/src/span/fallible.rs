@@ -0,0 +1,69 @@
+//! Reads on [`Span`] that report why they failed.
+//!
+//! Only reads that can fail on bytes that are present (`bool`, `char`, UTF-8) return
+//! [`ReadError`]; the integer and float reads can only run out of data, so they keep
+//! returning [`Option`], and [`Span::check_range`] describes the shortfall when needed.
+
+use core::mem::size_of;
+use core::str;
+
+use super::{ReadError, Span};
+
+impl<'a> Span<'a> {
+    /// Returns `Ok(())` if `len` bytes starting at `byte_offset` lie within the span,
+    /// or [`ReadError::Eof`] describing the shortfall.
+    #[inline]
+    pub fn check_range(&self, byte_offset: usize, len: usize) -> Result<(), ReadError> {
+        if self.contains_range(byte_offset, len) {
+            return Ok(());
+        }
+        Err(ReadError::Eof {
+            offset: byte_offset,
+            needed: len,
+            available: self.bytes_available_at(byte_offset),
+            len: self.len(),
+        })
+    }
+
+    /// Reads a [`bool`] value at the given byte offset.
+    ///
+    /// Returns [`ReadError::Eof`] if out of bounds, or [`ReadError::Invalid`] if the
+    /// byte is not 0 or 1.
+    #[inline]
+    pub fn read_bool_at(&self, byte_offset: usize) -> Result<bool, ReadError> {
+        self.check_range(byte_offset, 1)?;
+        match self.as_bytes()[byte_offset] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ReadError::Invalid {
+                offset: byte_offset,
+            }),
+        }
+    }
+
+    /// Reads a [`char`] stored as a native endian [`u32`] code point at the given byte offset.
+    ///
+    /// Returns [`ReadError::Eof`] if out of bounds, or [`ReadError::Invalid`] if the
+    /// value is not a Unicode scalar value (a surrogate, or above `0x10FFFF`).
+    #[inline]
+    pub fn read_char_at(&self, byte_offset: usize) -> Result<char, ReadError> {
+        self.check_range(byte_offset, size_of::<u32>())?;
+        let code = self.read_u32_at(byte_offset).unwrap_or_default();
+        char::from_u32(code).ok_or(ReadError::Invalid {
+            offset: byte_offset,
+        })
+    }
+
+    /// Returns the `len` bytes starting at `byte_offset` as a UTF-8 string.
+    ///
+    /// Returns [`ReadError::Eof`] if out of bounds, or [`ReadError::Invalid`] at the
+    /// offset of the first invalid byte if the bytes are not valid UTF-8.
+    #[inline]
+    pub fn read_utf8_at(&self, byte_offset: usize, len: usize) -> Result<&'a str, ReadError> {
+        self.check_range(byte_offset, len)?;
+        let bytes = &self.as_bytes()[byte_offset..byte_offset + len];
+        str::from_utf8(bytes).map_err(|e| ReadError::Invalid {
+            offset: byte_offset + e.valid_up_to(),
+        })
+    }
+}
@@ -0,0 +1,18 @@
+//! Safe, bounds-checked views over byte regions.
+//!
+//! A [`Span`] carries a base pointer and length together, so the unaligned reads
+//! can be bounds-checked without threading lengths through every call.
+
+pub mod error;
+pub mod fallible;
+pub mod prefixed;
+pub mod read;
+pub mod typed;
+pub mod view;
+
+#[cfg(test)]
+mod tests;
+
+pub use error::ReadError;
+pub use prefixed::PrefixWidth;
+pub use view::Span;
@@ -0,0 +1,159 @@
+//! Length-prefixed regions within a [`Span`].
+
+use core::str;
+
+use super::{ReadError, Span};
+
+/// Width of the length prefix of a Pascal-style string.
+///
+/// Prefixes are read in native endianness, like the rest of the [`Span`] reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrefixWidth {
+    /// A 1-byte ([`u8`]) length prefix.
+    U8,
+    /// A 2-byte ([`u16`]) length prefix.
+    U16,
+    /// A 4-byte ([`u32`]) length prefix.
+    U32,
+}
+
+impl PrefixWidth {
+    /// Returns the size of the prefix in bytes.
+    #[inline(always)]
+    pub const fn size(self) -> usize {
+        match self {
+            PrefixWidth::U8 => 1,
+            PrefixWidth::U16 => 2,
+            PrefixWidth::U32 => 4,
+        }
+    }
+}
+
+impl<'a> Span<'a> {
+    /// Reads a [`u32`] length (native endianness) at the given byte offset and returns the
+    /// sub-span of that many bytes immediately following it.
+    ///
+    /// Returns [`None`] if the length field is out of bounds, or if the declared length
+    /// exceeds the bytes remaining in this span. The returned span can itself be parsed
+    /// with the same method, allowing recursive descent into nested structures.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::Span;
+    ///
+    /// let mut data = [0u8; 10];
+    /// data[..4].copy_from_slice(&3u32.to_ne_bytes());
+    /// data[4..7].copy_from_slice(b"abc");
+    ///
+    /// let span = Span::new(&data);
+    /// let inner = span.read_length_prefixed_at(0).unwrap();
+    /// assert_eq!(inner.as_bytes(), b"abc");
+    /// ```
+    #[inline]
+    pub fn read_length_prefixed_at(&self, byte_offset: usize) -> Option<Span<'a>> {
+        let len = self.read_u32_at(byte_offset)?;
+        self.sub_span(byte_offset + 4, usize::try_from(len).ok()?)
+    }
+
+    /// Reads a Pascal-style string: a length prefix of the given width at `byte_offset`,
+    /// followed by that many bytes, which are returned.
+    ///
+    /// Returns [`None`] if the prefix is out of bounds, or if the declared length exceeds
+    /// the bytes remaining in this span.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::span::PrefixWidth;
+    /// use ptr_utils::Span;
+    ///
+    /// let data = [5u8, b'h', b'e', b'l', b'l', b'o'];
+    /// let span = Span::new(&data);
+    /// assert_eq!(span.read_pstr_at(0, PrefixWidth::U8), Some(&b"hello"[..]));
+    /// ```
+    #[inline]
+    pub fn read_pstr_at(&self, byte_offset: usize, prefix_width: PrefixWidth) -> Option<&'a [u8]> {
+        let len = match prefix_width {
+            PrefixWidth::U8 => self.read_u8_at(byte_offset)? as usize,
+            PrefixWidth::U16 => self.read_u16_at(byte_offset)? as usize,
+            PrefixWidth::U32 => usize::try_from(self.read_u32_at(byte_offset)?).ok()?,
+        };
+        self.sub_span(byte_offset + prefix_width.size(), len)
+            .map(|span| span.as_bytes())
+    }
+
+    /// Reads a Pascal-style string as with [`Span::read_pstr_at`], additionally
+    /// requiring it to be valid UTF-8.
+    ///
+    /// Returns [`None`] if out of bounds or if the bytes are not valid UTF-8.
+    #[inline]
+    pub fn read_pstr_str_at(
+        &self,
+        byte_offset: usize,
+        prefix_width: PrefixWidth,
+    ) -> Option<&'a str> {
+        str::from_utf8(self.read_pstr_at(byte_offset, prefix_width)?).ok()
+    }
+
+    /// Reads a [`u32`] count (native endianness) at `count_offset`, then calls `f` with the
+    /// [`u32`] at `count_offset + 4 + i * item_stride` for each `i` in `0..count`.
+    ///
+    /// Returns the number of bytes the list occupies: the count, then `count * item_stride`
+    /// bytes, or up to the end of the last item if `item_stride` is narrower than an item.
+    /// The whole list is bounds-checked before `f` is first called, so on error `f` is
+    /// never called.
+    ///
+    /// Returns [`ReadError::Eof`] if the count, or the list it declares, extends past the
+    /// end of the span.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::Span;
+    ///
+    /// let mut data = [0u8; 16];
+    /// data[..4].copy_from_slice(&3u32.to_ne_bytes());
+    /// for x in 0..3 {
+    ///     data[4 + x * 4..8 + x * 4].copy_from_slice(&(x as u32 + 1).to_ne_bytes());
+    /// }
+    ///
+    /// let span = Span::new(&data);
+    /// let mut items = [0u32; 3];
+    /// let mut x = 0;
+    /// let consumed = span.read_prefixed_list_u32_at(0, 4, |item| {
+    ///     items[x] = item;
+    ///     x += 1;
+    /// });
+    /// assert_eq!(consumed, Ok(16));
+    /// assert_eq!(items, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn read_prefixed_list_u32_at<F: FnMut(u32)>(
+        &self,
+        count_offset: usize,
+        item_stride: usize,
+        mut f: F,
+    ) -> Result<usize, ReadError> {
+        self.check_range(count_offset, 4)?;
+        let count = self.read_u32_at(count_offset).unwrap_or_default() as usize;
+        let items_offset = count_offset + 4;
+
+        // The list spans `count` strides, and at least up to the end of the last item.
+        let list_len = match count.checked_mul(item_stride) {
+            Some(len) if count > 0 => (len - item_stride)
+                .checked_add(4)
+                .map_or(usize::MAX, |end| len.max(end)),
+            Some(len) => len,
+            None => usize::MAX,
+        };
+        self.check_range(items_offset, list_len)?;
+
+        for x in 0..count {
+            f(self
+                .read_u32_at(items_offset + x * item_stride)
+                .unwrap_or_default());
+        }
+        Ok(4 + list_len)
+    }
+}
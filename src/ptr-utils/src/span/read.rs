@@ -0,0 +1,95 @@
+//! Bounds-checked reads on [`Span`].
+
+use super::Span;
+
+impl Span<'_> {
+    // Unsigned integer types
+
+    /// Reads a [`u8`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_u8_at(&self, byte_offset: usize) -> Option<u8> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads a [`u16`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_u16_at(&self, byte_offset: usize) -> Option<u16> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads a [`u32`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_u32_at(&self, byte_offset: usize) -> Option<u32> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads a [`u64`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_u64_at(&self, byte_offset: usize) -> Option<u64> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads a [`u128`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_u128_at(&self, byte_offset: usize) -> Option<u128> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads a [`usize`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_usize_at(&self, byte_offset: usize) -> Option<usize> {
+        self.read_checked(byte_offset)
+    }
+
+    // Signed integer types
+
+    /// Reads an [`i8`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_i8_at(&self, byte_offset: usize) -> Option<i8> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads an [`i16`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_i16_at(&self, byte_offset: usize) -> Option<i16> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads an [`i32`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_i32_at(&self, byte_offset: usize) -> Option<i32> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads an [`i64`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_i64_at(&self, byte_offset: usize) -> Option<i64> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads an [`i128`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_i128_at(&self, byte_offset: usize) -> Option<i128> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads an [`isize`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_isize_at(&self, byte_offset: usize) -> Option<isize> {
+        self.read_checked(byte_offset)
+    }
+
+    // Floating point types
+
+    /// Reads an [`f32`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_f32_at(&self, byte_offset: usize) -> Option<f32> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads an [`f64`] value at the given byte offset, or [`None`] if out of bounds.
+    #[inline(always)]
+    pub fn read_f64_at(&self, byte_offset: usize) -> Option<f64> {
+        self.read_checked(byte_offset)
+    }
+}
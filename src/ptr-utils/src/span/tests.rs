@@ -0,0 +1,358 @@
+use super::*;
+use crate::UnalignedWrite;
+
+#[test]
+fn test_span_reads_in_bounds() {
+    let mut buffer = [0u8; 48];
+    unsafe {
+        let ptr = buffer.as_mut_ptr();
+        ptr.write_u8_at(0, 0x12);
+        ptr.write_u16_at(1, 0x1234);
+        ptr.write_u32_at(3, 0x12345678);
+        ptr.write_u64_at(7, 0x123456789ABCDEF0);
+        ptr.write_u128_at(15, 0x123456789ABCDEF0FEDCBA0987654321);
+        ptr.write_i32_at(31, -12345678);
+        ptr.write_f64_at(35, -2.5);
+        ptr.write_bool_at(43, true);
+    }
+
+    let span = Span::new(&buffer);
+    assert_eq!(span.len(), 48);
+    assert_eq!(span.read_u8_at(0), Some(0x12));
+    assert_eq!(span.read_u16_at(1), Some(0x1234));
+    assert_eq!(span.read_u32_at(3), Some(0x12345678));
+    assert_eq!(span.read_u64_at(7), Some(0x123456789ABCDEF0));
+    assert_eq!(
+        span.read_u128_at(15),
+        Some(0x123456789ABCDEF0FEDCBA0987654321)
+    );
+    assert_eq!(span.read_i32_at(31), Some(-12345678));
+    assert_eq!(span.read_f64_at(35), Some(-2.5));
+    assert_eq!(span.read_bool_at(43), Ok(true));
+}
+
+#[test]
+fn test_span_reads_out_of_bounds() {
+    let buffer = [2u8; 8];
+    let span = Span::from(&buffer[..]);
+
+    assert_eq!(span.read_u64_at(0), Some(u64::from_ne_bytes([2; 8])));
+    assert_eq!(span.read_u64_at(1), None);
+    assert_eq!(span.read_u8_at(8), None);
+    assert_eq!(span.read_u16_at(usize::MAX), None);
+    assert_eq!(span.read_array_at::<4>(4), Some([2; 4]));
+    assert_eq!(span.read_array_at::<4>(5), None);
+
+    // Invalid bool byte
+    assert_eq!(span.read_bool_at(0), Err(ReadError::Invalid { offset: 0 }));
+}
+
+#[test]
+fn test_span_sub_span() {
+    let buffer = [0u8, 1, 2, 3, 4, 5, 6, 7];
+    let span = Span::new(&buffer);
+
+    let sub = span.sub_span(2, 4).unwrap();
+    assert_eq!(sub.as_bytes(), &[2, 3, 4, 5]);
+    assert_eq!(sub.read_u8_at(3), Some(5));
+    assert_eq!(sub.read_u8_at(4), None);
+    assert!(span.sub_span(5, 4).is_none());
+    assert!(span.sub_span(8, 0).unwrap().is_empty());
+}
+
+#[test]
+fn test_span_length_prefixed() {
+    // outer: len=10 [ inner: len=2 [0xAA, 0xBB], 0xCC, 0xDD, 0xEE, 0xFF ]
+    let mut data = [0u8; 16];
+    unsafe {
+        let ptr = data.as_mut_ptr();
+        ptr.write_u32_at(0, 10);
+        ptr.write_u32_at(4, 2);
+        ptr.write_u16_at(8, u16::from_ne_bytes([0xAA, 0xBB]));
+        ptr.write_u32_at(10, u32::from_ne_bytes([0xCC, 0xDD, 0xEE, 0xFF]));
+        ptr.write_u16_at(14, 0xFFFF);
+    }
+
+    let span = Span::new(&data);
+    let outer = span.read_length_prefixed_at(0).unwrap();
+    assert_eq!(outer.len(), 10);
+    let inner = outer.read_length_prefixed_at(0).unwrap();
+    assert_eq!(inner.as_bytes(), &[0xAA, 0xBB]);
+
+    // Declared length fits within the remaining bytes
+    assert!(span.read_length_prefixed_at(4).is_some());
+    // Declared length exceeds remaining bytes, by one and by far
+    assert!(span
+        .sub_span(0, 13)
+        .unwrap()
+        .read_length_prefixed_at(0)
+        .is_none());
+    assert!(outer.read_length_prefixed_at(6).is_none());
+    // Length field itself out of bounds
+    assert!(span.read_length_prefixed_at(13).is_none());
+}
+
+#[test]
+fn test_span_introspection() {
+    let buffer = [0u8; 10];
+    let span = Span::new(&buffer);
+
+    assert_eq!(span.bytes_available_at(0), 10);
+    assert_eq!(span.bytes_available_at(7), 3);
+    assert_eq!(span.bytes_available_at(10), 0);
+    assert_eq!(span.bytes_available_at(usize::MAX), 0);
+
+    assert!(span.can_read::<u64>(2));
+    assert!(!span.can_read::<u64>(3));
+    assert!(span.can_read::<u8>(9));
+    assert!(!span.can_read::<u8>(10));
+    assert!(!span.can_read::<u16>(usize::MAX));
+}
+
+#[test]
+fn test_read_error_eof_vs_invalid() {
+    let data = [1u8, 2, 0x00, 0xD8, 0x00, 0x00];
+    let span = Span::new(&data);
+
+    assert_eq!(span.read_bool_at(0), Ok(true));
+    assert_eq!(span.read_bool_at(1), Err(ReadError::Invalid { offset: 1 }));
+    assert_eq!(
+        span.read_bool_at(6),
+        Err(ReadError::Eof {
+            offset: 6,
+            needed: 1,
+            available: 0,
+            len: 6
+        })
+    );
+
+    // 0xD800 is a surrogate, not a valid `char`.
+    let surrogate = 0xD800u32.to_ne_bytes();
+    assert_eq!(
+        Span::new(&surrogate).read_char_at(0),
+        Err(ReadError::Invalid { offset: 0 })
+    );
+    let a = ('a' as u32).to_ne_bytes();
+    assert_eq!(Span::new(&a).read_char_at(0), Ok('a'));
+    assert_eq!(
+        span.read_char_at(4),
+        Err(ReadError::Eof {
+            offset: 4,
+            needed: 4,
+            available: 2,
+            len: 6
+        })
+    );
+}
+
+#[test]
+fn test_read_utf8() {
+    let data = *b"hi\xFFok";
+    let span = Span::new(&data);
+
+    assert_eq!(span.read_utf8_at(0, 2), Ok("hi"));
+    assert_eq!(span.read_utf8_at(3, 2), Ok("ok"));
+    assert_eq!(
+        span.read_utf8_at(1, 3),
+        Err(ReadError::Invalid { offset: 2 })
+    );
+    assert_eq!(
+        span.read_utf8_at(3, 3),
+        Err(ReadError::Eof {
+            offset: 3,
+            needed: 3,
+            available: 2,
+            len: 5
+        })
+    );
+}
+
+#[test]
+fn test_read_pstr() {
+    let mut data = [0u8; 16];
+    data[0] = 2;
+    data[1..3].copy_from_slice(b"ab");
+    data[3..5].copy_from_slice(&3u16.to_ne_bytes());
+    data[5..8].copy_from_slice(b"cde");
+    data[8..12].copy_from_slice(&4u32.to_ne_bytes());
+    data[12..16].copy_from_slice(b"fghi");
+    let span = Span::new(&data);
+
+    assert_eq!(span.read_pstr_at(0, PrefixWidth::U8), Some(&b"ab"[..]));
+    assert_eq!(span.read_pstr_at(3, PrefixWidth::U16), Some(&b"cde"[..]));
+    assert_eq!(span.read_pstr_at(8, PrefixWidth::U32), Some(&b"fghi"[..]));
+    assert_eq!(span.read_pstr_str_at(8, PrefixWidth::U32), Some("fghi"));
+
+    // Declared length runs past the end of the span.
+    let truncated = span.sub_span(8, 7).unwrap();
+    assert_eq!(truncated.read_pstr_at(0, PrefixWidth::U32), None);
+    // Prefix itself is out of bounds.
+    assert_eq!(span.read_pstr_at(15, PrefixWidth::U16), None);
+}
+
+#[test]
+fn test_read_pstr_invalid_utf8() {
+    let data = [2u8, 0xFF, 0xFE];
+    let span = Span::new(&data);
+
+    assert_eq!(
+        span.read_pstr_at(0, PrefixWidth::U8),
+        Some(&[0xFF, 0xFE][..])
+    );
+    assert_eq!(span.read_pstr_str_at(0, PrefixWidth::U8), None);
+}
+
+#[test]
+fn test_iter_refs_and_copied() {
+    let values = [10u32, 20, 30, 40];
+    // SAFETY: `u32` has no padding, so its bytes may be viewed as `u8`.
+    let bytes: &[u8] = unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), 16) };
+    let span = Span::new(bytes);
+
+    let refs: [&u32; 3] = {
+        let mut iter = span.iter_refs::<u32>(4, 3).unwrap();
+        [
+            iter.next().unwrap(),
+            iter.next().unwrap(),
+            iter.next().unwrap(),
+        ]
+    };
+    assert_eq!(refs, [&20, &30, &40]);
+    assert!(core::ptr::eq(refs[0], &values[1]));
+
+    // Misaligned and out of bounds.
+    assert!(span.iter_refs::<u32>(2, 1).is_none());
+    assert!(span.iter_refs::<u32>(4, 4).is_none());
+
+    // Copying works at any offset.
+    let copied = span.iter_copied::<u16>(1, 2).unwrap();
+    assert_eq!(copied.len(), 2);
+    assert!(span.iter_copied::<u32>(1, 4).is_none());
+    assert_eq!(
+        span.iter_copied::<[u32; 2]>(0, 2).unwrap().next_back(),
+        Some([30, 40])
+    );
+}
+
+#[test]
+fn test_as_slice_of() {
+    let values = [1u64, 2, 3];
+    // SAFETY: `u64` has no padding, so its bytes may be viewed as `u8`.
+    let bytes: &[u8] = unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), 24) };
+
+    let slice = Span::new(bytes).as_slice_of::<u64>().unwrap();
+    assert_eq!(slice, &values);
+    assert!(core::ptr::eq(slice.as_ptr(), values.as_ptr()));
+
+    // The same bytes viewed at a narrower width.
+    assert_eq!(Span::new(bytes).as_slice_of::<u32>().unwrap().len(), 6);
+    assert_eq!(Span::new(bytes).as_slice_of::<u8>(), Some(bytes));
+
+    // Ragged length and misaligned start.
+    assert!(Span::new(&bytes[..20]).as_slice_of::<u64>().is_none());
+    assert!(Span::new(&bytes[4..20]).as_slice_of::<u64>().is_none());
+
+    // An empty span is an empty slice.
+    assert_eq!(Span::new(&bytes[..0]).as_slice_of::<u64>(), Some(&[][..]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_read_error_display() {
+    use std::string::ToString;
+
+    let error = ReadError::Eof {
+        offset: 12,
+        needed: 4,
+        available: 2,
+        len: 14,
+    };
+    assert_eq!(
+        error.to_string(),
+        "out of bounds: need 4 bytes at offset 12, buffer is 14 bytes"
+    );
+    // An offset past the end still reports the real length.
+    let error = ReadError::Eof {
+        offset: 20,
+        needed: 4,
+        available: 0,
+        len: 14,
+    };
+    assert_eq!(
+        error.to_string(),
+        "out of bounds: need 4 bytes at offset 20, buffer is 14 bytes"
+    );
+    assert_eq!(
+        ReadError::Invalid { offset: 3 }.to_string(),
+        "invalid value at offset 3"
+    );
+}
+
+#[test]
+fn test_span_read_prefixed_list_u32_at() {
+    let mut data = [0u8; 16];
+    data[..4].copy_from_slice(&3u32.to_ne_bytes());
+    for x in 0..3 {
+        data[4 + x * 4..8 + x * 4].copy_from_slice(&(7 * x as u32).to_ne_bytes());
+    }
+    let span = Span::new(&data);
+
+    let mut sum = 0;
+    assert_eq!(
+        span.read_prefixed_list_u32_at(0, 4, |item| sum += item),
+        Ok(16)
+    );
+    assert_eq!(sum, 21);
+
+    // The count claims more items than the span holds; `f` is never called.
+    let short = span.sub_span(0, 15).unwrap();
+    assert_eq!(
+        short.read_prefixed_list_u32_at(0, 4, |_| unreachable!()),
+        Err(ReadError::Eof {
+            offset: 4,
+            needed: 12,
+            available: 11,
+            len: 15,
+        })
+    );
+    assert_eq!(
+        span.read_prefixed_list_u32_at(14, 4, |_| unreachable!()),
+        Err(ReadError::Eof {
+            offset: 14,
+            needed: 4,
+            available: 2,
+            len: 16,
+        })
+    );
+
+    // A stride narrower than an item still requires the last item in bounds, and counts
+    // it in the bytes consumed.
+    let mut calls = 0;
+    assert_eq!(span.read_prefixed_list_u32_at(0, 2, |_| calls += 1), Ok(12));
+    assert_eq!(calls, 3);
+    let short = span.sub_span(0, 11).unwrap();
+    assert!(short
+        .read_prefixed_list_u32_at(0, 2, |_| unreachable!())
+        .is_err());
+    data[..4].copy_from_slice(&u32::MAX.to_ne_bytes());
+    let span = Span::new(&data);
+    assert!(span
+        .read_prefixed_list_u32_at(0, usize::MAX, |_| unreachable!())
+        .is_err());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_cast_slice_at() {
+    let values = [10u32, 20, 30, 40];
+    let span = Span::new(bytemuck::cast_slice(&values));
+
+    assert_eq!(span.cast_slice_at::<u32>(0, 4), Some(&values[..]));
+    assert_eq!(span.cast_slice_at::<u32>(8, 2), Some(&values[2..]));
+    assert_eq!(span.cast_slice_at::<u32>(16, 0), Some(&[][..]));
+    let halves: &[u16] = bytemuck::cast_slice(&values[1..2]);
+    assert_eq!(span.cast_slice_at::<u16>(4, 2), Some(halves));
+    assert!(span.cast_slice_at::<u32>(8, 3).is_none());
+    assert!(span.cast_slice_at::<u32>(2, 1).is_none());
+    assert!(span.cast_slice_at::<u32>(4, usize::MAX).is_none());
+}
@@ -0,0 +1,130 @@
+//! Iteration over arrays of [`Pod`] values within a [`Span`].
+
+use core::mem::size_of;
+use core::slice;
+
+use super::Span;
+use crate::{Pod, ReadIter};
+
+impl<'a> Span<'a> {
+    /// Returns an iterator of zero-copy references to `count` consecutive `T` values
+    /// starting at `byte_offset`.
+    ///
+    /// References require alignment, so this returns [`None`] unless the address at
+    /// `byte_offset` is aligned for `T` (as well as when the range is out of bounds).
+    /// Whether a given offset is aligned depends on where the underlying buffer was
+    /// allocated; for data with no alignment guarantee, use [`Span::iter_copied`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::Span;
+    ///
+    /// let values = [1u32, 2, 3];
+    /// let bytes: &[u8] = unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), 12) };
+    /// let span = Span::new(bytes);
+    ///
+    /// let sum: u32 = span.iter_refs::<u32>(4, 2).unwrap().sum();
+    /// assert_eq!(sum, 5);
+    /// assert!(span.iter_refs::<u32>(1, 2).is_none()); // misaligned
+    /// ```
+    #[inline]
+    pub fn iter_refs<T: Pod>(
+        &self,
+        byte_offset: usize,
+        count: usize,
+    ) -> Option<slice::Iter<'a, T>> {
+        let len = count.checked_mul(size_of::<T>())?;
+        let bytes = self.sub_span(byte_offset, len)?.as_bytes();
+        if !bytes.as_ptr().cast::<T>().is_aligned() {
+            return None;
+        }
+        // SAFETY: the range is in bounds and aligned, and `T: Pod` is valid for any bytes.
+        // The bytes are borrowed immutably for `'a`.
+        Some(unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<T>(), count) }.iter())
+    }
+
+    /// Returns an iterator copying `count` consecutive `T` values starting at `byte_offset`,
+    /// or [`None`] if the range is out of bounds.
+    ///
+    /// Unlike [`Span::iter_refs`], this has no alignment requirement. The iterator borrows
+    /// the span's bytes, so it cannot outlive the buffer:
+    ///
+    /// ```compile_fail
+    /// use ptr_utils::Span;
+    ///
+    /// fn dangling() -> impl Iterator<Item = u32> {
+    ///     let data = vec![0u8; 8];
+    ///     Span::new(&data).iter_copied::<u32>(0, 2).unwrap()
+    /// }
+    /// ```
+    #[inline]
+    pub fn iter_copied<T: Pod>(&self, byte_offset: usize, count: usize) -> Option<ReadIter<'a, T>> {
+        let len = count.checked_mul(size_of::<T>())?;
+        let bytes = self.sub_span(byte_offset, len)?.as_bytes();
+        // SAFETY: the range is in bounds, and `T: Pod` is valid for any bytes. The bytes
+        // are borrowed immutably for `'a`, which bounds the iterator.
+        Some(unsafe { ReadIter::new(bytes.as_ptr(), count) })
+    }
+
+    /// Returns the whole span reinterpreted as a zero-copy `&[T]`.
+    ///
+    /// Returns [`None`] unless the span's length is a multiple of `size_of::<T>()` and
+    /// its start is aligned for `T`. Unaligned or ragged buffers should be read with
+    /// [`Span::iter_copied`] instead, which copies each value out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::Span;
+    ///
+    /// let values = [10u32, 20, 30];
+    /// let bytes: &[u8] = unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), 12) };
+    ///
+    /// assert_eq!(Span::new(bytes).as_slice_of::<u32>(), Some(&values[..]));
+    /// assert!(Span::new(&bytes[..10]).as_slice_of::<u32>().is_none()); // ragged
+    /// assert!(Span::new(&bytes[1..9]).as_slice_of::<u32>().is_none()); // misaligned
+    /// ```
+    #[inline]
+    pub fn as_slice_of<T: Pod>(&self) -> Option<&'a [T]> {
+        let bytes = self.as_bytes();
+        let size = size_of::<T>();
+        if size == 0 || !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+        if !bytes.as_ptr().cast::<T>().is_aligned() {
+            return None;
+        }
+        // SAFETY: the whole span is covered exactly and aligned, and `T: Pod` is valid for
+        // any bytes. The bytes are borrowed immutably for `'a`.
+        Some(unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<T>(), bytes.len() / size) })
+    }
+
+    /// Returns `count` consecutive `T` values starting at `byte_offset` as a zero-copy
+    /// `&[T]`, cast with [`bytemuck::try_cast_slice`].
+    ///
+    /// Returns [`None`] if the range is out of bounds or its start is not aligned for `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ptr_utils::Span;
+    ///
+    /// let values = [1u16, 2, 3, 4];
+    /// let span = Span::new(bytemuck::cast_slice(&values));
+    ///
+    /// assert_eq!(span.cast_slice_at::<u16>(2, 3), Some(&values[1..]));
+    /// assert!(span.cast_slice_at::<u16>(4, 3).is_none()); // out of bounds
+    /// assert!(span.cast_slice_at::<u16>(1, 2).is_none()); // misaligned
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn cast_slice_at<T: bytemuck::Pod>(
+        &self,
+        byte_offset: usize,
+        count: usize,
+    ) -> Option<&'a [T]> {
+        let len = count.checked_mul(size_of::<T>())?;
+        bytemuck::try_cast_slice(self.sub_span(byte_offset, len)?.as_bytes()).ok()
+    }
+}
@@ -0,0 +1,144 @@
+//! The [`Span`] type and its constructors.
+
+use core::mem::size_of;
+use core::slice;
+
+use crate::unaligned::raw::read_raw;
+
+/// A borrowed, random-access view over a byte region with a known length.
+///
+/// All reads on a [`Span`] are bounds-checked and safe; out-of-bounds reads
+/// return [`None`] instead of causing undefined behaviour.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::Span;
+///
+/// let data = [0x78u8, 0x56, 0x34, 0x12, 0xFF];
+/// let span = Span::new(&data);
+///
+/// assert_eq!(span.read_u32_at(1), Some(u32::from_ne_bytes([0x56, 0x34, 0x12, 0xFF])));
+/// assert_eq!(span.read_u32_at(2), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Span<'a> {
+    /// Creates a [`Span`] over the given byte slice.
+    #[inline(always)]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Creates a [`Span`] from a raw pointer and length.
+    ///
+    /// # Safety
+    /// - The pointer must be valid for reading `len` bytes for the lifetime `'a`
+    /// - The memory must not be mutated for the lifetime `'a`
+    /// - `len` must be no larger than `isize::MAX`
+    #[inline(always)]
+    pub unsafe fn from_raw_parts(ptr: *const u8, len: usize) -> Self {
+        Self {
+            bytes: slice::from_raw_parts(ptr, len),
+        }
+    }
+
+    /// Returns the length of the span in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the span contains no bytes.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the base pointer of the span.
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *const u8 {
+        self.bytes.as_ptr()
+    }
+
+    /// Returns the bytes covered by the span.
+    #[inline(always)]
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns `true` if `len` bytes starting at `byte_offset` lie within the span.
+    #[inline(always)]
+    pub const fn contains_range(&self, byte_offset: usize, len: usize) -> bool {
+        match byte_offset.checked_add(len) {
+            Some(end) => end <= self.bytes.len(),
+            None => false,
+        }
+    }
+
+    /// Returns the number of bytes available from `byte_offset` to the end of the span.
+    ///
+    /// Returns 0 if the offset is at or past the end.
+    #[inline(always)]
+    pub const fn bytes_available_at(&self, byte_offset: usize) -> usize {
+        self.bytes.len().saturating_sub(byte_offset)
+    }
+
+    /// Returns `true` if a `T` can be read at `byte_offset` without going out of bounds.
+    #[inline(always)]
+    pub const fn can_read<T>(&self, byte_offset: usize) -> bool {
+        self.contains_range(byte_offset, size_of::<T>())
+    }
+
+    /// Returns a sub-span of `len` bytes starting at `byte_offset`,
+    /// or [`None`] if the range is out of bounds.
+    #[inline]
+    pub fn sub_span(&self, byte_offset: usize, len: usize) -> Option<Span<'a>> {
+        if !self.contains_range(byte_offset, len) {
+            return None;
+        }
+        Some(Span::new(&self.bytes[byte_offset..byte_offset + len]))
+    }
+
+    /// Returns the `N` bytes starting at `byte_offset`, or [`None`] if out of bounds.
+    #[inline]
+    pub fn read_array_at<const N: usize>(&self, byte_offset: usize) -> Option<[u8; N]> {
+        self.read_checked(byte_offset)
+    }
+
+    /// Reads a `V` at `byte_offset` after checking the read lies within the span.
+    ///
+    /// Only instantiated for types valid for every bit pattern.
+    #[inline(always)]
+    pub(crate) fn read_checked<V: Copy>(&self, byte_offset: usize) -> Option<V> {
+        if !self.contains_range(byte_offset, size_of::<V>()) {
+            return None;
+        }
+        // SAFETY: the range was bounds-checked above.
+        Some(unsafe { read_raw(self.bytes.as_ptr(), byte_offset) })
+    }
+}
+
+impl<'a> From<&'a [u8]> for Span<'a> {
+    #[inline(always)]
+    fn from(bytes: &'a [u8]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [u8; N]> for Span<'a> {
+    #[inline(always)]
+    fn from(bytes: &'a [u8; N]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl<'a> From<Span<'a>> for &'a [u8] {
+    #[inline(always)]
+    fn from(span: Span<'a>) -> Self {
+        span.bytes
+    }
+}
@@ -0,0 +1,11 @@
+//! Reads through pointers that carry tag bits in their address.
+//!
+//! Garbage collectors and interpreters commonly stash type or state tags in the low
+//! (alignment) bits of a pointer; these must be masked off before dereferencing.
+
+pub mod read;
+
+#[cfg(test)]
+mod tests;
+
+pub use read::TaggedRead;
@@ -0,0 +1,220 @@
+//! Reads through tagged pointers.
+
+use crate::{AsBytePtr, UnalignedRead};
+
+/// Trait providing reads through pointers carrying tag bits in their address.
+///
+/// Each method clears `tag_mask` from the pointer's address, then reads at `byte_offset`
+/// from the untagged address.
+///
+/// # Provenance
+///
+/// The tag is removed with `map_addr`, which keeps the provenance of the
+/// original pointer. The untagged address must therefore still lie within the allocation
+/// the tagged pointer was derived from. This holds for the usual scheme of storing tags in
+/// alignment bits (e.g. the low 3 bits of an 8-byte aligned pointer), but not for
+/// addresses reconstructed from unrelated integers.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::TaggedRead;
+///
+/// let value = 0x12345678u32.to_ne_bytes();
+/// let aligned = [value, value];
+/// let tagged = aligned.as_ptr().cast::<u8>().map_addr(|addr| addr | 0b1);
+///
+/// unsafe {
+///     assert_eq!(tagged.read_u32_tagged_at(0b11, 4), 0x12345678);
+/// }
+/// ```
+pub trait TaggedRead: UnalignedRead + AsBytePtr + Copy {
+    /// Clears `tag_mask` from the pointer's address and reads a `T` at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading `size_of::<T>()` bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    /// - The bytes read must represent a valid `T`
+    #[inline(always)]
+    unsafe fn read_tagged_at<T: Copy>(self, tag_mask: usize, byte_offset: usize) -> T {
+        self.as_byte_ptr()
+            .map_addr(|addr| addr & !tag_mask)
+            .read_at(byte_offset)
+    }
+
+    // Unsigned integer types
+
+    /// Clears `tag_mask` from the pointer's address and reads a [`u8`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 1 byte
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_u8_tagged_at(self, tag_mask: usize, byte_offset: usize) -> u8 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads a [`u16`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 2 bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u16_tagged_at(self, tag_mask: usize, byte_offset: usize) -> u16 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads a [`u32`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 4 bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u32_tagged_at(self, tag_mask: usize, byte_offset: usize) -> u32 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads a [`u64`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 8 bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u64_tagged_at(self, tag_mask: usize, byte_offset: usize) -> u64 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads a [`u128`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 16 bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_u128_tagged_at(self, tag_mask: usize, byte_offset: usize) -> u128 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads a [`usize`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading [`size_of::<usize>()`] bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_usize_tagged_at(self, tag_mask: usize, byte_offset: usize) -> usize {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    // Signed integer types
+
+    /// Clears `tag_mask` from the pointer's address and reads an [`i8`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 1 byte
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    unsafe fn read_i8_tagged_at(self, tag_mask: usize, byte_offset: usize) -> i8 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads an [`i16`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 2 bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i16_tagged_at(self, tag_mask: usize, byte_offset: usize) -> i16 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads an [`i32`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 4 bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i32_tagged_at(self, tag_mask: usize, byte_offset: usize) -> i32 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads an [`i64`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 8 bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i64_tagged_at(self, tag_mask: usize, byte_offset: usize) -> i64 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads an [`i128`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 16 bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_i128_tagged_at(self, tag_mask: usize, byte_offset: usize) -> i128 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads an [`isize`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading [`size_of::<isize>()`] bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_isize_tagged_at(self, tag_mask: usize, byte_offset: usize) -> isize {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    // Floating point types
+
+    /// Clears `tag_mask` from the pointer's address and reads an [`f32`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 4 bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f32_tagged_at(self, tag_mask: usize, byte_offset: usize) -> f32 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+
+    /// Clears `tag_mask` from the pointer's address and reads an [`f64`] at the given byte offset.
+    ///
+    /// # Safety
+    /// - The untagged pointer plus byte offset must be valid for reading 8 bytes
+    /// - The untagged address must lie within the allocation the pointer was derived from
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_f64_tagged_at(self, tag_mask: usize, byte_offset: usize) -> f64 {
+        self.read_tagged_at(tag_mask, byte_offset)
+    }
+}
+
+impl<P: UnalignedRead + AsBytePtr + Copy> TaggedRead for P {}
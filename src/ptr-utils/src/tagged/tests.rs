@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn test_tagged_reads() {
+    #[repr(C, align(8))]
+    struct Object {
+        header: u64,
+        fields: [u8; 8],
+    }
+
+    let mut object = Object {
+        header: 0xDEAD_BEEF_0000_0001,
+        fields: [0; 8],
+    };
+    object.fields[1..5].copy_from_slice(&0x12345678u32.to_ne_bytes());
+
+    let ptr = &object as *const Object;
+    for tag in 0..8usize {
+        let tagged = ptr.map_addr(|addr| addr | tag);
+        unsafe {
+            assert_eq!(tagged.read_u64_tagged_at(0b111, 0), 0xDEAD_BEEF_0000_0001);
+            assert_eq!(tagged.read_u32_tagged_at(0b111, 9), 0x12345678);
+            assert_eq!(
+                tagged.read_tagged_at::<[u8; 2]>(0b111, 8),
+                [0, 0x12345678u32.to_ne_bytes()[0]]
+            );
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! MS-DOS packed date and time reads, as used by FAT and ZIP.
+
+use crate::{Endian, EndianRead, UnalignedRead};
+
+/// The earliest representable MS-DOS year; stored years are offsets from it.
+pub const DOS_EPOCH_YEAR: u16 = 1980;
+
+/// Trait providing reads of MS-DOS packed date-time fields.
+///
+/// The field is a little-endian 16-bit time followed by a little-endian 16-bit date, the
+/// order used by ZIP headers and FAT directory entries:
+///
+/// | Word | Bits    | Meaning                |
+/// |------|---------|------------------------|
+/// | time | `15-11` | hour (0-23)            |
+/// | time | `10-5`  | minute (0-59)          |
+/// | time | `4-0`   | second / 2 (0-29)      |
+/// | date | `15-9`  | year - 1980 (0-127)    |
+/// | date | `8-5`   | month (1-12)           |
+/// | date | `4-0`   | day (1-31)             |
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::DosTimeRead;
+///
+/// // 2024-03-15 13:45:30
+/// let time: u16 = (13 << 11) | (45 << 5) | (30 / 2);
+/// let date: u16 = ((2024 - 1980) << 9) | (3 << 5) | 15;
+/// let mut field = [0u8; 4];
+/// field[..2].copy_from_slice(&time.to_le_bytes());
+/// field[2..].copy_from_slice(&date.to_le_bytes());
+///
+/// let datetime = unsafe { field.as_ptr().read_dos_datetime_at(0) };
+/// assert_eq!(datetime, (2024, 3, 15, 13, 45, 30));
+/// ```
+pub trait DosTimeRead: UnalignedRead + Copy {
+    /// Reads an MS-DOS packed time and date at the given byte offset, returning
+    /// `(year, month, day, hour, minute, second)`.
+    ///
+    /// The year is the full year (`1980..=2107`). DOS stores seconds halved, so `second`
+    /// is always even. Fields are returned as stored, without validation: a zeroed field
+    /// decodes to month 0 and day 0.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline]
+    unsafe fn read_dos_datetime_at(self, byte_offset: usize) -> (u16, u8, u8, u8, u8, u8) {
+        let time = self.read_u16_endian_at(byte_offset, Endian::Little);
+        let date = self.read_u16_endian_at(byte_offset + 2, Endian::Little);
+        (
+            DOS_EPOCH_YEAR + (date >> 9),
+            ((date >> 5) & 0x0F) as u8,
+            (date & 0x1F) as u8,
+            (time >> 11) as u8,
+            ((time >> 5) & 0x3F) as u8,
+            ((time & 0x1F) * 2) as u8,
+        )
+    }
+}
+
+impl<P: UnalignedRead + Copy> DosTimeRead for P {}
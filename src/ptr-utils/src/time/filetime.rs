@@ -0,0 +1,80 @@
+//! Windows `FILETIME` timestamp reads.
+
+use crate::UnalignedRead;
+
+/// Seconds between the `FILETIME` epoch (`1601-01-01`) and the Unix epoch (`1970-01-01`).
+pub const FILETIME_UNIX_EPOCH_OFFSET_SECS: i64 = 11_644_473_600;
+
+/// Number of `FILETIME` 100-nanosecond intervals per second.
+pub const FILETIME_INTERVALS_PER_SEC: i64 = 10_000_000;
+
+/// Trait providing reads of Windows `FILETIME` timestamps (100-nanosecond intervals since
+/// `1601-01-01 00:00:00 UTC`), as found in NTFS, the registry and PE files.
+///
+/// `FILETIME` is always stored little-endian, so unlike [`UnalignedRead`], these reads are
+/// little-endian on every platform.
+pub trait FileTimeRead: UnalignedRead + Copy {
+    /// Reads a `FILETIME` from the pointer at the given byte offset, returning the raw
+    /// number of 100-nanosecond intervals since `1601-01-01 00:00:00 UTC`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_filetime_at(self, byte_offset: usize) -> i64 {
+        i64::from_le(self.read_i64_at(byte_offset))
+    }
+
+    /// Reads a `FILETIME` from the pointer at the given byte offset, converted to whole
+    /// seconds since the Unix epoch (rounded toward negative infinity).
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_filetime_unix_secs_at(self, byte_offset: usize) -> i64 {
+        self.read_filetime_at(byte_offset)
+            .div_euclid(FILETIME_INTERVALS_PER_SEC)
+            - FILETIME_UNIX_EPOCH_OFFSET_SECS
+    }
+
+    /// Reads a `FILETIME` from the pointer at the given byte offset as a
+    /// [`SystemTime`](std::time::SystemTime), preserving its 100-nanosecond precision.
+    ///
+    /// Returns [`None`] if the time is outside the range [`SystemTime`](std::time::SystemTime)
+    /// can represent on this platform (e.g. before 1601 on Windows).
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[cfg(feature = "std")]
+    #[inline]
+    unsafe fn read_filetime_system_time_at(
+        self,
+        byte_offset: usize,
+    ) -> Option<std::time::SystemTime> {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        // Relative to the Unix epoch, in 100ns intervals. Widened, as negative FILETIMEs
+        // near `i64::MIN` would overflow an `i64`.
+        let intervals = self.read_filetime_at(byte_offset) as i128
+            - (FILETIME_UNIX_EPOCH_OFFSET_SECS * FILETIME_INTERVALS_PER_SEC) as i128;
+        let magnitude = intervals.unsigned_abs();
+        let interval = FILETIME_INTERVALS_PER_SEC as u128;
+        let duration = Duration::new(
+            (magnitude / interval) as u64,
+            (magnitude % interval) as u32 * 100,
+        );
+
+        if intervals >= 0 {
+            UNIX_EPOCH.checked_add(duration)
+        } else {
+            UNIX_EPOCH.checked_sub(duration)
+        }
+    }
+}
+
+impl<P: UnalignedRead + Copy> FileTimeRead for P {}
@@ -0,0 +1,15 @@
+//! Timestamp reads for common binary timestamp encodings.
+//!
+//! Timestamps are stored in a variety of widths and epochs; this module centralizes
+//! the width handling and sign extension so callers don't have to repeat it.
+
+pub mod dos;
+pub mod filetime;
+pub mod unix;
+
+#[cfg(test)]
+mod tests;
+
+pub use dos::{DosTimeRead, DOS_EPOCH_YEAR};
+pub use filetime::{FileTimeRead, FILETIME_INTERVALS_PER_SEC, FILETIME_UNIX_EPOCH_OFFSET_SECS};
+pub use unix::UnixTimeRead;
@@ -0,0 +1,139 @@
+use super::*;
+use crate::UnalignedWrite;
+
+#[test]
+fn test_unix_secs() {
+    unsafe {
+        let mut buffer = [0u8; 16];
+        let ptr = buffer.as_mut_ptr();
+
+        ptr.write_i64_at(1, 1_700_000_000);
+        ptr.write_i32_at(9, i32::MAX);
+
+        assert_eq!(ptr.read_unix_secs_at(1), 1_700_000_000);
+        assert_eq!(ptr.read_unix_secs32_at(9), i32::MAX as i64);
+    }
+}
+
+#[test]
+fn test_unix_secs_pre_epoch() {
+    unsafe {
+        let mut buffer = [0u8; 16];
+        let ptr = buffer.as_mut_ptr();
+
+        // 1969-07-20 20:17:40 UTC (Apollo 11 landing)
+        ptr.write_i64_at(0, -14_182_940);
+        ptr.write_i32_at(8, -14_182_940);
+
+        assert_eq!(ptr.read_unix_secs_at(0), -14_182_940);
+        assert_eq!(ptr.read_unix_secs32_at(8), -14_182_940);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_unix_system_time() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    unsafe {
+        let mut buffer = [0u8; 16];
+        let ptr = buffer.as_mut_ptr();
+
+        ptr.write_i64_at(0, 86_400);
+        ptr.write_i32_at(8, -86_400);
+
+        assert_eq!(
+            ptr.read_unix_system_time_at(0),
+            Some(UNIX_EPOCH + Duration::from_secs(86_400))
+        );
+        assert_eq!(
+            ptr.read_unix_system_time32_at(8),
+            Some(UNIX_EPOCH - Duration::from_secs(86_400))
+        );
+
+        // The extremes don't panic, whether or not this platform's `SystemTime` holds them.
+        ptr.write_i64_at(0, i64::MIN);
+        assert_eq!(
+            ptr.read_unix_system_time_at(0),
+            UNIX_EPOCH.checked_sub(Duration::from_secs(i64::MIN.unsigned_abs()))
+        );
+        ptr.write_i64_at(0, i64::MAX);
+        assert_eq!(
+            ptr.read_unix_system_time_at(0),
+            UNIX_EPOCH.checked_add(Duration::from_secs(i64::MAX as u64))
+        );
+    }
+}
+
+#[test]
+fn test_filetime() {
+    // 2023-11-14 22:13:20 UTC (Unix 1_700_000_000), plus 0.5 seconds.
+    const FILETIME: u64 = 133_444_736_005_000_000;
+    let mut buffer = [0u8; 17];
+    buffer[1..9].copy_from_slice(&FILETIME.to_le_bytes());
+    buffer[9..].copy_from_slice(&116_444_736_000_000_000u64.to_le_bytes());
+    let ptr = buffer.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_filetime_at(1), FILETIME as i64);
+        assert_eq!(ptr.read_filetime_unix_secs_at(1), 1_700_000_000);
+        // The Unix epoch itself.
+        assert_eq!(ptr.read_filetime_unix_secs_at(9), 0);
+    }
+}
+
+#[test]
+fn test_filetime_pre_unix_epoch() {
+    // 1601-01-01, the FILETIME epoch.
+    let buffer = 0u64.to_le_bytes();
+    unsafe {
+        assert_eq!(
+            buffer.as_ptr().read_filetime_unix_secs_at(0),
+            -FILETIME_UNIX_EPOCH_OFFSET_SECS
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_filetime_system_time() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let buffer = 133_444_736_005_000_000u64.to_le_bytes();
+    unsafe {
+        assert_eq!(
+            buffer.as_ptr().read_filetime_system_time_at(0),
+            Some(UNIX_EPOCH + Duration::new(1_700_000_000, 500_000_000))
+        );
+    }
+
+    // Negative FILETIMEs don't panic, whether or not this platform's `SystemTime` holds them.
+    let ancient = (-1_000_000_000_000_000_000i64).to_le_bytes();
+    let earliest = i64::MIN.to_le_bytes();
+    unsafe {
+        assert_eq!(
+            ancient.as_ptr().read_filetime_system_time_at(0),
+            UNIX_EPOCH.checked_sub(Duration::from_secs(100_000_000_000 + 11_644_473_600))
+        );
+        earliest.as_ptr().read_filetime_system_time_at(0);
+    }
+}
+
+#[test]
+fn test_read_dos_datetime() {
+    let mut buffer = [0u8; 9];
+
+    // The ZIP epoch, 1980-01-01 00:00:00: time 0x0000, date 0x0021.
+    buffer[1..3].copy_from_slice(&0x0000u16.to_le_bytes());
+    buffer[3..5].copy_from_slice(&0x0021u16.to_le_bytes());
+
+    // The latest representable value, 2107-12-31 23:59:58.
+    buffer[5..7].copy_from_slice(&0xBF7Du16.to_le_bytes());
+    buffer[7..9].copy_from_slice(&0xFF9Fu16.to_le_bytes());
+
+    unsafe {
+        let ptr = buffer.as_ptr();
+        assert_eq!(ptr.read_dos_datetime_at(1), (DOS_EPOCH_YEAR, 1, 1, 0, 0, 0));
+        assert_eq!(ptr.read_dos_datetime_at(5), (2107, 12, 31, 23, 59, 58));
+    }
+}
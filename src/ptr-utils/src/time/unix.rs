@@ -0,0 +1,87 @@
+//! Unix (`time_t`) timestamp reads.
+
+use crate::UnalignedRead;
+
+/// Trait providing reads of Unix timestamps (seconds since `1970-01-01 00:00:00 UTC`).
+///
+/// Values are read in native endianness, like the rest of [`UnalignedRead`].
+pub trait UnixTimeRead: UnalignedRead + Copy {
+    /// Reads a 64-bit signed Unix timestamp from the pointer at the given byte offset.
+    ///
+    /// Negative values represent times before the Unix epoch.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_unix_secs_at(self, byte_offset: usize) -> i64 {
+        self.read_i64_at(byte_offset)
+    }
+
+    /// Reads a legacy 32-bit signed Unix timestamp from the pointer at the given byte offset,
+    /// sign-extending it to 64 bits.
+    ///
+    /// A 32-bit `time_t` overflows on `2038-01-19 03:14:07 UTC`; values written after that
+    /// point by 32-bit writers wrap around to negative (pre-1970) times. This is inherent
+    /// to the format and cannot be corrected on read.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[inline(always)]
+    unsafe fn read_unix_secs32_at(self, byte_offset: usize) -> i64 {
+        self.read_i32_at(byte_offset) as i64
+    }
+
+    /// Reads a 64-bit signed Unix timestamp from the pointer at the given byte offset
+    /// as a [`SystemTime`](std::time::SystemTime).
+    ///
+    /// Returns [`None`] if the time is outside the range [`SystemTime`](std::time::SystemTime)
+    /// can represent on this platform (e.g. `i64::MIN`, or before 1601 on Windows).
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[cfg(feature = "std")]
+    #[inline]
+    unsafe fn read_unix_system_time_at(self, byte_offset: usize) -> Option<std::time::SystemTime> {
+        unix_secs_to_system_time(self.read_unix_secs_at(byte_offset))
+    }
+
+    /// Reads a legacy 32-bit signed Unix timestamp from the pointer at the given byte offset
+    /// as a [`SystemTime`](std::time::SystemTime).
+    ///
+    /// See [`UnixTimeRead::read_unix_secs32_at`] for the year 2038 caveat. Returns [`None`]
+    /// if the time is outside the range [`SystemTime`](std::time::SystemTime) can represent.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - No alignment requirements - this performs unaligned reads
+    #[cfg(feature = "std")]
+    #[inline]
+    unsafe fn read_unix_system_time32_at(
+        self,
+        byte_offset: usize,
+    ) -> Option<std::time::SystemTime> {
+        unix_secs_to_system_time(self.read_unix_secs32_at(byte_offset))
+    }
+}
+
+impl<P: UnalignedRead + Copy> UnixTimeRead for P {}
+
+#[cfg(feature = "std")]
+#[inline]
+fn unix_secs_to_system_time(secs: i64) -> Option<std::time::SystemTime> {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let duration = Duration::from_secs(secs.unsigned_abs());
+    if secs >= 0 {
+        UNIX_EPOCH.checked_add(duration)
+    } else {
+        UNIX_EPOCH.checked_sub(duration)
+    }
+}
@@ -0,0 +1,221 @@
+//! Unaligned reads from `&[u8; N]` and writes to `&mut [u8; N]`.
+//!
+//! These delegate to the array's data pointer, but assert that every access stays
+//! within its `N` bytes, so an out-of-bounds offset panics rather than accessing past
+//! the array. With a constant offset, the check is resolved at compile time and costs
+//! nothing.
+
+use super::raw::{read_raw, write_raw};
+use super::{UnalignedRead, UnalignedWrite};
+use core::mem::size_of;
+
+/// Asserts that `len` bytes at `byte_offset` lie within an `N`-byte array.
+///
+/// # Panics
+/// If the range does not lie entirely within the array, unless the `no-panic` feature is
+/// enabled, in which case the check is compiled out.
+#[inline(always)]
+fn check_bounds<const N: usize>(op: &str, byte_offset: usize, len: usize) {
+    release_check!(
+        byte_offset.checked_add(len).is_some_and(|end| end <= N),
+        "{op} of {len} bytes at offset {byte_offset} out of bounds for array of {N} bytes"
+    );
+}
+
+/// Reads are bounds-checked against `N` and panic if out of bounds.
+impl<const N: usize> UnalignedRead for &[u8; N] {
+    #[inline(always)]
+    unsafe fn read_u8_at(self, byte_offset: usize) -> u8 {
+        check_bounds::<N>("read", byte_offset, size_of::<u8>());
+        read_raw::<u8>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u16_at(self, byte_offset: usize) -> u16 {
+        check_bounds::<N>("read", byte_offset, size_of::<u16>());
+        read_raw::<u16>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u32_at(self, byte_offset: usize) -> u32 {
+        check_bounds::<N>("read", byte_offset, size_of::<u32>());
+        read_raw::<u32>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u64_at(self, byte_offset: usize) -> u64 {
+        check_bounds::<N>("read", byte_offset, size_of::<u64>());
+        read_raw::<u64>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_u128_at(self, byte_offset: usize) -> u128 {
+        check_bounds::<N>("read", byte_offset, size_of::<u128>());
+        read_raw::<u128>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_usize_at(self, byte_offset: usize) -> usize {
+        check_bounds::<N>("read", byte_offset, size_of::<usize>());
+        read_raw::<usize>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i8_at(self, byte_offset: usize) -> i8 {
+        check_bounds::<N>("read", byte_offset, size_of::<i8>());
+        read_raw::<i8>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i16_at(self, byte_offset: usize) -> i16 {
+        check_bounds::<N>("read", byte_offset, size_of::<i16>());
+        read_raw::<i16>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i32_at(self, byte_offset: usize) -> i32 {
+        check_bounds::<N>("read", byte_offset, size_of::<i32>());
+        read_raw::<i32>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i64_at(self, byte_offset: usize) -> i64 {
+        check_bounds::<N>("read", byte_offset, size_of::<i64>());
+        read_raw::<i64>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_i128_at(self, byte_offset: usize) -> i128 {
+        check_bounds::<N>("read", byte_offset, size_of::<i128>());
+        read_raw::<i128>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_isize_at(self, byte_offset: usize) -> isize {
+        check_bounds::<N>("read", byte_offset, size_of::<isize>());
+        read_raw::<isize>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_f32_at(self, byte_offset: usize) -> f32 {
+        check_bounds::<N>("read", byte_offset, size_of::<f32>());
+        read_raw::<f32>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_f64_at(self, byte_offset: usize) -> f64 {
+        check_bounds::<N>("read", byte_offset, size_of::<f64>());
+        read_raw::<f64>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_bool_at(self, byte_offset: usize) -> bool {
+        check_bounds::<N>("read", byte_offset, size_of::<bool>());
+        read_raw::<bool>(self.as_ptr(), byte_offset)
+    }
+
+    #[inline(always)]
+    unsafe fn read_at<V: Copy>(self, byte_offset: usize) -> V {
+        check_bounds::<N>("read", byte_offset, size_of::<V>());
+        read_raw::<V>(self.as_ptr(), byte_offset)
+    }
+}
+
+/// Writes are bounds-checked against `N` and panic if out of bounds.
+impl<const N: usize> UnalignedWrite for &mut [u8; N] {
+    #[inline(always)]
+    unsafe fn write_u8_at(self, byte_offset: usize, value: u8) {
+        check_bounds::<N>("write", byte_offset, size_of::<u8>());
+        write_raw::<u8>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_u16_at(self, byte_offset: usize, value: u16) {
+        check_bounds::<N>("write", byte_offset, size_of::<u16>());
+        write_raw::<u16>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_u32_at(self, byte_offset: usize, value: u32) {
+        check_bounds::<N>("write", byte_offset, size_of::<u32>());
+        write_raw::<u32>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_u64_at(self, byte_offset: usize, value: u64) {
+        check_bounds::<N>("write", byte_offset, size_of::<u64>());
+        write_raw::<u64>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_u128_at(self, byte_offset: usize, value: u128) {
+        check_bounds::<N>("write", byte_offset, size_of::<u128>());
+        write_raw::<u128>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_usize_at(self, byte_offset: usize, value: usize) {
+        check_bounds::<N>("write", byte_offset, size_of::<usize>());
+        write_raw::<usize>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_i8_at(self, byte_offset: usize, value: i8) {
+        check_bounds::<N>("write", byte_offset, size_of::<i8>());
+        write_raw::<i8>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_i16_at(self, byte_offset: usize, value: i16) {
+        check_bounds::<N>("write", byte_offset, size_of::<i16>());
+        write_raw::<i16>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_i32_at(self, byte_offset: usize, value: i32) {
+        check_bounds::<N>("write", byte_offset, size_of::<i32>());
+        write_raw::<i32>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_i64_at(self, byte_offset: usize, value: i64) {
+        check_bounds::<N>("write", byte_offset, size_of::<i64>());
+        write_raw::<i64>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_i128_at(self, byte_offset: usize, value: i128) {
+        check_bounds::<N>("write", byte_offset, size_of::<i128>());
+        write_raw::<i128>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_isize_at(self, byte_offset: usize, value: isize) {
+        check_bounds::<N>("write", byte_offset, size_of::<isize>());
+        write_raw::<isize>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_f32_at(self, byte_offset: usize, value: f32) {
+        check_bounds::<N>("write", byte_offset, size_of::<f32>());
+        write_raw::<f32>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_f64_at(self, byte_offset: usize, value: f64) {
+        check_bounds::<N>("write", byte_offset, size_of::<f64>());
+        write_raw::<f64>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_bool_at(self, byte_offset: usize, value: bool) {
+        check_bounds::<N>("write", byte_offset, size_of::<bool>());
+        write_raw::<bool>(self.as_mut_ptr(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_at<V: Copy>(self, byte_offset: usize, value: V) {
+        check_bounds::<N>("write", byte_offset, size_of::<V>());
+        write_raw::<V>(self.as_mut_ptr(), byte_offset, value);
+    }
+}
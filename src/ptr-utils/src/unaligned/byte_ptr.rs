@@ -0,0 +1,90 @@
+//! Conversion of arbitrary pointer types into byte pointers.
+
+/// Trait for obtaining a byte pointer from any pointer type.
+///
+/// This is primarily useful at FFI boundaries, where opaque pointers such as
+/// `*mut c_void` need to be handed to code expecting `*const u8` or `*mut u8`.
+///
+/// The [`UnalignedRead`] and [`UnalignedWrite`] traits already work on opaque
+/// pointers directly, so this is only needed when passing the pointer on.
+///
+/// [`UnalignedRead`]: crate::UnalignedRead
+/// [`UnalignedWrite`]: crate::UnalignedWrite
+///
+/// # Example
+///
+/// ```
+/// use core::ffi::c_void;
+/// use ptr_utils::{AsBytePtr, UnalignedRead};
+///
+/// let buffer = [0x78u8, 0x56, 0x34, 0x12];
+/// let opaque = buffer.as_ptr() as *const c_void;
+///
+/// unsafe {
+///     assert_eq!(opaque.read_u32_at(0), u32::from_le_bytes(buffer));
+///     assert_eq!(*opaque.as_byte_ptr(), 0x78);
+/// }
+/// ```
+pub trait AsBytePtr {
+    /// Returns the pointer cast to a `*const u8`.
+    #[allow(clippy::wrong_self_convention)] // pointers are `Copy`
+    fn as_byte_ptr(self) -> *const u8;
+
+    /// Returns a byte pointer `byte_offset` bytes past this one, without reading.
+    ///
+    /// For handing a sub-pointer to FFI or other `unsafe` code after navigating with
+    /// byte offsets.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must lie within (or one past the end of) the same
+    ///   allocation, as for [`pointer::add`](https://doc.rust-lang.org/std/primitive.pointer.html#method.add)
+    #[inline(always)]
+    unsafe fn byte_ptr_at(self, byte_offset: usize) -> *const u8
+    where
+        Self: Sized,
+    {
+        self.as_byte_ptr().add(byte_offset)
+    }
+}
+
+/// Trait for obtaining a mutable byte pointer from any mutable pointer type.
+pub trait AsBytePtrMut {
+    /// Returns the pointer cast to a `*mut u8`.
+    #[allow(clippy::wrong_self_convention)] // pointers are `Copy`
+    fn as_byte_ptr_mut(self) -> *mut u8;
+
+    /// Returns a mutable byte pointer `byte_offset` bytes past this one, without reading
+    /// or writing.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must lie within (or one past the end of) the same
+    ///   allocation, as for [`pointer::add`](https://doc.rust-lang.org/std/primitive.pointer.html#method.add)
+    #[inline(always)]
+    unsafe fn byte_ptr_mut_at(self, byte_offset: usize) -> *mut u8
+    where
+        Self: Sized,
+    {
+        self.as_byte_ptr_mut().add(byte_offset)
+    }
+}
+
+impl<T> AsBytePtr for *const T {
+    #[inline(always)]
+    fn as_byte_ptr(self) -> *const u8 {
+        self.cast()
+    }
+}
+
+impl<T> AsBytePtr for *mut T {
+    #[inline(always)]
+    fn as_byte_ptr(self) -> *const u8 {
+        self.cast()
+    }
+}
+
+impl<T> AsBytePtrMut for *mut T {
+    #[inline(always)]
+    fn as_byte_ptr_mut(self) -> *mut u8 {
+        self.cast()
+    }
+}
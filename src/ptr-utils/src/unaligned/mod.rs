@@ -3,11 +3,17 @@
 //! This module provides a trait-based solution to avoid explicit pointer casts
 //! when performing unaligned reads and writes on all common Rust primitive types.
 
+pub mod array;
+pub mod byte_ptr;
+pub(crate) mod raw;
 pub mod read;
+#[cfg(feature = "alloc")]
+pub mod shared;
 pub mod write;
 
 #[cfg(test)]
 mod tests;
 
+pub use byte_ptr::{AsBytePtr, AsBytePtrMut};
 pub use read::UnalignedRead;
 pub use write::UnalignedWrite;
@@ -0,0 +1,111 @@
+//! The single place where the crate's loads and stores are performed.
+//!
+//! Every typed read and write funnels through [`read_raw`] and [`write_raw`] (or
+//! [`read_raw_aligned`] for reads the caller guarantees are aligned), so
+//! alternative lowering strategies (such as the `byte-assembly` feature) and
+//! instrumentation (the `tracing` feature) only need to be implemented here.
+//!
+//! Pointers are only ever converted with `.cast()` and offset with `.add()`, never
+//! round-tripped through integers, so every access keeps the provenance of the pointer
+//! the caller passed in. This is checked by running the tests under Miri with
+//! `-Zmiri-strict-provenance`.
+
+#[cfg(any(feature = "byte-assembly", feature = "tracing"))]
+use core::mem::size_of;
+#[cfg(feature = "byte-assembly")]
+use core::mem::MaybeUninit;
+
+/// Reads a `V` from `base` plus `byte_offset` without any alignment requirement.
+///
+/// With the `byte-assembly` feature, the value is assembled from individual
+/// volatile byte loads, which the compiler cannot merge into a wider (potentially
+/// trapping) load.
+///
+/// # Safety
+/// - `base` plus `byte_offset` must be valid for reading [`size_of::<V>()`] bytes
+/// - The bytes read must represent a valid value of `V`
+#[inline(always)]
+pub(crate) unsafe fn read_raw<V: Copy>(base: *const u8, byte_offset: usize) -> V {
+    #[cfg(feature = "tracing")]
+    trace_access::<V>("read", base, byte_offset);
+
+    let ptr = base.add(byte_offset);
+
+    #[cfg(not(feature = "byte-assembly"))]
+    {
+        ptr.cast::<V>().read_unaligned()
+    }
+
+    #[cfg(feature = "byte-assembly")]
+    {
+        // Copied as `MaybeUninit<u8>`, so padding bytes are never read as `u8`.
+        let src = ptr.cast::<MaybeUninit<u8>>();
+        let mut value = MaybeUninit::<V>::uninit();
+        let dst = value.as_mut_ptr().cast::<MaybeUninit<u8>>();
+        for x in 0..size_of::<V>() {
+            dst.add(x).write(src.add(x).read_volatile());
+        }
+        value.assume_init()
+    }
+}
+
+/// Reads a `V` from `base` plus `byte_offset`, which must be aligned for `V`.
+///
+/// A plain aligned load on every configuration, including `byte-assembly` (an aligned
+/// load never traps).
+///
+/// # Safety
+/// - `base` plus `byte_offset` must be valid for reading [`size_of::<V>()`] bytes
+/// - `base` plus `byte_offset` must be aligned to `align_of::<V>()`
+/// - The bytes read must represent a valid value of `V`
+#[inline(always)]
+pub(crate) unsafe fn read_raw_aligned<V: Copy>(base: *const u8, byte_offset: usize) -> V {
+    #[cfg(feature = "tracing")]
+    trace_access::<V>("read_aligned", base, byte_offset);
+
+    base.add(byte_offset).cast::<V>().read()
+}
+
+/// Writes a `V` to `base` plus `byte_offset` without any alignment requirement.
+///
+/// With the `byte-assembly` feature, the value is stored with individual
+/// volatile byte stores, which the compiler cannot merge into a wider store.
+///
+/// # Safety
+/// - `base` plus `byte_offset` must be valid for writing [`size_of::<V>()`] bytes
+#[inline(always)]
+pub(crate) unsafe fn write_raw<V: Copy>(base: *mut u8, byte_offset: usize, value: V) {
+    #[cfg(feature = "tracing")]
+    trace_access::<V>("write", base, byte_offset);
+
+    let ptr = base.add(byte_offset);
+
+    #[cfg(not(feature = "byte-assembly"))]
+    {
+        ptr.cast::<V>().write_unaligned(value)
+    }
+
+    #[cfg(feature = "byte-assembly")]
+    {
+        // Copied as `MaybeUninit<u8>`, so padding bytes are never read as `u8`.
+        let src = (&value as *const V).cast::<MaybeUninit<u8>>();
+        let dst = ptr.cast::<MaybeUninit<u8>>();
+        for x in 0..size_of::<V>() {
+            dst.add(x).write_volatile(src.add(x).read());
+        }
+    }
+}
+
+/// Emits a `TRACE` level event describing a single read or write.
+#[cfg(feature = "tracing")]
+#[inline(always)]
+fn trace_access<V>(op: &'static str, base: *const u8, byte_offset: usize) {
+    tracing::trace!(
+        target: "ptr_utils",
+        op,
+        base = ?base,
+        byte_offset,
+        size = size_of::<V>(),
+        ty = core::any::type_name::<V>(),
+    );
+}
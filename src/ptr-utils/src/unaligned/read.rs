@@ -1,5 +1,7 @@
 //! Unaligned read operations for pointer types.
 
+use super::raw::read_raw;
+
 /// Trait providing convenient unaligned read operations for pointer types.
 ///
 /// This trait eliminates the need for explicit casts when reading from
@@ -131,160 +133,174 @@ pub trait UnalignedRead {
     /// - The caller must ensure the pointer remains valid for the duration of the read
     /// - The byte value must represent a valid [`bool`] (0 or 1)
     unsafe fn read_bool_at(self, byte_offset: usize) -> bool;
-}
-
-// Implementations for const pointers
-impl<T> UnalignedRead for *const T {
-    #[inline(always)]
-    unsafe fn read_u8_at(self, byte_offset: usize) -> u8 {
-        (self as *const u8).add(byte_offset).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_u16_at(self, byte_offset: usize) -> u16 {
-        ((self as *const u8).add(byte_offset) as *const u16).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_u32_at(self, byte_offset: usize) -> u32 {
-        ((self as *const u8).add(byte_offset) as *const u32).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_u64_at(self, byte_offset: usize) -> u64 {
-        ((self as *const u8).add(byte_offset) as *const u64).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_u128_at(self, byte_offset: usize) -> u128 {
-        ((self as *const u8).add(byte_offset) as *const u128).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_usize_at(self, byte_offset: usize) -> usize {
-        ((self as *const u8).add(byte_offset) as *const usize).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_i8_at(self, byte_offset: usize) -> i8 {
-        ((self as *const u8).add(byte_offset) as *const i8).read_unaligned()
-    }
 
-    #[inline(always)]
-    unsafe fn read_i16_at(self, byte_offset: usize) -> i16 {
-        ((self as *const u8).add(byte_offset) as *const i16).read_unaligned()
-    }
+    // Generic
 
-    #[inline(always)]
-    unsafe fn read_i32_at(self, byte_offset: usize) -> i32 {
-        ((self as *const u8).add(byte_offset) as *const i32).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_i64_at(self, byte_offset: usize) -> i64 {
-        ((self as *const u8).add(byte_offset) as *const i64).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_i128_at(self, byte_offset: usize) -> i128 {
-        ((self as *const u8).add(byte_offset) as *const i128).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_isize_at(self, byte_offset: usize) -> isize {
-        ((self as *const u8).add(byte_offset) as *const isize).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_f32_at(self, byte_offset: usize) -> f32 {
-        ((self as *const u8).add(byte_offset) as *const f32).read_unaligned()
-    }
+    /// Reads a value of any [`Copy`] type `T` from the pointer at the given byte offset.
+    ///
+    /// This is useful for reading whole `#[repr(C)]` structs at once.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading [`size_of::<T>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    /// - The bytes read must represent a valid value of `T`
+    /// - No alignment requirements - this performs unaligned reads
+    unsafe fn read_at<T: Copy>(self, byte_offset: usize) -> T;
+}
 
-    #[inline(always)]
-    unsafe fn read_f64_at(self, byte_offset: usize) -> f64 {
-        ((self as *const u8).add(byte_offset) as *const f64).read_unaligned()
-    }
+/// Applies the inlining attribute of the crate's single-access reads to each method.
+///
+/// `#[inline(always)]` by default; building with `--cfg ptr_utils_inline="hint"` or
+/// `--cfg ptr_utils_inline="never"` swaps it for `#[inline]` or `#[inline(never)]`, so
+/// `benches/inline.rs` can measure each choice across the crate boundary.
+macro_rules! single_access {
+    ($(unsafe fn $name:ident $(<$gen:ident: $bound:ident>)? ($($args:tt)*) -> $ret:ty $body:block)*) => {
+        $(
+            #[cfg_attr(
+                not(any(ptr_utils_inline = "hint", ptr_utils_inline = "never")),
+                inline(always)
+            )]
+            #[cfg_attr(ptr_utils_inline = "hint", inline)]
+            #[cfg_attr(ptr_utils_inline = "never", inline(never))]
+            unsafe fn $name $(<$gen: $bound>)? ($($args)*) -> $ret $body
+        )*
+    };
+}
 
-    #[inline(always)]
-    unsafe fn read_bool_at(self, byte_offset: usize) -> bool {
-        ((self as *const u8).add(byte_offset) as *const bool).read_unaligned()
+// Implementations for const pointers
+impl<T> UnalignedRead for *const T {
+    single_access! {
+        unsafe fn read_u8_at(self, byte_offset: usize) -> u8 {
+            read_raw::<u8>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_u16_at(self, byte_offset: usize) -> u16 {
+            read_raw::<u16>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_u32_at(self, byte_offset: usize) -> u32 {
+            read_raw::<u32>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_u64_at(self, byte_offset: usize) -> u64 {
+            read_raw::<u64>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_u128_at(self, byte_offset: usize) -> u128 {
+            read_raw::<u128>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_usize_at(self, byte_offset: usize) -> usize {
+            read_raw::<usize>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_i8_at(self, byte_offset: usize) -> i8 {
+            read_raw::<i8>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_i16_at(self, byte_offset: usize) -> i16 {
+            read_raw::<i16>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_i32_at(self, byte_offset: usize) -> i32 {
+            read_raw::<i32>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_i64_at(self, byte_offset: usize) -> i64 {
+            read_raw::<i64>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_i128_at(self, byte_offset: usize) -> i128 {
+            read_raw::<i128>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_isize_at(self, byte_offset: usize) -> isize {
+            read_raw::<isize>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_f32_at(self, byte_offset: usize) -> f32 {
+            read_raw::<f32>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_f64_at(self, byte_offset: usize) -> f64 {
+            read_raw::<f64>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_bool_at(self, byte_offset: usize) -> bool {
+            read_raw::<bool>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_at<V: Copy>(self, byte_offset: usize) -> V {
+            read_raw::<V>(self.cast(), byte_offset)
+        }
     }
 }
 
 // Implementations for mutable pointers (read operations)
 impl<T> UnalignedRead for *mut T {
-    #[inline(always)]
-    unsafe fn read_u8_at(self, byte_offset: usize) -> u8 {
-        (self as *const u8).add(byte_offset).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_u16_at(self, byte_offset: usize) -> u16 {
-        ((self as *const u8).add(byte_offset) as *const u16).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_u32_at(self, byte_offset: usize) -> u32 {
-        ((self as *const u8).add(byte_offset) as *const u32).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_u64_at(self, byte_offset: usize) -> u64 {
-        ((self as *const u8).add(byte_offset) as *const u64).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_u128_at(self, byte_offset: usize) -> u128 {
-        ((self as *const u8).add(byte_offset) as *const u128).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_usize_at(self, byte_offset: usize) -> usize {
-        ((self as *const u8).add(byte_offset) as *const usize).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_i8_at(self, byte_offset: usize) -> i8 {
-        ((self as *const u8).add(byte_offset) as *const i8).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_i16_at(self, byte_offset: usize) -> i16 {
-        ((self as *const u8).add(byte_offset) as *const i16).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_i32_at(self, byte_offset: usize) -> i32 {
-        ((self as *const u8).add(byte_offset) as *const i32).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_i64_at(self, byte_offset: usize) -> i64 {
-        ((self as *const u8).add(byte_offset) as *const i64).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_i128_at(self, byte_offset: usize) -> i128 {
-        ((self as *const u8).add(byte_offset) as *const i128).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_isize_at(self, byte_offset: usize) -> isize {
-        ((self as *const u8).add(byte_offset) as *const isize).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_f32_at(self, byte_offset: usize) -> f32 {
-        ((self as *const u8).add(byte_offset) as *const f32).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_f64_at(self, byte_offset: usize) -> f64 {
-        ((self as *const u8).add(byte_offset) as *const f64).read_unaligned()
-    }
-
-    #[inline(always)]
-    unsafe fn read_bool_at(self, byte_offset: usize) -> bool {
-        ((self as *const u8).add(byte_offset) as *const bool).read_unaligned()
+    single_access! {
+        unsafe fn read_u8_at(self, byte_offset: usize) -> u8 {
+            read_raw::<u8>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_u16_at(self, byte_offset: usize) -> u16 {
+            read_raw::<u16>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_u32_at(self, byte_offset: usize) -> u32 {
+            read_raw::<u32>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_u64_at(self, byte_offset: usize) -> u64 {
+            read_raw::<u64>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_u128_at(self, byte_offset: usize) -> u128 {
+            read_raw::<u128>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_usize_at(self, byte_offset: usize) -> usize {
+            read_raw::<usize>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_i8_at(self, byte_offset: usize) -> i8 {
+            read_raw::<i8>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_i16_at(self, byte_offset: usize) -> i16 {
+            read_raw::<i16>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_i32_at(self, byte_offset: usize) -> i32 {
+            read_raw::<i32>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_i64_at(self, byte_offset: usize) -> i64 {
+            read_raw::<i64>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_i128_at(self, byte_offset: usize) -> i128 {
+            read_raw::<i128>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_isize_at(self, byte_offset: usize) -> isize {
+            read_raw::<isize>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_f32_at(self, byte_offset: usize) -> f32 {
+            read_raw::<f32>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_f64_at(self, byte_offset: usize) -> f64 {
+            read_raw::<f64>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_bool_at(self, byte_offset: usize) -> bool {
+            read_raw::<bool>(self.cast(), byte_offset)
+        }
+
+        unsafe fn read_at<V: Copy>(self, byte_offset: usize) -> V {
+            read_raw::<V>(self.cast(), byte_offset)
+        }
     }
 }
@@ -0,0 +1,164 @@
+//! Unaligned reads from shared byte buffers (`Arc<[u8]>` and `Rc<[u8]>`).
+//!
+//! These delegate to the slice's data pointer, but assert that every read stays
+//! within the buffer, so an out-of-bounds offset panics rather than reading past
+//! the allocation. Only reads are provided, as the buffers are shared and immutable.
+
+use super::raw::read_raw;
+use super::UnalignedRead;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::mem::size_of;
+
+/// Returns the base pointer of `bytes`, after asserting that `len` bytes at
+/// `byte_offset` lie within it.
+///
+/// # Panics
+/// If the range does not lie entirely within `bytes`, unless the `no-panic` feature is
+/// enabled, in which case the check is compiled out.
+#[inline(always)]
+fn checked_base(bytes: &[u8], byte_offset: usize, len: usize) -> *const u8 {
+    release_check!(
+        byte_offset
+            .checked_add(len)
+            .is_some_and(|end| end <= bytes.len()),
+        "read of {len} bytes at offset {byte_offset} out of bounds for buffer of {} bytes",
+        bytes.len()
+    );
+    bytes.as_ptr()
+}
+
+macro_rules! impl_shared_read {
+    ($ty:ty) => {
+        /// Reads are bounds-checked against the buffer length and panic if out of bounds.
+        impl UnalignedRead for &$ty {
+            #[inline(always)]
+            unsafe fn read_u8_at(self, byte_offset: usize) -> u8 {
+                read_raw::<u8>(
+                    checked_base(self, byte_offset, size_of::<u8>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_u16_at(self, byte_offset: usize) -> u16 {
+                read_raw::<u16>(
+                    checked_base(self, byte_offset, size_of::<u16>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_u32_at(self, byte_offset: usize) -> u32 {
+                read_raw::<u32>(
+                    checked_base(self, byte_offset, size_of::<u32>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_u64_at(self, byte_offset: usize) -> u64 {
+                read_raw::<u64>(
+                    checked_base(self, byte_offset, size_of::<u64>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_u128_at(self, byte_offset: usize) -> u128 {
+                read_raw::<u128>(
+                    checked_base(self, byte_offset, size_of::<u128>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_usize_at(self, byte_offset: usize) -> usize {
+                read_raw::<usize>(
+                    checked_base(self, byte_offset, size_of::<usize>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_i8_at(self, byte_offset: usize) -> i8 {
+                read_raw::<i8>(
+                    checked_base(self, byte_offset, size_of::<i8>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_i16_at(self, byte_offset: usize) -> i16 {
+                read_raw::<i16>(
+                    checked_base(self, byte_offset, size_of::<i16>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_i32_at(self, byte_offset: usize) -> i32 {
+                read_raw::<i32>(
+                    checked_base(self, byte_offset, size_of::<i32>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_i64_at(self, byte_offset: usize) -> i64 {
+                read_raw::<i64>(
+                    checked_base(self, byte_offset, size_of::<i64>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_i128_at(self, byte_offset: usize) -> i128 {
+                read_raw::<i128>(
+                    checked_base(self, byte_offset, size_of::<i128>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_isize_at(self, byte_offset: usize) -> isize {
+                read_raw::<isize>(
+                    checked_base(self, byte_offset, size_of::<isize>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_f32_at(self, byte_offset: usize) -> f32 {
+                read_raw::<f32>(
+                    checked_base(self, byte_offset, size_of::<f32>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_f64_at(self, byte_offset: usize) -> f64 {
+                read_raw::<f64>(
+                    checked_base(self, byte_offset, size_of::<f64>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_bool_at(self, byte_offset: usize) -> bool {
+                read_raw::<bool>(
+                    checked_base(self, byte_offset, size_of::<bool>()),
+                    byte_offset,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn read_at<V: Copy>(self, byte_offset: usize) -> V {
+                read_raw::<V>(checked_base(self, byte_offset, size_of::<V>()), byte_offset)
+            }
+        }
+    };
+}
+
+impl_shared_read!(Arc<[u8]>);
+impl_shared_read!(Rc<[u8]>);
@@ -1,7 +1,10 @@
 use core::f32;
 use core::f64;
+use core::ffi::c_void;
+use core::mem::MaybeUninit;
 
 use super::*;
+use crate::EndianRead;
 
 #[test]
 fn test_unsigned_integers() {
@@ -219,3 +222,224 @@ fn test_round_trip_all_types() {
         assert_eq!(ptr.read_isize_at(offset), test_isize);
     }
 }
+
+#[test]
+fn test_c_void_pointers() {
+    unsafe {
+        let mut buffer = [0u8; 16];
+        // Every pointer derives from `base`; a fresh `buffer.as_mut_ptr()` would
+        // invalidate the earlier ones under Stacked Borrows.
+        let base = buffer.as_mut_ptr();
+        let mut_ptr = base as *mut c_void;
+
+        // Opaque pointers work directly, as handed over FFI boundaries
+        mut_ptr.write_u32_at(1, 0x12345678);
+        mut_ptr.write_u16_at(5, 0xABCD);
+        assert_eq!(mut_ptr.read_u32_at(1), 0x12345678);
+        assert_eq!(mut_ptr.read_u16_at(5), 0xABCD);
+
+        let const_ptr = mut_ptr as *const c_void;
+        assert_eq!(const_ptr.read_u32_at(1), 0x12345678);
+        assert_eq!(const_ptr.as_byte_ptr(), base as *const u8);
+        assert_eq!(mut_ptr.as_byte_ptr_mut(), base);
+
+        // Sub-pointers for passing on, without reading.
+        let sub = const_ptr.byte_ptr_at(1);
+        assert_eq!(sub, base.add(1) as *const u8);
+        assert_eq!(sub.read_u32_at(0), 0x12345678);
+        let sub_mut = mut_ptr.byte_ptr_mut_at(5);
+        sub_mut.write_u16_at(0, 0x1234);
+        assert_eq!(mut_ptr.read_u16_at(5), 0x1234);
+        assert_eq!(mut_ptr.byte_ptr_at(16), base.add(16) as *const u8);
+    }
+}
+
+#[test]
+fn test_maybe_uninit_pointers() {
+    let mut buffer = [MaybeUninit::<u8>::uninit(); 16];
+    let ptr: *mut MaybeUninit<u8> = buffer.as_mut_ptr();
+
+    unsafe {
+        // Writes initialize the bytes they cover, which can then be read back.
+        ptr.write_u8_at(0, 0x11);
+        ptr.write_u32_at(1, 0x22334455);
+        ptr.write_f64_at(5, 2.5);
+        ptr.write_at(13, [0xAAu8, 0xBB, 0xCC]);
+
+        assert_eq!(ptr.read_u8_at(0), 0x11);
+        assert_eq!(ptr.read_u32_at(1), 0x22334455);
+        assert_eq!(ptr.read_f64_at(5), 2.5);
+
+        let const_ptr: *const MaybeUninit<u8> = ptr;
+        assert_eq!(const_ptr.read_at::<[u8; 3]>(13), [0xAA, 0xBB, 0xCC]);
+        assert_eq!(const_ptr.as_byte_ptr(), ptr.as_byte_ptr_mut().cast_const());
+
+        // The whole buffer is now initialized.
+        let bytes = &*(&raw const buffer).cast::<[u8; 16]>();
+        assert_eq!(bytes[0], 0x11);
+        assert_eq!(&bytes[13..], &[0xAA, 0xBB, 0xCC]);
+    }
+
+    // Wider element types use the same byte offsets.
+    let mut words = [MaybeUninit::<u32>::uninit(); 2];
+    let ptr = words.as_mut_ptr();
+    let bytes = 0x0102030405060708u64.to_ne_bytes();
+    unsafe {
+        ptr.write_u64_at(0, 0x0102030405060708);
+        assert_eq!(ptr.read_u16_at(3), u16::from_ne_bytes([bytes[3], bytes[4]]));
+        assert_eq!(
+            words[1].assume_init(),
+            u32::from_ne_bytes([bytes[4], bytes[5], bytes[6], bytes[7]])
+        );
+    }
+}
+
+#[test]
+fn test_generic_read_write() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(C)]
+    struct Header {
+        magic: u32,
+        version: u16,
+        flags: u16,
+    }
+
+    unsafe {
+        let mut buffer = [0u8; 16];
+        let ptr = buffer.as_mut_ptr();
+        let header = Header {
+            magic: 0x12345678,
+            version: 3,
+            flags: 0xABCD,
+        };
+
+        ptr.write_at(1, header);
+        assert_eq!(ptr.read_at::<Header>(1), header);
+        assert_eq!(ptr.read_u32_at(1), 0x12345678);
+        assert_eq!(ptr.read_at::<u16>(5), 3);
+    }
+}
+
+#[test]
+fn test_value_semantics_match_byte_order() {
+    // Must hold identically with and without the `byte-assembly` feature.
+    unsafe {
+        let bytes: [u8; 17] = [
+            0xAA, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x10, 0x32, 0x54, 0x76, 0x98,
+            0xBA, 0xDC, 0xFE,
+        ];
+        let ptr = bytes.as_ptr();
+
+        let b2: [u8; 2] = bytes[1..3].try_into().unwrap();
+        let b4: [u8; 4] = bytes[1..5].try_into().unwrap();
+        let b8: [u8; 8] = bytes[1..9].try_into().unwrap();
+        let b16: [u8; 16] = bytes[1..17].try_into().unwrap();
+
+        assert_eq!(ptr.read_u16_at(1), u16::from_ne_bytes(b2));
+        assert_eq!(ptr.read_u32_at(1), u32::from_ne_bytes(b4));
+        assert_eq!(ptr.read_u64_at(1), u64::from_ne_bytes(b8));
+        assert_eq!(ptr.read_u128_at(1), u128::from_ne_bytes(b16));
+        assert_eq!(u32::from_le(ptr.read_u32_at(1)), u32::from_le_bytes(b4));
+        assert_eq!(u32::from_be(ptr.read_u32_at(1)), u32::from_be_bytes(b4));
+        assert_eq!(u64::from_be(ptr.read_u64_at(1)), u64::from_be_bytes(b8));
+
+        let mut out = [0u8; 17];
+        let out_ptr = out.as_mut_ptr();
+        out_ptr.write_u64_at(1, u64::from_ne_bytes(b8));
+        out_ptr.write_u32_at(9, u32::from_le_bytes(b4).to_le());
+        assert_eq!(out[1..9], b8);
+        assert_eq!(out[9..13], b4);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_shared_buffer_reads() {
+    use alloc::rc::Rc;
+    use alloc::sync::Arc;
+
+    let bytes = [0x78u8, 0x56, 0x34, 0x12, 0xFF];
+    let arc: Arc<[u8]> = Arc::from(&bytes[..]);
+    let rc: Rc<[u8]> = Rc::from(&bytes[..]);
+
+    unsafe {
+        assert_eq!((&arc).read_u32_at(0), u32::from_le(0x12345678));
+        assert_eq!((&arc).read_u8_at(4), 0xFF);
+        assert_eq!((&rc).read_u16_at(3), u16::from_ne_bytes([0x12, 0xFF]));
+        assert_eq!((&rc).read_at::<[u8; 2]>(1), [0x56, 0x34]);
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "no-panic")))]
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_shared_buffer_read_out_of_bounds() {
+    let arc: alloc::sync::Arc<[u8]> = alloc::sync::Arc::from(&[0u8; 4][..]);
+    unsafe {
+        (&arc).read_u32_at(1);
+    }
+}
+
+#[test]
+fn test_array_reads_and_writes() {
+    let mut buffer = [0u8; 16];
+
+    unsafe {
+        // Arrays auto-reference, so no pointer is needed.
+        buffer.write_u32_at(3, 0x12345678);
+        (&mut buffer).write_at(12, [0xAAu8, 0xBB, 0xCC, 0xDD]);
+        assert_eq!(buffer.read_u32_at(3), 0x12345678);
+        assert_eq!(buffer.read_u8_at(15), 0xDD);
+
+        // Extension traits work through `&[u8; N]` too.
+        let array = &buffer;
+        assert_eq!(
+            array.read_u32_endian_at(3, crate::Endian::NATIVE),
+            0x12345678
+        );
+        assert_eq!(array.read_at::<[u8; 2]>(12), [0xAA, 0xBB]);
+    }
+    assert_eq!(buffer[3..7], 0x12345678u32.to_ne_bytes());
+}
+
+#[cfg(not(feature = "no-panic"))]
+#[test]
+#[should_panic(expected = "read of 4 bytes at offset 13 out of bounds for array of 16 bytes")]
+fn test_array_read_out_of_bounds() {
+    let buffer = [0u8; 16];
+    unsafe {
+        buffer.read_u32_at(13);
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+#[test]
+#[should_panic(expected = "write of 8 bytes at offset 9 out of bounds for array of 16 bytes")]
+fn test_array_write_out_of_bounds() {
+    let mut buffer = [0u8; 16];
+    unsafe {
+        buffer.write_u64_at(9, 0);
+    }
+}
+
+/// Accesses through typed pointers of a different type than the one written must keep
+/// the provenance of the original pointer. Meaningful under
+/// `MIRIFLAGS=-Zmiri-strict-provenance cargo +nightly miri test`.
+#[test]
+fn test_typed_pointer_provenance() {
+    let mut buffer = [0u64; 4];
+    let u64_ptr = buffer.as_mut_ptr();
+    let u16_ptr = u64_ptr.cast::<u16>();
+
+    unsafe {
+        // Write through a `*mut u64` at offsets that aren't multiples of its size.
+        u64_ptr.write_u16_at(3, 0xBEEF);
+        u64_ptr.write_u64_at(13, 0x0102_0304_0506_0708);
+        u64_ptr.write_at(30, [0xAAu8, 0xBB]);
+
+        // Read back through differently typed pointers derived from the same allocation.
+        assert_eq!(u16_ptr.read_u16_at(3), 0xBEEF);
+        assert_eq!(u16_ptr.cast_const().read_u64_at(13), 0x0102_0304_0506_0708);
+        assert_eq!(u64_ptr.cast::<u32>().read_at::<[u8; 2]>(30), [0xAA, 0xBB]);
+    }
+}
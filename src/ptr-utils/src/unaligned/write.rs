@@ -1,6 +1,28 @@
 //! Unaligned write operations for pointer types.
 
+use super::raw::write_raw;
+
 /// Trait providing convenient unaligned write operations for mutable pointer types.
+///
+/// This includes `*mut MaybeUninit<T>`, for filling uninitialized buffers: each write
+/// initializes the bytes it covers, which may then be read back. Reading bytes that
+/// have not been written is undefined behaviour, as for any uninitialized memory.
+///
+/// # Example
+///
+/// ```
+/// use core::mem::MaybeUninit;
+/// use ptr_utils::{UnalignedRead, UnalignedWrite};
+///
+/// let mut buffer = [MaybeUninit::<u8>::uninit(); 6];
+/// let ptr = buffer.as_mut_ptr();
+///
+/// unsafe {
+///     ptr.write_u16_at(0, 0x0102);
+///     ptr.write_u32_at(2, 0x03040506);
+///     assert_eq!(ptr.read_u32_at(2), 0x03040506);
+/// }
+/// ```
 pub trait UnalignedWrite {
     // Unsigned integer types
 
@@ -141,81 +163,99 @@ pub trait UnalignedWrite {
     /// - The caller must ensure the pointer remains valid for the duration of the write
     /// - The memory location must be mutable
     unsafe fn write_bool_at(self, byte_offset: usize, value: bool);
+
+    // Generic
+
+    /// Writes a value of any [`Copy`] type `T` to the pointer at the given byte offset.
+    ///
+    /// This is useful for writing whole `#[repr(C)]` structs at once.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for writing [`size_of::<T>()`] bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the write
+    /// - The memory location must be mutable
+    /// - No alignment requirements - this performs unaligned writes
+    unsafe fn write_at<T: Copy>(self, byte_offset: usize, value: T);
 }
 
 impl<T> UnalignedWrite for *mut T {
     #[inline(always)]
     unsafe fn write_u8_at(self, byte_offset: usize, value: u8) {
-        (self as *mut u8).add(byte_offset).write_unaligned(value);
+        write_raw::<u8>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_u16_at(self, byte_offset: usize, value: u16) {
-        ((self as *mut u8).add(byte_offset) as *mut u16).write_unaligned(value);
+        write_raw::<u16>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_u32_at(self, byte_offset: usize, value: u32) {
-        ((self as *mut u8).add(byte_offset) as *mut u32).write_unaligned(value);
+        write_raw::<u32>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_u64_at(self, byte_offset: usize, value: u64) {
-        ((self as *mut u8).add(byte_offset) as *mut u64).write_unaligned(value);
+        write_raw::<u64>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_u128_at(self, byte_offset: usize, value: u128) {
-        ((self as *mut u8).add(byte_offset) as *mut u128).write_unaligned(value);
+        write_raw::<u128>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_usize_at(self, byte_offset: usize, value: usize) {
-        ((self as *mut u8).add(byte_offset) as *mut usize).write_unaligned(value);
+        write_raw::<usize>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_i8_at(self, byte_offset: usize, value: i8) {
-        ((self as *mut u8).add(byte_offset) as *mut i8).write_unaligned(value);
+        write_raw::<i8>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_i16_at(self, byte_offset: usize, value: i16) {
-        ((self as *mut u8).add(byte_offset) as *mut i16).write_unaligned(value);
+        write_raw::<i16>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_i32_at(self, byte_offset: usize, value: i32) {
-        ((self as *mut u8).add(byte_offset) as *mut i32).write_unaligned(value);
+        write_raw::<i32>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_i64_at(self, byte_offset: usize, value: i64) {
-        ((self as *mut u8).add(byte_offset) as *mut i64).write_unaligned(value);
+        write_raw::<i64>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_i128_at(self, byte_offset: usize, value: i128) {
-        ((self as *mut u8).add(byte_offset) as *mut i128).write_unaligned(value);
+        write_raw::<i128>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_isize_at(self, byte_offset: usize, value: isize) {
-        ((self as *mut u8).add(byte_offset) as *mut isize).write_unaligned(value);
+        write_raw::<isize>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_f32_at(self, byte_offset: usize, value: f32) {
-        ((self as *mut u8).add(byte_offset) as *mut f32).write_unaligned(value);
+        write_raw::<f32>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_f64_at(self, byte_offset: usize, value: f64) {
-        ((self as *mut u8).add(byte_offset) as *mut f64).write_unaligned(value);
+        write_raw::<f64>(self.cast(), byte_offset, value);
     }
 
     #[inline(always)]
     unsafe fn write_bool_at(self, byte_offset: usize, value: bool) {
-        ((self as *mut u8).add(byte_offset) as *mut bool).write_unaligned(value);
+        write_raw::<bool>(self.cast(), byte_offset, value);
+    }
+
+    #[inline(always)]
+    unsafe fn write_at<V: Copy>(self, byte_offset: usize, value: V) {
+        write_raw::<V>(self.cast(), byte_offset, value);
     }
 }
@@ -0,0 +1,101 @@
+//! Decoding of delta-encoded sequences.
+
+use crate::{PtrCursor, VarintRead};
+
+/// A stateful decoder for sequences stored as differences from the previous value.
+///
+/// Each `read_next_*` call reads one delta at the current position, adds it to the
+/// running total and returns the reconstructed absolute value.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::DeltaReader;
+///
+/// // Zigzag varint deltas: +100, -1, +2
+/// let data = [0xC8u8, 0x01, 0x01, 0x04];
+/// let mut reader = DeltaReader::new(data.as_ptr());
+///
+/// unsafe {
+///     assert_eq!(reader.read_next_zigzag(), Some(100));
+///     assert_eq!(reader.read_next_zigzag(), Some(99));
+///     assert_eq!(reader.read_next_zigzag(), Some(101));
+/// }
+/// assert_eq!(reader.position(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeltaReader {
+    cursor: PtrCursor,
+    total: i64,
+}
+
+impl DeltaReader {
+    /// Creates a decoder at the start of `ptr`, with a running total of zero.
+    #[inline(always)]
+    pub const fn new(ptr: *const u8) -> Self {
+        Self::with_initial(ptr, 0)
+    }
+
+    /// Creates a decoder at the start of `ptr`, with the given initial running total.
+    #[inline(always)]
+    pub const fn with_initial(ptr: *const u8, initial: i64) -> Self {
+        Self {
+            cursor: PtrCursor::new(ptr),
+            total: initial,
+        }
+    }
+
+    /// Returns the current running total (the last reconstructed value).
+    #[inline(always)]
+    pub const fn total(&self) -> i64 {
+        self.total
+    }
+
+    /// Returns the current byte position relative to the start.
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.cursor.position()
+    }
+
+    /// Reads an [`i32`] delta (native endianness) and returns the reconstructed value.
+    ///
+    /// The running total wraps within [`i32`] range; mixing this with the 64-bit reads
+    /// truncates the total to 32 bits.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 4 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    pub unsafe fn read_next_i32(&mut self) -> i32 {
+        let value = (self.total as i32).wrapping_add(self.cursor.read_i32());
+        self.total = value as i64;
+        value
+    }
+
+    /// Reads an [`i64`] delta (native endianness) and returns the reconstructed value.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading 8 bytes
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline(always)]
+    pub unsafe fn read_next_i64(&mut self) -> i64 {
+        self.total = self.total.wrapping_add(self.cursor.read_i64());
+        self.total
+    }
+
+    /// Reads a zigzag varint delta and returns the reconstructed value.
+    ///
+    /// Returns [`None`] (without advancing) if the varint is malformed.
+    ///
+    /// # Safety
+    /// - The current position must be valid for reading the whole encoded varint
+    ///   (up to [`MAX_LEB128_LEN`](crate::varint::MAX_LEB128_LEN) bytes)
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    pub unsafe fn read_next_zigzag(&mut self) -> Option<i64> {
+        let (delta, len) = self.cursor.base().read_zigzag_at(self.cursor.position())?;
+        self.cursor.skip(len);
+        self.total = self.total.wrapping_add(delta);
+        Some(self.total)
+    }
+}
@@ -0,0 +1,130 @@
+//! LEB128 (a.k.a. varint) reads, as used by protobuf, DWARF and WebAssembly.
+
+use crate::UnalignedRead;
+
+/// Maximum encoded length of a 64-bit LEB128 value, in bytes.
+pub const MAX_LEB128_LEN: usize = 10;
+
+/// Trait providing LEB128 variable-length integer reads.
+///
+/// Each read returns the decoded value together with the number of bytes consumed,
+/// or [`None`] if the encoding is longer than [`MAX_LEB128_LEN`] bytes or overflows 64 bits.
+///
+/// # Example
+///
+/// ```
+/// use ptr_utils::VarintRead;
+///
+/// let data = [0xE5u8, 0x8E, 0x26, 0x7F];
+/// let ptr = data.as_ptr();
+///
+/// unsafe {
+///     assert_eq!(ptr.read_uleb128_at(0), Some((624485, 3)));
+///     assert_eq!(ptr.read_sleb128_at(3), Some((-1, 1)));
+/// }
+/// ```
+pub trait VarintRead: UnalignedRead + Copy {
+    /// Reads an unsigned LEB128 value from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading the whole encoded value
+    ///   (up to [`MAX_LEB128_LEN`] bytes)
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn read_uleb128_at(self, byte_offset: usize) -> Option<(u64, usize)> {
+        let mut result = 0u64;
+        let mut shift = 0u32;
+        for x in 0..MAX_LEB128_LEN {
+            let byte = self.read_u8_at(byte_offset + x);
+            let low = (byte & 0x7F) as u64;
+            if shift == 63 && low > 1 {
+                return None;
+            }
+            result |= low << shift;
+            if byte & 0x80 == 0 {
+                return Some((result, x + 1));
+            }
+            shift += 7;
+        }
+        None
+    }
+
+    /// Reads a signed (two's complement) LEB128 value from the pointer at the given byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading the whole encoded value
+    ///   (up to [`MAX_LEB128_LEN`] bytes)
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn read_sleb128_at(self, byte_offset: usize) -> Option<(i64, usize)> {
+        let mut result = 0i64;
+        let mut shift = 0u32;
+        for x in 0..MAX_LEB128_LEN {
+            let byte = self.read_u8_at(byte_offset + x);
+            let low = byte & 0x7F;
+            // The 10th byte holds bit 63; the rest of its payload must repeat it as sign bits.
+            if shift == 63 && low != 0 && low != 0x7F {
+                return None;
+            }
+            result |= (low as i64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if shift < 64 && byte & 0x40 != 0 {
+                    result |= -1i64 << shift;
+                }
+                return Some((result, x + 1));
+            }
+        }
+        None
+    }
+
+    /// Reads a zigzag-encoded signed value (protobuf `sint64`) from the pointer at the given
+    /// byte offset.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading the whole encoded value
+    ///   (up to [`MAX_LEB128_LEN`] bytes)
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn read_zigzag_at(self, byte_offset: usize) -> Option<(i64, usize)> {
+        let (value, len) = self.read_uleb128_at(byte_offset)?;
+        Some((zigzag_decode(value), len))
+    }
+
+    /// Decodes `count` consecutive unsigned LEB128 values starting at the given byte offset
+    /// into `out[..count]`, as in a protobuf packed repeated field.
+    ///
+    /// Returns the total number of bytes consumed, so the caller can advance past the
+    /// sequence, or [`None`] if any value is malformed (in which case `out` may be
+    /// partially written).
+    ///
+    /// # Panics
+    /// If `out.len() < count`.
+    ///
+    /// # Safety
+    /// - The pointer plus byte offset must be valid for reading all `count` encoded values
+    /// - The caller must ensure the pointer remains valid for the duration of the read
+    #[inline]
+    unsafe fn read_uleb128_array_at(
+        self,
+        byte_offset: usize,
+        count: usize,
+        out: &mut [u64],
+    ) -> Option<usize> {
+        let mut consumed = 0;
+        for slot in &mut out[..count] {
+            let (value, len) = self.read_uleb128_at(byte_offset + consumed)?;
+            *slot = value;
+            consumed += len;
+        }
+        Some(consumed)
+    }
+}
+
+impl<P: UnalignedRead + Copy> VarintRead for P {}
+
+/// Decodes a zigzag-encoded value, mapping `0, 1, 2, 3, ...` to `0, -1, 1, -2, ...`.
+#[inline(always)]
+pub const fn zigzag_decode(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
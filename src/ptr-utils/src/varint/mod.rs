@@ -0,0 +1,10 @@
+//! Variable-length integer (LEB128) decoding and delta-encoded sequences.
+
+pub mod delta;
+pub mod leb128;
+
+#[cfg(test)]
+mod tests;
+
+pub use delta::DeltaReader;
+pub use leb128::{zigzag_decode, VarintRead, MAX_LEB128_LEN};
@@ -0,0 +1,119 @@
+use super::*;
+use crate::UnalignedWrite;
+
+#[test]
+fn test_uleb128() {
+    let data = [
+        0x00u8, 0x7F, 0x80, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01,
+    ];
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_uleb128_at(0), Some((0, 1)));
+        assert_eq!(ptr.read_uleb128_at(1), Some((127, 1)));
+        assert_eq!(ptr.read_uleb128_at(2), Some((128, 2)));
+        assert_eq!(ptr.read_uleb128_at(4), Some((u64::MAX, 10)));
+    }
+
+    // Overflowing final byte, and an overlong encoding
+    let bad = [
+        0xFFu8, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02, 0x80,
+    ];
+    let overlong = [0x80u8; 11];
+    unsafe {
+        assert_eq!(bad.as_ptr().read_uleb128_at(0), None);
+        assert_eq!(overlong.as_ptr().read_uleb128_at(0), None);
+    }
+}
+
+#[test]
+fn test_sleb128_and_zigzag() {
+    let data = [0x02u8, 0x7E, 0xFF, 0x00, 0x80, 0x7F, 0x03];
+    let ptr = data.as_ptr();
+
+    unsafe {
+        assert_eq!(ptr.read_sleb128_at(0), Some((2, 1)));
+        assert_eq!(ptr.read_sleb128_at(1), Some((-2, 1)));
+        assert_eq!(ptr.read_sleb128_at(2), Some((127, 2)));
+        assert_eq!(ptr.read_sleb128_at(4), Some((-128, 2)));
+        assert_eq!(ptr.read_zigzag_at(6), Some((-2, 1)));
+    }
+
+    // The extremes, and 10th bytes whose payload beyond bit 63 disagrees with the sign
+    let mut min = [0x80u8; 10];
+    min[9] = 0x7F;
+    let mut max = [0xFFu8; 10];
+    max[9] = 0x00;
+    let mut bad_positive = max;
+    bad_positive[9] = 0x01;
+    let mut bad_negative = min;
+    bad_negative[9] = 0x7E;
+    unsafe {
+        assert_eq!(min.as_ptr().read_sleb128_at(0), Some((i64::MIN, 10)));
+        assert_eq!(max.as_ptr().read_sleb128_at(0), Some((i64::MAX, 10)));
+        assert_eq!(bad_positive.as_ptr().read_sleb128_at(0), None);
+        assert_eq!(bad_negative.as_ptr().read_sleb128_at(0), None);
+        assert_eq!([0x80u8; 11].as_ptr().read_sleb128_at(0), None);
+    }
+
+    assert_eq!(zigzag_decode(0), 0);
+    assert_eq!(zigzag_decode(1), -1);
+    assert_eq!(zigzag_decode(2), 1);
+    assert_eq!(zigzag_decode(u64::MAX), i64::MIN);
+}
+
+#[test]
+fn test_delta_reader() {
+    unsafe {
+        // Absolute values 1000, 1010, 1005, 1005, 2005
+        let mut buffer = [0u8; 20];
+        let ptr = buffer.as_mut_ptr();
+        for (x, delta) in [1000i32, 10, -5, 0, 1000].into_iter().enumerate() {
+            ptr.write_i32_at(x * 4, delta);
+        }
+
+        let mut reader = DeltaReader::new(buffer.as_ptr());
+        let decoded: [i32; 5] = core::array::from_fn(|_| reader.read_next_i32());
+        assert_eq!(decoded, [1000, 1010, 1005, 1005, 2005]);
+        assert_eq!(reader.position(), 20);
+
+        // Zigzag deltas from an initial value: -3, +64, -64
+        let data = [0x05u8, 0x80, 0x01, 0x7F];
+        let mut reader = DeltaReader::with_initial(data.as_ptr(), 10);
+        assert_eq!(reader.read_next_zigzag(), Some(7));
+        assert_eq!(reader.read_next_zigzag(), Some(71));
+        assert_eq!(reader.read_next_zigzag(), Some(7));
+        assert_eq!(reader.total(), 7);
+    }
+}
+
+#[test]
+fn test_uleb128_array() {
+    // Packed field: 3, 270, 86942, 0, u64::MAX
+    let mut data = [0u8; 32];
+    let encoded: &[u8] = &[
+        0x03, 0x8E, 0x02, 0x9E, 0xA7, 0x05, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFF, 0x01,
+    ];
+    data[1..1 + encoded.len()].copy_from_slice(encoded);
+    let ptr = data.as_ptr();
+
+    let mut out = [0u64; 6];
+    unsafe {
+        assert_eq!(
+            ptr.read_uleb128_array_at(1, 5, &mut out),
+            Some(encoded.len())
+        );
+        assert_eq!(out, [3, 270, 86942, 0, u64::MAX, 0]);
+
+        assert_eq!(ptr.read_uleb128_array_at(1, 0, &mut out), Some(0));
+    }
+
+    // A malformed (over-long) value fails the whole sequence.
+    let bad = [
+        0x01u8, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+    ];
+    unsafe {
+        assert_eq!(bad.as_ptr().read_uleb128_array_at(0, 2, &mut out), None);
+    }
+}
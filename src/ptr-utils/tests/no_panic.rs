@@ -0,0 +1,222 @@
+//! Link-time proof that the `no-panic` feature removes this crate's assertions, and that
+//! the guarded paths have no overflow checks left to fail.
+//!
+//! Each function below runs its body under a [`Guard`] whose destructor references a
+//! symbol that doesn't exist. The destructor only runs while unwinding, so the test fails
+//! to link unless the compiler can prove the body never panics. That proof needs
+//! optimizations, while the assertions need `debug_assertions`, so run this with the
+//! dedicated profile:
+//!
+//! ```text
+//! cargo test --profile no-panic --features no-panic --test no_panic
+//! ```
+//!
+//! Every function containing one of the crate's checks is guarded here. The arguments
+//! are passed through [`black_box`], so the proof holds for any value rather than the
+//! ones the test happens to use.
+
+#![cfg(debug_assertions)]
+
+use core::hint::black_box;
+use ptr_utils::dynamic::{DynRead, DynValue, ValueKind};
+use std::sync::Arc;
+
+use ptr_utils::{
+    AlignedRead, BcdRead, BitFieldRead, BitOrder, BitReader, BitWriter, Bounded, Checkpoint,
+    Endian, EndianRead, EndianWrite, FiniteRead, OffsetTableRead, OverflowPolicy, PtrCursor,
+    RegionWrite, UnalignedRead, UnalignedWrite,
+};
+
+/// Fails the link if it is still reachable after optimization.
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        extern "C" {
+            #[link_name = "\n\nERROR: a function guarded in tests/no_panic.rs may panic\n\n"]
+            fn may_panic() -> !;
+        }
+        unsafe { may_panic() }
+    }
+}
+
+/// Runs `body`, failing the link if it may panic.
+macro_rules! no_panic {
+    ($body:expr) => {{
+        let guard = Guard;
+        let result = $body;
+        core::mem::forget(guard);
+        result
+    }};
+}
+
+#[inline(never)]
+unsafe fn bounded_read(ptr: Bounded<*const u8>, byte_offset: usize) -> u32 {
+    no_panic!(ptr.read_u32_at(byte_offset))
+}
+
+#[inline(never)]
+unsafe fn aligned_read(ptr: *const u8, byte_offset: usize) -> u64 {
+    no_panic!(ptr.read_u64_require_aligned_at(byte_offset))
+}
+
+#[inline(never)]
+unsafe fn uint_dyn_round_trip(ptr: *mut u8, value: u64, byte_count: usize) -> u64 {
+    no_panic!({
+        ptr.write_uint_dyn_at(0, value, byte_count, Endian::Big);
+        ptr.read_uint_dyn_at(0, byte_count, Endian::Big)
+    })
+}
+
+#[inline(never)]
+unsafe fn bcd_read(ptr: *const u8, digits: usize) -> Option<u64> {
+    no_panic!(ptr.read_bcd_at(4, digits))
+}
+
+#[inline(never)]
+unsafe fn fields_read(ptr: *const u8, fields: &[(usize, ValueKind)], out: &mut [DynValue]) {
+    no_panic!(ptr.read_fields_into(0, fields, out))
+}
+
+#[inline(never)]
+unsafe fn array_round_trip(array: &mut [u8; 16], byte_offset: usize, value: u32) -> u32 {
+    no_panic!({
+        array.write_u32_at(byte_offset, value);
+        (&*array).read_u32_at(byte_offset)
+    })
+}
+
+#[inline(never)]
+unsafe fn shared_read(buffer: &Arc<[u8]>, byte_offset: usize) -> u32 {
+    no_panic!(buffer.read_u32_at(byte_offset))
+}
+
+#[inline(never)]
+unsafe fn clamped_read(ptr: *const u8, min: f32, max: f32) -> f32 {
+    no_panic!(ptr.read_f32_clamped_at(0, min, max))
+}
+
+#[inline(never)]
+unsafe fn uint_dyn_policy_write(ptr: *mut u8, value: u64, byte_count: usize) -> bool {
+    no_panic!(ptr
+        .write_uint_dyn_with_policy_at(
+            0,
+            value,
+            byte_count,
+            Endian::Little,
+            OverflowPolicy::DebugAssert
+        )
+        .is_ok())
+}
+
+#[inline(never)]
+unsafe fn bit_field_read(ptr: *const u8, bit_offset: u32, bit_count: u32) -> (u64, i64) {
+    no_panic!((
+        ptr.read_bits_at(0, bit_offset, bit_count),
+        ptr.read_signed_bits_at(0, bit_offset, bit_count)
+    ))
+}
+
+#[inline(never)]
+unsafe fn bit_stream_round_trip(ptr: *mut u8, value: u64, count: u32) -> u64 {
+    no_panic!({
+        let mut writer = BitWriter::new(ptr, BitOrder::MsbFirst);
+        writer.write_bits(value, count);
+        writer.finish();
+        BitReader::new(ptr, BitOrder::MsbFirst).read_bits(count)
+    })
+}
+
+#[inline(never)]
+unsafe fn offset_tables(ptr: *const u8, count: usize, out: &mut [*const u8]) {
+    no_panic!({
+        ptr.resolve_offset_table_u32_at(0, count, out);
+        ptr.resolve_offset_table_u64_at(0, count, out);
+    })
+}
+
+#[inline(never)]
+unsafe fn swap_regions(ptr: *mut u8, offset_a: usize, offset_b: usize, len: usize) {
+    no_panic!(ptr.swap_regions_at(offset_a, offset_b, len))
+}
+
+#[inline(never)]
+fn cursor_restore(cursor: &mut PtrCursor, checkpoint: Checkpoint) {
+    no_panic!(cursor.restore(checkpoint))
+}
+
+#[test]
+fn test_no_panic_paths() {
+    let mut buffer = [0x12u8; 16];
+
+    unsafe {
+        let bounded = Bounded::new(buffer.as_ptr(), buffer.len());
+        assert_eq!(bounded_read(bounded, black_box(4)), 0x12121212);
+
+        let words = [0x1234u64; 2];
+        assert_eq!(aligned_read(words.as_ptr().cast(), black_box(8)), 0x1234);
+
+        assert_eq!(
+            uint_dyn_round_trip(buffer.as_mut_ptr(), black_box(0xABCDEF), black_box(3)),
+            0xABCDEF
+        );
+        assert_eq!(bcd_read(buffer.as_ptr(), black_box(4)), Some(1212));
+
+        let mut array = [0u8; 16];
+        assert_eq!(
+            array_round_trip(&mut array, black_box(3), 0xDEADBEEF),
+            0xDEADBEEF
+        );
+
+        let shared: Arc<[u8]> = Arc::from(&buffer[..]);
+        assert_eq!(shared_read(&shared, black_box(12)), 0x12121212);
+
+        let nan = f32::NAN.to_ne_bytes();
+        assert_eq!(
+            clamped_read(nan.as_ptr(), black_box(-1.0), black_box(1.0)),
+            -1.0
+        );
+
+        assert!(uint_dyn_policy_write(
+            buffer.as_mut_ptr(),
+            black_box(0xFF),
+            black_box(1)
+        ));
+
+        let bits = [0xF0u8, 0x0F];
+        assert_eq!(
+            bit_field_read(bits.as_ptr(), black_box(4), black_box(8)),
+            (0xFF, -1)
+        );
+
+        let mut stream = [0u8; 9];
+        assert_eq!(
+            bit_stream_round_trip(stream.as_mut_ptr(), black_box(0x2A), black_box(6)),
+            0x2A
+        );
+
+        let mut table = [0u8; 16];
+        table[..4].copy_from_slice(&4u32.to_ne_bytes());
+        let mut entries = [core::ptr::null(); 2];
+        offset_tables(table.as_ptr(), black_box(2), &mut entries);
+
+        let mut records = *b"abcdef";
+        swap_regions(
+            records.as_mut_ptr(),
+            black_box(0),
+            black_box(3),
+            black_box(3),
+        );
+        assert_eq!(&records, b"defabc");
+
+        let mut cursor = PtrCursor::new(buffer.as_ptr());
+        let checkpoint = cursor.checkpoint();
+        cursor.skip(black_box(4));
+        cursor_restore(&mut cursor, black_box(checkpoint));
+        assert_eq!(cursor.position(), 0);
+
+        let mut out = [DynValue::U8(0)];
+        fields_read(buffer.as_ptr(), black_box(&[(8, ValueKind::U8)]), &mut out);
+        assert_eq!(out, [DynValue::U8(0x12)]);
+    }
+}